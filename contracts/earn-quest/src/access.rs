@@ -0,0 +1,125 @@
+//! Quest access modes — restrict who may submit proof to an allowlist the
+//! creator maintains, or to submitters who redeem a single-use invite code.
+//!
+//! Invite codes are stored as `sha256(code)`; redeeming one consumes it and
+//! admits the submitter as a participant.
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage;
+use crate::types::{AccessMode, Quest};
+use crate::validation;
+
+/// Load a quest and check that `caller` is its creator and it is not terminal.
+fn require_creator(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<Quest, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    Ok(quest)
+}
+
+/// Set who may submit proof to a quest (creator only).
+pub fn set_mode(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    mode: AccessMode,
+) -> Result<(), Error> {
+    require_creator(env, quest_id, caller)?;
+
+    storage::set_access_mode(env, quest_id, mode);
+    events::access_mode_set(env, quest_id.clone(), caller.clone(), mode);
+
+    Ok(())
+}
+
+/// Admit or remove a batch of participants (creator only).
+pub fn set_participants(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    participants: &Vec<Address>,
+    admitted: bool,
+) -> Result<(), Error> {
+    require_creator(env, quest_id, caller)?;
+    validation::validate_array_length(participants.len(), validation::MAX_ACCESS_BATCH)?;
+
+    for participant in participants.iter() {
+        storage::set_participant(env, quest_id, &participant, admitted);
+    }
+    events::participants_set(
+        env,
+        quest_id.clone(),
+        caller.clone(),
+        participants.clone(),
+        admitted,
+    );
+
+    Ok(())
+}
+
+/// Store or revoke a batch of invite code hashes (creator only).
+pub fn set_invite_codes(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    code_hashes: &Vec<BytesN<32>>,
+    available: bool,
+) -> Result<(), Error> {
+    require_creator(env, quest_id, caller)?;
+    validation::validate_array_length(code_hashes.len(), validation::MAX_ACCESS_BATCH)?;
+
+    for code_hash in code_hashes.iter() {
+        storage::set_invite_code(env, quest_id, &code_hash, available);
+    }
+    events::invites_set(
+        env,
+        quest_id.clone(),
+        caller.clone(),
+        code_hashes.clone(),
+        available,
+    );
+
+    Ok(())
+}
+
+/// Consume an invite code and admit `submitter` to the quest.
+///
+/// Fails with `InvalidProof` if the code was never stored or is already used.
+pub fn redeem_invite(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    code: &Bytes,
+) -> Result<(), Error> {
+    let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+    if !storage::has_invite_code(env, quest_id, &code_hash) {
+        return Err(Error::InvalidProof);
+    }
+
+    storage::set_invite_code(env, quest_id, &code_hash, false);
+    storage::set_participant(env, quest_id, submitter, true);
+    events::invite_redeemed(env, quest_id.clone(), submitter.clone(), code_hash);
+
+    Ok(())
+}
+
+/// Fail with `Unauthorized` unless `submitter` may submit proof to the quest.
+pub fn check(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    if storage::get_access_mode(env, quest_id) == AccessMode::Open
+        || storage::is_participant(env, quest_id, submitter)
+    {
+        Ok(())
+    } else {
+        Err(Error::Unauthorized)
+    }
+}
@@ -1,28 +1,71 @@
 use crate::errors::Error;
+use crate::events;
+use crate::security;
+use crate::stats;
 use crate::storage;
-use soroban_sdk::{Address, Env};
+use crate::types::{
+    AssetConfig, CreationDeposit, ProposalTarget, ProposalValue, QuestStatus, SubmissionKey,
+    TtlConfig,
+};
+use crate::validation;
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
-/// Add a new admin (only callable by existing admin)
-pub fn add_admin(env: &Env, caller: &Address, new_admin: &Address) -> Result<(), Error> {
-    caller.require_auth();
+/// Propose granting `new_admin` admin rights (admin only).
+///
+/// Admins approve recovery, treasurer and upgrade proposals, so the admin
+/// set itself only changes through the same M-of-N approval and timelock.
+pub fn add_admin(env: &Env, caller: &Address, new_admin: &Address) -> Result<u32, Error> {
+    security::propose(
+        env,
+        caller,
+        ProposalTarget::AddAdmin,
+        ProposalValue::Address(new_admin.clone()),
+    )
+}
 
-    if !storage::is_admin(env, caller) {
-        return Err(Error::Unauthorized);
-    }
+/// Approve the pending add-admin proposal (admin only).
+pub fn approve_add_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    security::approve(env, caller, ProposalTarget::AddAdmin)
+}
 
-    storage::set_admin(env, new_admin);
+/// Grant admin rights once the add-admin proposal's approvals are met and
+/// the timelock expired.
+pub fn execute_add_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    let new_admin = security::take_executable_address(env, caller, ProposalTarget::AddAdmin)?;
+    storage::set_admin(env, &new_admin);
+    events::admin_added(env, caller.clone(), new_admin);
     Ok(())
 }
 
-/// Remove an admin (only callable by existing admin)
-pub fn remove_admin(env: &Env, caller: &Address, admin_to_remove: &Address) -> Result<(), Error> {
-    caller.require_auth();
+/// Propose revoking `admin_to_remove`'s admin rights (admin only).
+pub fn remove_admin(env: &Env, caller: &Address, admin_to_remove: &Address) -> Result<u32, Error> {
+    security::propose(
+        env,
+        caller,
+        ProposalTarget::RemoveAdmin,
+        ProposalValue::Address(admin_to_remove.clone()),
+    )
+}
 
-    if !storage::is_admin(env, caller) {
-        return Err(Error::Unauthorized);
+/// Approve the pending remove-admin proposal (admin only).
+pub fn approve_remove_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    security::approve(env, caller, ProposalTarget::RemoveAdmin)
+}
+
+/// Revoke admin rights once the remove-admin proposal's approvals are met and
+/// the timelock expired. The last admin cannot be removed. The removed
+/// admin's unpause approval is dropped, and their approvals of other pending
+/// proposals stop counting.
+pub fn execute_remove_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    let admin_to_remove =
+        security::take_executable_address(env, caller, ProposalTarget::RemoveAdmin)?;
+    if storage::get_admin_count(env) == Some(1) && storage::is_admin(env, &admin_to_remove) {
+        return Err(Error::InsufficientApprovals);
     }
 
-    storage::remove_admin(env, admin_to_remove);
+    storage::remove_admin(env, &admin_to_remove);
+    storage::set_unpause_approval(env, &admin_to_remove, false);
+    events::admin_removed(env, caller.clone(), admin_to_remove);
     Ok(())
 }
 
@@ -41,3 +84,148 @@ pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Add or update a reward asset in the registry (admin only)
+pub fn set_asset_config(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    config: &AssetConfig,
+) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    validation::validate_asset_config(config)?;
+
+    storage::set_asset_config(env, asset, config);
+    events::asset_configured(
+        env,
+        caller.clone(),
+        asset.clone(),
+        config.allowed,
+        config.min_reward,
+        config.max_reward,
+        config.decimals,
+    );
+    Ok(())
+}
+
+/// Update the TTL extension settings applied to persistent entries (admin only)
+pub fn set_ttl_config(env: &Env, caller: &Address, config: &TtlConfig) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    validation::validate_ttl_config(config, env.storage().max_ttl())?;
+
+    storage::set_ttl_config(env, config);
+    events::ttl_configured(env, caller.clone(), config.threshold, config.extend_to);
+    Ok(())
+}
+
+/// Extend a quest, its escrow and the contract instance to the full configured TTL (admin only)
+pub fn bump_ttl(env: &Env, caller: &Address, quest_id: &Symbol) -> Result<(), Error> {
+    require_admin(env, caller)?;
+
+    storage::bump_quest_ttl(env, quest_id)?;
+    storage::extend_instance_ttl(env);
+    Ok(())
+}
+
+/// Move entries written to instance storage by older contract versions into
+/// persistent storage (admin only). Returns the number of entries moved.
+pub fn migrate_storage(
+    env: &Env,
+    caller: &Address,
+    quest_ids: &Vec<Symbol>,
+    submissions: &Vec<SubmissionKey>,
+    users: &Vec<Address>,
+) -> Result<u32, Error> {
+    require_admin(env, caller)?;
+    validation::validate_migration_size(quest_ids.len() + submissions.len() + users.len())?;
+
+    let mut migrated = 0u32;
+    for id in quest_ids.iter() {
+        migrated += storage::migrate_quest(env, &id);
+    }
+    for key in submissions.iter() {
+        migrated += storage::migrate_submission(env, &key.quest_id, &key.submitter);
+    }
+    for user in users.iter() {
+        migrated += storage::migrate_user_stats(env, &user);
+    }
+
+    events::storage_migrated(env, caller.clone(), migrated);
+    Ok(migrated)
+}
+
+/// Set how long terminal quests are retained before they can be pruned (admin only)
+pub fn set_retention_period(env: &Env, caller: &Address, seconds: u64) -> Result<(), Error> {
+    require_admin(env, caller)?;
+
+    storage::set_retention_period(env, seconds);
+    events::retention_set(env, caller.clone(), seconds);
+    Ok(())
+}
+
+/// Set how many rewards a quest's escrow must cover before it opens (admin only)
+pub fn set_funding_requirement(env: &Env, caller: &Address, rewards: u32) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    if rewards == 0 {
+        return Err(Error::InvalidFundingRequirement);
+    }
+
+    storage::set_min_funded_rewards(env, rewards);
+    events::funding_requirement_set(env, caller.clone(), rewards);
+    Ok(())
+}
+
+/// Set or clear the deposit or fee creators pay for each quest they register (admin only)
+pub fn set_creation_deposit(
+    env: &Env,
+    caller: &Address,
+    deposit: &Option<CreationDeposit>,
+) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    if let Some(deposit) = deposit {
+        validation::validate_reward_amount(deposit.amount)?;
+        validation::validate_asset_allowed(env, &deposit.asset)?;
+    }
+
+    storage::set_creation_deposit(env, deposit);
+    events::creation_deposit_set(env, caller.clone(), deposit.clone());
+    Ok(())
+}
+
+/// Set how many non-terminal quests a creator may have at once; 0 removes the cap (admin only)
+pub fn set_max_active_quests(env: &Env, caller: &Address, max: u32) -> Result<(), Error> {
+    require_admin(env, caller)?;
+
+    storage::set_max_active_quests(env, max);
+    events::quest_cap_set(env, caller.clone(), max);
+    Ok(())
+}
+
+/// Move legacy Active or Paused quests that have no escrow into the Unfunded
+/// state (admin only). They reopen once funded through `deposit_escrow`.
+/// Each quest moves between its creator's status counters like any other
+/// status change, so the active-quest cap stays accurate.
+/// Returns the number of quests marked.
+pub fn mark_unfunded_quests(
+    env: &Env,
+    caller: &Address,
+    quest_ids: &Vec<Symbol>,
+) -> Result<u32, Error> {
+    require_admin(env, caller)?;
+    validation::validate_migration_size(quest_ids.len())?;
+
+    let mut marked = 0u32;
+    for id in quest_ids.iter() {
+        let quest = storage::get_quest(env, &id)?;
+        let open = matches!(quest.status, QuestStatus::Active | QuestStatus::Paused);
+        if open && !storage::has_escrow(env, &id) {
+            // Migration-only transition; not part of the normal status lifecycle
+            storage::update_quest_status(env, &id, QuestStatus::Unfunded)?;
+            stats::record_status_change(env, &quest.creator, &quest.status, &QuestStatus::Unfunded);
+            marked += 1;
+        }
+    }
+
+    events::quests_marked_unfunded(env, caller.clone(), marked);
+    Ok(marked)
+}
@@ -0,0 +1,189 @@
+//! Answer-hash quests — the contract checks answers itself, so no verifier
+//! is involved.
+//!
+//! COMMIT-REVEAL:
+//!
+//!   registration: answer_hash = sha256(salt || answer), salt kept secret
+//!   before reveal_at: submit_proof(proof_hash = sha256(answer || submitter as XDR))
+//!   from reveal_at: the creator publishes the salt
+//!   once the salt is published, until the deadline: reveal_answer(answer)
+//!
+//! A commitment is bound to its submitter, so copying another participant's
+//! proof hash is useless, and no commitment can be made once answers start
+//! appearing in reveals. The salt stays off-chain until commits close, so
+//! short answers cannot be brute-forced from `answer_hash` in the meantime.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol};
+
+use crate::campaign;
+use crate::errors::Error;
+use crate::escrow;
+use crate::events;
+use crate::quest;
+use crate::stats;
+use crate::storage;
+use crate::types::{AnswerConfig, SubmissionStatus};
+use crate::validation;
+
+/// Hash stored at registration for `answer` under `salt`.
+pub fn answer_hash(env: &Env, salt: &Bytes, answer: &Bytes) -> BytesN<32> {
+    let mut data = salt.clone();
+    data.append(answer);
+    env.crypto().sha256(&data).into()
+}
+
+/// Proof hash a submitter commits to for `answer`.
+pub fn commitment(env: &Env, answer: &Bytes, submitter: &Address) -> BytesN<32> {
+    let mut data = answer.clone();
+    data.append(&submitter.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Register a quest whose submissions are checked against `answer_hash`.
+///
+/// The contract itself is recorded as the quest's verifier, so submissions
+/// can only be settled by `reveal`. `reveal_at` must be in the future and
+/// before the deadline.
+#[allow(clippy::too_many_arguments)]
+pub fn register_quest(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    reward_asset: &Address,
+    reward_amount: i128,
+    deadline: u64,
+    answer_hash: &BytesN<32>,
+    reveal_at: u64,
+) -> Result<(), Error> {
+    validation::validate_deadline(env, reveal_at)?;
+    if reveal_at >= deadline {
+        return Err(Error::DeadlineInPast);
+    }
+
+    quest::register_quest(
+        env,
+        quest_id,
+        creator,
+        reward_asset,
+        reward_amount,
+        &env.current_contract_address(),
+        deadline,
+    )?;
+    storage::set_quest_answer(
+        env,
+        quest_id,
+        &AnswerConfig {
+            answer_hash: answer_hash.clone(),
+            reveal_at,
+            salt: None,
+        },
+    );
+
+    Ok(())
+}
+
+/// Publish the salt behind an answer-hash quest's `answer_hash`, opening reveals.
+///
+/// # Requirements
+/// - Caller is the quest creator
+/// - `reveal_at` has passed and no salt has been published yet
+pub fn publish_salt(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    salt: &Bytes,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+    let mut config = storage::get_quest_answer(env, quest_id).ok_or(Error::Unauthorized)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() < config.reveal_at {
+        return Err(Error::TimelockNotExpired);
+    }
+    if config.salt.is_some() {
+        return Err(Error::InvalidStatusTransition);
+    }
+    if salt.len() > validation::MAX_ANSWER_LENGTH {
+        return Err(Error::StringTooLong);
+    }
+
+    config.salt = Some(salt.clone());
+    storage::set_quest_answer(env, quest_id, &config);
+    events::answer_salt_published(env, quest_id.clone(), creator.clone(), salt.clone());
+
+    Ok(())
+}
+
+/// Reject commitments to an answer-hash quest once reveals have started.
+/// No-op for other quests.
+pub fn validate_commit_window(env: &Env, quest_id: &Symbol) -> Result<(), Error> {
+    match storage::get_quest_answer(env, quest_id) {
+        Some(answer) if env.ledger().timestamp() >= answer.reveal_at => Err(Error::QuestExpired),
+        _ => Ok(()),
+    }
+}
+
+/// Reveal a committed answer and settle the submission.
+///
+/// # Requirements
+/// - Quest is an Active answer-hash quest in its reveal window
+/// - The creator has published the salt
+/// - Submission is Pending and `answer` matches its commitment
+///
+/// Returns `true` if the answer is correct and the submission was approved,
+/// or `false` if it was rejected.
+pub fn reveal(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    answer: &Bytes,
+) -> Result<bool, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+    let config = storage::get_quest_answer(env, quest_id).ok_or(Error::Unauthorized)?;
+
+    validation::validate_quest_is_active(&quest.status)?;
+    validation::validate_quest_not_expired(env, quest.deadline)?;
+    if env.ledger().timestamp() < config.reveal_at {
+        return Err(Error::TimelockNotExpired);
+    }
+    let salt = config.salt.ok_or(Error::TimelockNotExpired)?;
+    if answer.len() > validation::MAX_ANSWER_LENGTH {
+        return Err(Error::StringTooLong);
+    }
+
+    let submission = storage::get_submission(env, quest_id, submitter)?;
+    if submission.proof_hash != commitment(env, answer, submitter) {
+        return Err(Error::InvalidProof);
+    }
+
+    let correct = answer_hash(env, &salt, answer) == config.answer_hash;
+    let status = if correct {
+        SubmissionStatus::Approved
+    } else {
+        SubmissionStatus::Rejected
+    };
+    validation::validate_submission_status_transition(&submission.status, &status)?;
+
+    if correct {
+        // Same escrow guarantee as a verifier approval
+        campaign::draw(env, &quest, quest.reward_amount)?;
+        escrow::validate_sufficient(env, quest_id, quest.reward_amount)?;
+    }
+
+    storage::update_submission_status(env, quest_id, submitter, status)?;
+    storage::remove_verifier_pending(env, &quest.verifier, quest_id, submitter);
+    crate::bond::refund(env, quest_id, submitter)?;
+
+    if correct {
+        crate::lottery::enter(env, quest_id, submitter);
+        stats::record_approval(env, &quest.creator);
+        events::submission_approved(env, quest_id.clone(), submitter.clone(), quest.verifier);
+    } else {
+        events::submission_rejected(env, quest_id.clone(), submitter.clone(), quest.verifier);
+    }
+
+    Ok(correct)
+}
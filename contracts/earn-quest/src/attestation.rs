@@ -0,0 +1,162 @@
+//! Attestation claims — an off-chain service checks a submission and signs
+//! an `Attestation`; the submitter presents it to be approved and paid in
+//! one transaction.
+//!
+//! SIGNED MESSAGE:
+//!
+//!   message = Attestation as XDR (`ScVal` map of its fields)
+//!
+//! The signature is verified against the quest's registered attestor key
+//! with `ed25519_verify`, which aborts the transaction on a bad signature.
+//!
+//! The submitter's `Submission` is created (or updated) as Paid, so an
+//! attested reward cannot also be claimed through `claim_reward`, and a
+//! submitter already paid for the quest cannot be paid again.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::campaign;
+use crate::errors::Error;
+use crate::escrow;
+use crate::events;
+use crate::payout;
+use crate::reputation;
+use crate::security;
+use crate::stats;
+use crate::storage;
+use crate::submission;
+use crate::types::{Attestation, SubmissionStatus};
+use crate::validation;
+
+/// Register or clear the ed25519 key allowed to sign attestations for a quest.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must not be terminal
+/// - Lottery quests cannot register a key; only drawn winners are paid
+pub fn set_attestor(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    attestor: &Option<BytesN<32>>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    if attestor.is_some() && storage::get_lottery_winners(env, quest_id).is_some() {
+        return Err(Error::InvalidConfig);
+    }
+
+    storage::set_quest_attestor(env, quest_id, attestor);
+    events::attestor_set(env, quest_id.clone(), caller.clone(), attestor.clone());
+
+    Ok(())
+}
+
+/// Approve and pay a submission from a signed attestation.
+///
+/// # Requirements
+/// - Quest has an attestor and is Active and not expired
+/// - `payload` names this quest, has not expired and its nonce is unused
+/// - The submitter has not already been paid for the quest, and the
+///   verifier has not rejected their submission
+/// - The submitter passes the quest's access rules and prerequisites, and
+///   the proof hash passes its uniqueness policy, as in `submit_proof`
+/// - `signature` is the attestor's signature over `payload`
+/// - The quest's escrow covers `payload.amount`
+///
+/// Returns `false` without paying if the payout tripped the circuit breaker.
+pub fn claim_with_attestation(
+    env: &Env,
+    quest_id: &Symbol,
+    payload: &Attestation,
+    signature: &BytesN<64>,
+) -> Result<bool, Error> {
+    crate::fraud::check(env, &payload.submitter)?;
+
+    let quest = storage::get_quest(env, quest_id)?;
+    let attestor = storage::get_quest_attestor(env, quest_id).ok_or(Error::Unauthorized)?;
+
+    validation::validate_quest_is_active(&quest.status)?;
+    validation::validate_quest_not_expired(env, quest.deadline)?;
+
+    if payload.quest_id != *quest_id || payload.expiry < env.ledger().timestamp() {
+        return Err(Error::InvalidProof);
+    }
+    if payload.amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+    if storage::is_attestation_nonce_used(env, quest_id, payload.nonce) {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    let previous = storage::get_submission(env, quest_id, &payload.submitter).ok();
+    let previous_status = previous.map(|p| p.status);
+    match previous_status {
+        Some(SubmissionStatus::Paid) => return Err(Error::AlreadyClaimed),
+        Some(SubmissionStatus::Rejected) => return Err(Error::InvalidStatusTransition),
+        _ => {}
+    }
+
+    crate::access::check(env, quest_id, &payload.submitter)?;
+    crate::prerequisites::check(env, quest_id, &payload.submitter)?;
+
+    env.crypto()
+        .ed25519_verify(&attestor, &payload.clone().to_xdr(env), signature);
+
+    validation::validate_quest_claims_limit(quest.total_claims)?;
+
+    // Checked before the nonce is used so the attestation can be replayed after unpause
+    if !security::admit_payout(env, &quest.reward_asset, payload.amount) {
+        return Ok(false);
+    }
+
+    // Same escrow guarantee as a regular approval
+    campaign::draw(env, &quest, payload.amount)?;
+    escrow::validate_sufficient(env, quest_id, payload.amount)?;
+
+    // Reject or flag proof hashes already used by someone else
+    crate::uniqueness::register(env, quest_id, &payload.submitter, &payload.proof_hash)?;
+
+    storage::use_attestation_nonce(env, quest_id, payload.nonce);
+    submission::record_paid(env, &quest, &payload.submitter, &payload.proof_hash)?;
+    events::attested(
+        env,
+        quest_id.clone(),
+        payload.submitter.clone(),
+        payload.proof_hash.clone(),
+        payload.nonce,
+    );
+
+    // Payout (net of any platform fee)
+    let paid = payout::transfer_reward_from_escrow(
+        env,
+        quest_id,
+        &quest.reward_asset,
+        &payload.submitter,
+        payload.amount,
+    )?;
+    storage::increment_quest_claims(env, quest_id)?;
+
+    events::reward_claimed(
+        env,
+        quest_id.clone(),
+        payload.submitter.clone(),
+        quest.reward_asset.clone(),
+        paid,
+    );
+
+    reputation::award_xp(env, &payload.submitter, 100)?;
+    reputation::record_earnings(env, &payload.submitter, &quest.reward_asset, paid);
+    stats::record_claim(env, &quest.creator, &quest.reward_asset, paid);
+
+    Ok(true)
+}
@@ -0,0 +1,180 @@
+//! Submission bonds — quests can require submitters to post a bond with their
+//! proof, discouraging spam that wastes verifier time.
+//!
+//! MONEY FLOW:
+//!
+//!   submit_proof:           Submitter  →  contract (bond held)
+//!   approve / clean reject: Contract   →  submitter
+//!   reject as spam:         Contract   →  quest creator, or treasury balance
+//!   quest closed, pending:  Contract   →  submitter (`reclaim_bond` or pruning)
+//!
+//! Bonds are tracked per submission and in a per-asset bond liability, separate
+//! from quest escrow.
+
+use soroban_sdk::{token, Address, Env, Symbol};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage;
+use crate::types::{Bond, BondConfig, Quest, SlashTarget};
+use crate::validation;
+
+/// Set or clear the bond a quest requires with each submission.
+/// Bonds already posted keep the terms they were posted under.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must not be terminal
+/// - Bond amount must be positive
+pub fn set_config(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    config: &Option<BondConfig>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    if let Some(config) = config {
+        validation::validate_reward_amount(config.amount)?;
+        validation::validate_asset_allowed(env, &config.asset)?;
+    }
+
+    storage::set_bond_config(env, quest_id, config);
+    events::bond_config_set(env, quest_id.clone(), caller.clone(), config.clone());
+
+    Ok(())
+}
+
+/// Take the quest's bond from `submitter` unless one is already held for them
+/// (e.g. on resubmission while still pending).
+pub fn post(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    let Some(config) = storage::get_bond_config(env, quest_id) else {
+        return Ok(());
+    };
+    if storage::get_bond(env, quest_id, submitter).is_some() {
+        return Ok(());
+    }
+
+    // Transfer tokens: submitter → contract
+    let token_client = token::Client::new(env, &config.asset);
+    match token_client.try_transfer(submitter, &env.current_contract_address(), &config.amount) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+
+    storage::set_bond(
+        env,
+        quest_id,
+        submitter,
+        &Bond {
+            asset: config.asset.clone(),
+            amount: config.amount,
+            slash_to: config.slash_to,
+            posted_at: env.ledger().timestamp(),
+        },
+    );
+    storage::adjust_bond_liability(env, &config.asset, config.amount);
+    events::bond_posted(
+        env,
+        quest_id.clone(),
+        submitter.clone(),
+        config.asset,
+        config.amount,
+    );
+
+    Ok(())
+}
+
+/// Return the bond held for `submitter`, if any.
+pub fn refund(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    let Some(bond) = storage::get_bond(env, quest_id, submitter) else {
+        return Ok(());
+    };
+
+    // Transfer tokens: contract → submitter
+    let token_client = token::Client::new(env, &bond.asset);
+    match token_client.try_transfer(&env.current_contract_address(), submitter, &bond.amount) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+
+    storage::delete_bond(env, quest_id, submitter);
+    storage::adjust_bond_liability(env, &bond.asset, -bond.amount);
+    events::bond_refunded(
+        env,
+        quest_id.clone(),
+        submitter.clone(),
+        bond.asset,
+        bond.amount,
+    );
+
+    Ok(())
+}
+
+/// Forfeit the bond held for `submitter`, if any, to the quest creator or
+/// the treasury balance.
+pub fn slash(env: &Env, quest: &Quest, submitter: &Address) -> Result<(), Error> {
+    let Some(bond) = storage::get_bond(env, &quest.id, submitter) else {
+        return Ok(());
+    };
+
+    match bond.slash_to {
+        SlashTarget::Creator => {
+            // Transfer tokens: contract → creator
+            let token_client = token::Client::new(env, &bond.asset);
+            match token_client.try_transfer(
+                &env.current_contract_address(),
+                &quest.creator,
+                &bond.amount,
+            ) {
+                Ok(Ok(_)) => {}
+                _ => return Err(Error::TransferFailed),
+            }
+        }
+        SlashTarget::Treasury => {
+            // Tokens stay in the contract until the Treasurer withdraws them
+            let balance = storage::get_treasury_balance(env, &bond.asset) + bond.amount;
+            storage::set_treasury_balance(env, &bond.asset, balance);
+        }
+    }
+
+    storage::delete_bond(env, &quest.id, submitter);
+    storage::adjust_bond_liability(env, &bond.asset, -bond.amount);
+    events::bond_slashed(
+        env,
+        quest.id.clone(),
+        submitter.clone(),
+        bond.asset,
+        bond.amount,
+        bond.slash_to,
+    );
+
+    Ok(())
+}
+
+/// Return a bond left behind by a submission that was never reviewed before
+/// its quest closed.
+///
+/// # Requirements
+/// - Quest is terminal
+/// - A bond is held for `submitter`
+pub fn reclaim(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if !validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotTerminal);
+    }
+    if storage::get_bond(env, quest_id, submitter).is_none() {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    refund(env, quest_id, submitter)
+}
@@ -0,0 +1,407 @@
+//! Campaign module — shared reward pools that fund many quests.
+//!
+//! MONEY FLOW:
+//!
+//!   deposit_campaign:     Owner/manager wallet  →  Contract  (pool topped up, minus any deposit fee)
+//!   draw (on approval):   Campaign pool  →  Quest escrow  (one reward per approved submission)
+//!   return_to_pool:       Quest escrow  →  Campaign pool  (leftover when the quest ends)
+//!   withdraw_unclaimed:   Contract  →  Owner wallet  (pool closed, remaining balance returned)
+//!
+//! Draws and returns move accounting between escrow records only; tokens
+//! stay in the contract until a reward is claimed or the pool is withdrawn.
+
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::escrow;
+use crate::events;
+use crate::fees;
+use crate::quest;
+use crate::stats;
+use crate::storage::{self, QuestIndex};
+use crate::types::{Campaign, EscrowInfo, FeeMode, Quest, QuestStatus};
+use crate::validation;
+
+// ═══════════════════════════════════════════════════════════════
+// CREATE: Owner opens a campaign
+// ═══════════════════════════════════════════════════════════════
+
+/// Create a campaign with an empty pool in `asset`.
+///
+/// # Requirements
+/// - Campaign id is unused
+/// - Asset is allowed in the registry
+pub fn create_campaign(
+    env: &Env,
+    campaign_id: &Symbol,
+    owner: &Address,
+    asset: &Address,
+) -> Result<(), Error> {
+    validation::validate_symbol_length(campaign_id)?;
+
+    if storage::has_campaign(env, campaign_id) {
+        return Err(Error::CampaignAlreadyExists);
+    }
+
+    validation::validate_asset_allowed(env, asset)?;
+
+    let campaign = Campaign {
+        id: campaign_id.clone(),
+        owner: owner.clone(),
+        asset: asset.clone(),
+        managers: Vec::new(env),
+    };
+    storage::set_campaign(env, campaign_id, &campaign);
+    storage::set_campaign_escrow(
+        env,
+        campaign_id,
+        &EscrowInfo {
+            quest_id: campaign_id.clone(),
+            depositor: owner.clone(),
+            token: asset.clone(),
+            total_deposited: 0,
+            total_paid_out: 0,
+            total_refunded: 0,
+            total_fees: 0,
+            fee_mode: fees::current_mode(env),
+            is_active: true,
+        },
+    );
+
+    events::campaign_created(env, campaign_id.clone(), owner.clone(), asset.clone());
+
+    Ok(())
+}
+
+/// Add or remove a campaign manager (owner only).
+pub fn set_manager(
+    env: &Env,
+    campaign_id: &Symbol,
+    caller: &Address,
+    manager: &Address,
+    allowed: bool,
+) -> Result<(), Error> {
+    let mut campaign = storage::get_campaign(env, campaign_id)?;
+
+    if *caller != campaign.owner {
+        return Err(Error::Unauthorized);
+    }
+
+    match (campaign.managers.first_index_of(manager), allowed) {
+        (None, true) => {
+            if campaign.managers.len() >= validation::MAX_CAMPAIGN_MANAGERS {
+                return Err(Error::ArrayTooLong);
+            }
+            campaign.managers.push_back(manager.clone());
+        }
+        (Some(i), false) => {
+            campaign.managers.remove(i);
+        }
+        _ => return Ok(()),
+    }
+
+    storage::set_campaign(env, campaign_id, &campaign);
+    events::campaign_manager_set(env, campaign_id.clone(), manager.clone(), allowed);
+
+    Ok(())
+}
+
+fn require_manager(campaign: &Campaign, caller: &Address) -> Result<(), Error> {
+    if *caller != campaign.owner && !campaign.managers.contains(caller) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// DEPOSIT: Owner or manager funds the pool
+// ═══════════════════════════════════════════════════════════════
+
+/// Deposit tokens into a campaign's pool.
+///
+/// # Flow
+/// ```text
+/// Depositor's wallet  ──(amount)──►  Contract address
+///                                    Pool.total_deposited += amount
+/// ```
+pub fn deposit(
+    env: &Env,
+    campaign_id: &Symbol,
+    depositor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    crate::fraud::check(env, depositor)?;
+    validation::validate_reward_amount(amount)?;
+
+    let campaign = storage::get_campaign(env, campaign_id)?;
+    require_manager(&campaign, depositor)?;
+
+    let mut pool = storage::get_campaign_escrow(env, campaign_id)?;
+    if !pool.is_active {
+        return Err(Error::EscrowInactive);
+    }
+
+    validation::validate_asset_allowed(env, &campaign.asset)?;
+
+    // Transfer tokens: depositor → contract
+    let token_client = token::Client::new(env, &campaign.asset);
+    let transfer_result =
+        token_client.try_transfer(depositor, &env.current_contract_address(), &amount);
+
+    match transfer_result {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+
+    // Platform fee (only when charged on deposit)
+    let fee = fees::fee_for(env, &pool, amount, FeeMode::OnDeposit);
+
+    pool.total_deposited += amount;
+    pool.total_fees += fee;
+    storage::set_campaign_escrow(env, campaign_id, &pool);
+    fees::accrue(env, campaign_id, &campaign.asset, FeeMode::OnDeposit, fee);
+    storage::adjust_escrow_liability(env, &campaign.asset, amount - fee);
+    stats::record_escrow_change(env, &campaign.owner, &campaign.asset, amount - fee);
+
+    events::campaign_deposited(
+        env,
+        campaign_id.clone(),
+        depositor.clone(),
+        amount,
+        escrow::available(&pool),
+    );
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// REGISTER: Manager opens a quest funded by the pool
+// ═══════════════════════════════════════════════════════════════
+
+/// Register a quest that draws its rewards from a campaign pool.
+///
+/// The quest uses the campaign's asset and opens immediately, provided the
+/// pool currently covers the funding requirement. The caller becomes the
+/// quest creator.
+pub fn register_quest(
+    env: &Env,
+    campaign_id: &Symbol,
+    manager: &Address,
+    quest_id: &Symbol,
+    reward_amount: i128,
+    verifier: &Address,
+    deadline: u64,
+) -> Result<(), Error> {
+    let campaign = storage::get_campaign(env, campaign_id)?;
+    require_manager(&campaign, manager)?;
+
+    let pool = storage::get_campaign_escrow(env, campaign_id)?;
+    if !pool.is_active {
+        return Err(Error::EscrowInactive);
+    }
+
+    quest::register_quest(
+        env,
+        quest_id,
+        manager,
+        &campaign.asset,
+        reward_amount,
+        verifier,
+        deadline,
+    )?;
+
+    if escrow::available(&pool) < escrow::funding_requirement(env, &reward_amount) {
+        return Err(Error::InsufficientEscrow);
+    }
+
+    storage::set_quest_campaign(env, quest_id, campaign_id);
+    storage::add_to_quest_index(env, QuestIndex::Campaign(campaign_id.clone()), quest_id);
+
+    storage::update_quest_status(env, quest_id, QuestStatus::Active)?;
+    stats::record_status_change(env, manager, &QuestStatus::Unfunded, &QuestStatus::Active);
+    events::quest_funded(env, quest_id.clone(), escrow::available(&pool));
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// DRAW / RETURN: Move funds between the pool and a quest's escrow
+// ═══════════════════════════════════════════════════════════════
+
+/// Move `amount` from a campaign quest's pool into the quest's own escrow.
+/// No-op for quests that are not part of a campaign.
+///
+/// Called when a submission is approved, so every approved reward is backed
+/// by the quest's escrow before it is claimed.
+pub fn draw(env: &Env, quest: &Quest, amount: i128) -> Result<(), Error> {
+    let campaign_id = match storage::get_quest_campaign(env, &quest.id) {
+        Some(campaign_id) => campaign_id,
+        None => return Ok(()),
+    };
+    let campaign = storage::get_campaign(env, &campaign_id)?;
+
+    let mut pool = storage::get_campaign_escrow(env, &campaign_id)?;
+    if !pool.is_active {
+        return Err(Error::EscrowInactive);
+    }
+    if escrow::available(&pool) < amount {
+        return Err(Error::InsufficientEscrow);
+    }
+    pool.total_paid_out += amount;
+    storage::set_campaign_escrow(env, &campaign_id, &pool);
+
+    let mut quest_escrow = if storage::has_escrow(env, &quest.id) {
+        storage::get_escrow(env, &quest.id)?
+    } else {
+        EscrowInfo {
+            quest_id: quest.id.clone(),
+            depositor: quest.creator.clone(),
+            token: campaign.asset.clone(),
+            total_deposited: 0,
+            total_paid_out: 0,
+            total_refunded: 0,
+            total_fees: 0,
+            // Funds drawn from the pool were charged (or not) under the pool's mode
+            fee_mode: pool.fee_mode,
+            is_active: true,
+        }
+    };
+    quest_escrow.total_deposited += amount;
+    storage::set_escrow(env, &quest.id, &quest_escrow);
+
+    stats::record_escrow_change(env, &campaign.owner, &campaign.asset, -amount);
+    stats::record_escrow_change(env, &quest.creator, &campaign.asset, amount);
+
+    events::campaign_drawn(
+        env,
+        campaign_id,
+        quest.id.clone(),
+        amount,
+        escrow::available(&pool),
+    );
+
+    Ok(())
+}
+
+/// Credit a quest's leftover escrow back to its campaign pool.
+///
+/// Called by `escrow::refund_remaining` for campaign quests instead of
+/// refunding depositors. The pool accepts returns even after it has been
+/// withdrawn, so the owner can withdraw them later.
+pub fn return_to_pool(
+    env: &Env,
+    campaign_id: &Symbol,
+    quest_id: &Symbol,
+    amount: i128,
+) -> Result<(), Error> {
+    let campaign = storage::get_campaign(env, campaign_id)?;
+    let mut pool = storage::get_campaign_escrow(env, campaign_id)?;
+
+    pool.total_paid_out -= amount;
+    storage::set_campaign_escrow(env, campaign_id, &pool);
+    stats::record_escrow_change(env, &campaign.owner, &campaign.asset, amount);
+
+    events::campaign_returned(
+        env,
+        campaign_id.clone(),
+        quest_id.clone(),
+        amount,
+        escrow::available(&pool),
+    );
+
+    Ok(())
+}
+
+/// Return an unclaimed draw from a quest's escrow to its campaign pool, for
+/// an approval that was voided. No-op for quests that are not part of a
+/// campaign or whose escrow has already been settled.
+pub fn release(env: &Env, quest: &Quest, amount: i128) -> Result<(), Error> {
+    let Some(campaign_id) = storage::get_quest_campaign(env, &quest.id) else {
+        return Ok(());
+    };
+    let mut quest_escrow = storage::get_escrow(env, &quest.id)?;
+    if !quest_escrow.is_active || escrow::available(&quest_escrow) < amount {
+        return Ok(());
+    }
+
+    quest_escrow.total_deposited -= amount;
+    storage::set_escrow(env, &quest.id, &quest_escrow);
+    stats::record_escrow_change(env, &quest.creator, &quest_escrow.token, -amount);
+
+    return_to_pool(env, &campaign_id, &quest.id, amount)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// WITHDRAW UNCLAIMED: Owner closes the campaign
+// ═══════════════════════════════════════════════════════════════
+
+/// Withdraw the pool's remaining balance to the owner and close the campaign.
+///
+/// Once closed, the pool accepts no deposits, draws or new quests. Leftovers
+/// returned later by the campaign's quests can be withdrawn by calling again.
+///
+/// # Flow
+/// ```text
+/// Contract  ──(remaining)──►  Owner's wallet
+/// Pool.total_refunded += remaining
+/// Pool.is_active = false
+/// ```
+pub fn withdraw_unclaimed(
+    env: &Env,
+    campaign_id: &Symbol,
+    caller: &Address,
+) -> Result<i128, Error> {
+    let campaign = storage::get_campaign(env, campaign_id)?;
+
+    if *caller != campaign.owner {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut pool = storage::get_campaign_escrow(env, campaign_id)?;
+    let available = escrow::available(&pool);
+
+    if available <= 0 && !pool.is_active {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    if available > 0 {
+        let token_client = token::Client::new(env, &campaign.asset);
+        let transfer_result =
+            token_client.try_transfer(&env.current_contract_address(), &campaign.owner, &available);
+
+        match transfer_result {
+            Ok(Ok(_)) => {}
+            _ => return Err(Error::TransferFailed),
+        }
+    }
+
+    pool.total_refunded += available;
+    pool.is_active = false;
+    storage::set_campaign_escrow(env, campaign_id, &pool);
+    storage::adjust_escrow_liability(env, &campaign.asset, -available);
+    stats::record_escrow_change(env, &campaign.owner, &campaign.asset, -available);
+
+    events::campaign_withdrawn(env, campaign_id.clone(), caller.clone(), available);
+
+    Ok(available)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// QUERIES: Read campaign state
+// ═══════════════════════════════════════════════════════════════
+
+/// Get the available (undrawn, unwithdrawn, net of fees) pool balance.
+pub fn get_balance(env: &Env, campaign_id: &Symbol) -> Result<i128, Error> {
+    let pool = storage::get_campaign_escrow(env, campaign_id)?;
+    Ok(escrow::available(&pool))
+}
+
+/// Load up to `limit` quests funded by a campaign, in registration order.
+pub fn list_quests(env: &Env, campaign_id: &Symbol, cursor: u32, limit: u32) -> Vec<Quest> {
+    quest::list_quests(
+        env,
+        &QuestIndex::Campaign(campaign_id.clone()),
+        cursor,
+        limit,
+    )
+}
@@ -9,20 +9,22 @@ pub enum Error {
     QuestNotFound = 2,
     InvalidRewardAmount = 3,
     QuestStillActive = 4,
-    
+    ActiveQuestLimitReached = 5,
+    CreationDepositUnpaid = 6,
+
     // Auth Errors
     Unauthorized = 10,
-    
+
     // Submission Errors
     InvalidSubmissionStatus = 20,
     SubmissionNotFound = 21,
-    
+
     // Payout Errors
     InsufficientBalance = 30,
     TransferFailed = 31,
     AlreadyClaimed = 32,
     InvalidAsset = 33,
-    
+
     // Reputation Errors
     UserStatsNotFound = 40,
     // Security / Emergency
@@ -30,6 +32,8 @@ pub enum Error {
     TimelockNotExpired = 51,
     AlreadyApproved = 52,
     InsufficientApprovals = 53,
+    RecoveryAddressNotSet = 54,
+    NoPendingProposal = 55,
 
     // Validation Errors
     DeadlineInPast = 60,
@@ -40,6 +44,7 @@ pub enum Error {
     InvalidAddress = 65,
     QuestExpired = 66,
     QuestNotActive = 67,
+    InvalidConfig = 68,
 
     InsufficientEscrow = 70,
     EscrowNotFound = 71,
@@ -47,4 +52,28 @@ pub enum Error {
     NoFundsToWithdraw = 73,
     QuestNotTerminal = 74,
     TokenMismatch = 75,
+    EscrowNotSettled = 76,
+    InvalidFundingRequirement = 77,
+
+    // Storage Errors
+    RetentionPeriodActive = 81,
+
+    // Sponsorship Errors
+    SponsorNotAllowed = 90,
+    RefundPolicyLocked = 92,
+
+    // Campaign Errors
+    CampaignNotFound = 100,
+    CampaignAlreadyExists = 101,
+    FundedByCampaign = 102,
+
+    // Proof Errors
+    MerkleRoundNotFound = 110,
+    InvalidProof = 111,
+    DuplicateProof = 112,
+
+    // Eligibility Errors
+    PrerequisiteNotMet = 120,
+    AddressDenylisted = 121,
+    NotFlagged = 122,
 }
@@ -2,32 +2,46 @@
 //!
 //! MONEY FLOW:
 //!
-//!   deposit_escrow:    Creator wallet  →  Contract  (tokens locked)
+//!   deposit_escrow:    Creator or sponsor wallet  →  Contract  (tokens locked, minus any deposit fee)
+//!                      Unfunded quest  →  Active once escrow covers the funding requirement
 //!   record_payout:     Update tracking after payout::transfer_reward sends tokens
-//!   refund_remaining:  Contract  →  Creator wallet  (leftover returned)
+//!   refund_remaining:  Contract  →  Depositor wallets  (leftover split by the quest's refund policy)
 
-use soroban_sdk::{token, Address, Env, Symbol};
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
 
+use crate::campaign;
 use crate::errors::Error;
 use crate::events;
+use crate::fees;
+use crate::quest;
+use crate::stats;
 use crate::storage;
-use crate::types::{EscrowInfo, QuestStatus};
+use crate::types::{
+    EscrowContribution, EscrowInfo, FeeMode, QuestStatus, RefundPolicy, SponsorPolicy,
+};
 use crate::validation;
 
+/// Tokens still held for a quest: deposits minus payouts, refunds and fees.
+pub fn available(escrow: &EscrowInfo) -> i128 {
+    escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded - escrow.total_fees
+}
+
 // ═══════════════════════════════════════════════════════════════
-// DEPOSIT: Creator locks tokens for a quest
+// DEPOSIT: Creator or sponsor locks tokens for a quest
 // ═══════════════════════════════════════════════════════════════
 
 /// Deposit tokens into escrow for a specific quest.
 ///
-/// Called by the quest creator to fund rewards. Can be called multiple
-/// times to top up. Tokens are transferred from the creator's wallet
-/// to the contract.
+/// Called by the quest creator or any sponsor to fund rewards. Can be
+/// called multiple times to top up. If the creator has enabled the sponsor
+/// allowlist, only the creator and allowlisted sponsors may deposit.
+/// Each depositor's net contribution is recorded for refunds.
 ///
 /// # Flow
 /// ```text
-/// Creator's wallet  ──(amount)──►  Contract address
-///                                  EscrowInfo.total_deposited += amount
+/// Depositor's wallet  ──(amount)──►  Contract address
+///                                    EscrowInfo.total_deposited += amount
+///                                    contribution[depositor] += amount - fee
 /// ```
 pub fn deposit(
     env: &Env,
@@ -38,13 +52,22 @@ pub fn deposit(
 ) -> Result<(), Error> {
     // Validate amount
     validation::validate_reward_amount(amount)?;
+    crate::fraud::check(env, depositor)?;
 
     // Load quest — must exist
     let quest = storage::get_quest(env, quest_id)?;
 
-    // Only the quest creator can deposit
-    if *depositor != quest.creator {
-        return Err(Error::Unauthorized);
+    // Campaign quests are funded from their campaign's pool
+    if storage::get_quest_campaign(env, quest_id).is_some() {
+        return Err(Error::FundedByCampaign);
+    }
+
+    // Sponsors other than the creator may be restricted to an allowlist
+    if *depositor != quest.creator
+        && storage::get_sponsor_policy(env, quest_id).allowlist_only
+        && !storage::get_sponsor_allowlist(env, quest_id).contains(depositor)
+    {
+        return Err(Error::SponsorNotAllowed);
     }
 
     // Quest must be active or paused (not terminal)
@@ -57,13 +80,13 @@ pub fn deposit(
         return Err(Error::TokenMismatch);
     }
 
-    // Transfer tokens: creator → contract
+    // Asset must still be allowed in the registry
+    validation::validate_asset_allowed(env, token_address)?;
+
+    // Transfer tokens: depositor → contract
     let token_client = token::Client::new(env, token_address);
-    let transfer_result = token_client.try_transfer(
-        depositor,
-        &env.current_contract_address(),
-        &amount,
-    );
+    let transfer_result =
+        token_client.try_transfer(depositor, &env.current_contract_address(), &amount);
 
     match transfer_result {
         Ok(Ok(_)) => {}
@@ -81,26 +104,60 @@ pub fn deposit(
         // First deposit — create new escrow record
         EscrowInfo {
             quest_id: quest_id.clone(),
-            depositor: depositor.clone(),
+            depositor: quest.creator.clone(),
             token: token_address.clone(),
             total_deposited: 0,
             total_paid_out: 0,
             total_refunded: 0,
+            total_fees: 0,
+            fee_mode: fees::current_mode(env),
             is_active: true,
         }
     };
 
+    // Platform fee (only when charged on deposit)
+    let fee = fees::fee_for(env, &escrow, amount, FeeMode::OnDeposit);
+
     // Update balance
     escrow.total_deposited += amount;
+    escrow.total_fees += fee;
     storage::set_escrow(env, quest_id, &escrow);
+    storage::add_escrow_contribution(env, quest_id, depositor, amount - fee)?;
+    fees::accrue(env, quest_id, token_address, FeeMode::OnDeposit, fee);
+    storage::adjust_escrow_liability(env, token_address, amount - fee);
+    stats::record_escrow_change(env, &quest.creator, token_address, amount - fee);
 
     // Emit event
-    let available = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
-    events::escrow_deposited(env, quest_id.clone(), depositor.clone(), amount, available);
+    events::escrow_deposited(
+        env,
+        quest_id.clone(),
+        depositor.clone(),
+        amount,
+        available(&escrow),
+    );
+
+    // Open the quest once it can pay the required number of rewards
+    if quest.status == QuestStatus::Unfunded
+        && available(&escrow) >= funding_requirement(env, &quest.reward_amount)
+    {
+        storage::update_quest_status(env, quest_id, QuestStatus::Active)?;
+        stats::record_status_change(
+            env,
+            &quest.creator,
+            &QuestStatus::Unfunded,
+            &QuestStatus::Active,
+        );
+        events::quest_funded(env, quest_id.clone(), available(&escrow));
+    }
 
     Ok(())
 }
 
+/// Escrow an Unfunded quest must hold before it becomes Active.
+pub fn funding_requirement(env: &Env, reward_amount: &i128) -> i128 {
+    reward_amount.saturating_mul(storage::get_min_funded_rewards(env) as i128)
+}
+
 // ═══════════════════════════════════════════════════════════════
 // VALIDATE: Check if enough escrow exists for a payout
 // ═══════════════════════════════════════════════════════════════
@@ -108,20 +165,17 @@ pub fn deposit(
 /// Returns Ok if the quest's escrow can cover the given amount.
 /// Returns Err(EscrowNotFound) if no escrow exists.
 /// Returns Err(InsufficientEscrow) if balance is too low.
-pub fn validate_sufficient(
-    env: &Env,
-    quest_id: &Symbol,
-    amount: i128,
-) -> Result<(), Error> {
+///
+/// Totals of posted Merkle rounds that are not yet claimed are held back;
+/// `merkle::claim_with_proof` releases its leaf's amount before paying.
+pub fn validate_sufficient(env: &Env, quest_id: &Symbol, amount: i128) -> Result<(), Error> {
     let escrow = storage::get_escrow(env, quest_id)?;
 
     if !escrow.is_active {
         return Err(Error::EscrowInactive);
     }
 
-    let available = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
-
-    if available < amount {
+    if available(&escrow) - storage::get_merkle_outstanding(env, quest_id) < amount {
         return Err(Error::InsufficientEscrow);
     }
 
@@ -136,17 +190,20 @@ pub fn validate_sufficient(
 ///
 /// Called AFTER payout::transfer_reward() succeeds.
 /// Does NOT transfer tokens — that's payout.rs's job.
-/// This just updates the accounting.
+/// This just updates the accounting. `amount` is what the recipient
+/// received; `fee` is the platform fee withheld from the same reward.
 ///
 /// # Flow
 /// ```text
 /// EscrowInfo.total_paid_out += amount
+/// EscrowInfo.total_fees += fee
 /// ```
 pub fn record_payout(
     env: &Env,
     quest_id: &Symbol,
     recipient: &Address,
     amount: i128,
+    fee: i128,
 ) -> Result<(), Error> {
     let mut escrow = storage::get_escrow(env, quest_id)?;
 
@@ -154,32 +211,36 @@ pub fn record_payout(
         return Err(Error::EscrowInactive);
     }
 
-    let available = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
-    if available < amount {
+    if available(&escrow) < amount + fee {
         return Err(Error::InsufficientEscrow);
     }
 
     escrow.total_paid_out += amount;
+    escrow.total_fees += fee;
     storage::set_escrow(env, quest_id, &escrow);
+    storage::adjust_escrow_liability(env, &escrow.token, -(amount + fee));
+    stats::record_escrow_change(env, &escrow.depositor, &escrow.token, -(amount + fee));
 
-    let remaining = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
+    let remaining = available(&escrow);
     events::escrow_payout(env, quest_id.clone(), recipient.clone(), amount, remaining);
 
     Ok(())
 }
 
 // ═══════════════════════════════════════════════════════════════
-// REFUND: Return remaining tokens to creator
+// REFUND: Return remaining tokens to depositors
 // ═══════════════════════════════════════════════════════════════
 
-/// Refund all remaining escrow balance to the depositor.
+/// Refund all remaining escrow balance to the quest's depositors.
 ///
 /// Called internally by cancel_quest() and withdraw_unclaimed().
-/// Transfers tokens from contract back to creator and deactivates escrow.
+/// Splits the balance with `refund_shares`, transfers each share back to
+/// its depositor and deactivates escrow. Campaign quests instead return
+/// the balance to their campaign's pool.
 ///
 /// # Flow
 /// ```text
-/// Contract  ──(remaining)──►  Creator's wallet
+/// Contract  ──(share)──►  Each depositor's wallet
 /// EscrowInfo.total_refunded += remaining
 /// EscrowInfo.is_active = false
 /// ```
@@ -188,15 +249,22 @@ pub fn record_payout(
 fn refund_remaining(env: &Env, quest_id: &Symbol) -> Result<i128, Error> {
     let mut escrow = storage::get_escrow(env, quest_id)?;
 
-    let available = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
+    let available = available(&escrow);
+    let campaign_id = storage::get_quest_campaign(env, quest_id);
+
+    let shares = if available > 0 && campaign_id.is_none() {
+        refund_shares(env, quest_id, &escrow, available)
+    } else {
+        Vec::new(env)
+    };
 
-    if available > 0 {
-        // Transfer tokens: contract → creator
-        let token_client = token::Client::new(env, &escrow.token);
+    // Transfer tokens: contract → each depositor
+    let token_client = token::Client::new(env, &escrow.token);
+    for share in shares.iter() {
         let transfer_result = token_client.try_transfer(
             &env.current_contract_address(),
-            &escrow.depositor,
-            &available,
+            &share.sponsor,
+            &share.amount,
         );
 
         match transfer_result {
@@ -209,34 +277,173 @@ fn refund_remaining(env: &Env, quest_id: &Symbol) -> Result<i128, Error> {
     escrow.total_refunded += available;
     escrow.is_active = false;
     storage::set_escrow(env, quest_id, &escrow);
+    stats::record_escrow_change(env, &escrow.depositor, &escrow.token, -available);
+
+    match campaign_id {
+        // Leftover stays in the contract, credited back to the pool
+        Some(campaign_id) if available > 0 => {
+            campaign::return_to_pool(env, &campaign_id, quest_id, available)?;
+        }
+        Some(_) => {}
+        None => storage::adjust_escrow_liability(env, &escrow.token, -available),
+    }
 
-    if available > 0 {
-        events::escrow_refunded(env, quest_id.clone(), escrow.depositor.clone(), available);
+    for share in shares.iter() {
+        events::escrow_refunded(env, quest_id.clone(), share.sponsor, share.amount);
     }
 
     Ok(available)
 }
 
+/// Split `amount` between the quest's depositors according to its refund policy.
+///
+/// - `ProRata`: each depositor gets `amount * contribution / total`; rounding
+///   dust goes to the earliest depositor.
+/// - `Lifo`: depositors are repaid in full, most recent deposit first, until
+///   the amount runs out.
+///
+/// Balance not covered by recorded contributions (escrow funded before
+/// sponsorship was tracked) goes to the quest creator. Shares of zero are omitted.
+fn refund_shares(
+    env: &Env,
+    quest_id: &Symbol,
+    escrow: &EscrowInfo,
+    amount: i128,
+) -> Vec<EscrowContribution> {
+    let contributions = storage::get_escrow_contributions(env, quest_id);
+    let total: i128 = contributions.iter().map(|c| c.amount).sum();
+    let tracked = amount.min(total);
+
+    let mut shares = Vec::new(env);
+    credit(&mut shares, &escrow.depositor, amount - tracked);
+
+    match storage::get_sponsor_policy(env, quest_id).refund_policy {
+        RefundPolicy::ProRata => {
+            let mut assigned = 0;
+            for c in contributions.iter() {
+                let share = tracked * c.amount / total;
+                assigned += share;
+                credit(&mut shares, &c.sponsor, share);
+            }
+            if let Some(first) = contributions.first() {
+                credit(&mut shares, &first.sponsor, tracked - assigned);
+            }
+        }
+        RefundPolicy::Lifo => {
+            let mut remaining = tracked;
+            for c in contributions.iter().rev() {
+                let share = remaining.min(c.amount);
+                remaining -= share;
+                credit(&mut shares, &c.sponsor, share);
+            }
+        }
+    }
+
+    shares
+}
+
+/// Add `amount` to `sponsor`'s entry in `shares`, creating it if needed.
+fn credit(shares: &mut Vec<EscrowContribution>, sponsor: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    for (i, mut share) in shares.iter().enumerate() {
+        if share.sponsor == *sponsor {
+            share.amount += amount;
+            shares.set(i as u32, share);
+            return;
+        }
+    }
+    shares.push_back(EscrowContribution {
+        sponsor: sponsor.clone(),
+        amount,
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════
+// SPONSORSHIP: Creator-controlled sponsor rules
+// ═══════════════════════════════════════════════════════════════
+
+/// Set a quest's sponsorship rules.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must not be terminal
+/// - The refund policy cannot change once anyone other than the creator
+///   has contributed, so sponsors keep the terms they funded under
+pub fn set_sponsor_policy(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    policy: &SponsorPolicy,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    let current = storage::get_sponsor_policy(env, quest_id);
+    if policy.refund_policy != current.refund_policy
+        && storage::get_escrow_contributions(env, quest_id)
+            .iter()
+            .any(|c| c.sponsor != quest.creator)
+    {
+        return Err(Error::RefundPolicyLocked);
+    }
+
+    storage::set_sponsor_policy(env, quest_id, policy);
+    events::sponsor_policy_set(env, quest_id.clone(), caller.clone(), policy.clone());
+
+    Ok(())
+}
+
+/// Add or remove a sponsor from a quest's allowlist (creator only).
+/// The allowlist is only enforced while `SponsorPolicy::allowlist_only` is set.
+pub fn set_sponsor_allowed(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    sponsor: &Address,
+    allowed: bool,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    storage::set_sponsor_allowed(env, quest_id, sponsor, allowed)?;
+    events::sponsor_allowed(env, quest_id.clone(), sponsor.clone(), allowed);
+
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════
 // CANCEL QUEST: Creator cancels + refund
 // ═══════════════════════════════════════════════════════════════
 
-/// Cancel a quest and refund remaining escrow to the creator.
+/// Cancel a quest and refund remaining escrow to its depositors.
 ///
 /// # Requirements
 /// - Caller must be the quest creator
 /// - Quest must be Active or Paused (not already terminal)
+/// - Drawn lottery winners have all claimed, or their claim window has passed
 ///
 /// # Flow
 /// ```text
 /// Quest.status = Cancelled
-/// Remaining escrow → Creator's wallet
+/// Remaining escrow → Depositors' wallets
 /// ```
-pub fn cancel_quest(
-    env: &Env,
-    quest_id: &Symbol,
-    caller: &Address,
-) -> Result<i128, Error> {
+pub fn cancel_quest(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<i128, Error> {
     let quest = storage::get_quest(env, quest_id)?;
 
     // Only creator can cancel
@@ -252,8 +459,12 @@ pub fn cancel_quest(
     // Validate the status transition
     validation::validate_quest_status_transition(&quest.status, &QuestStatus::Cancelled)?;
 
+    // Drawn winners' rewards stay in escrow until they are claimed
+    crate::lottery::validate_settled(env, quest_id)?;
+
     // Update quest status
     storage::update_quest_status(env, quest_id, QuestStatus::Cancelled)?;
+    stats::record_status_change(env, &quest.creator, &quest.status, &QuestStatus::Cancelled);
 
     // Refund escrow if it exists
     let refunded = if storage::has_escrow(env, quest_id) {
@@ -263,6 +474,7 @@ pub fn cancel_quest(
     };
 
     events::quest_cancelled(env, quest_id.clone(), caller.clone(), refunded);
+    quest::return_creation_deposit(env, quest_id)?;
 
     Ok(refunded)
 }
@@ -277,16 +489,13 @@ pub fn cancel_quest(
 /// - Caller must be the quest creator
 /// - Quest must be Completed, Expired, or Cancelled
 /// - Escrow must exist and have remaining balance
+/// - Drawn lottery winners have all claimed, or their claim window has passed
 ///
 /// # Flow
 /// ```text
-/// Remaining escrow → Creator's wallet
+/// Remaining escrow → Depositors' wallets
 /// ```
-pub fn withdraw_unclaimed(
-    env: &Env,
-    quest_id: &Symbol,
-    caller: &Address,
-) -> Result<i128, Error> {
+pub fn withdraw_unclaimed(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<i128, Error> {
     let quest = storage::get_quest(env, quest_id)?;
 
     // Only creator can withdraw
@@ -305,26 +514,35 @@ pub fn withdraw_unclaimed(
     }
 
     let escrow = storage::get_escrow(env, quest_id)?;
-    let available = escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded;
 
-    if available <= 0 {
+    if available(&escrow) <= 0 {
         return Err(Error::NoFundsToWithdraw);
     }
 
-    refund_remaining(env, quest_id)
+    crate::lottery::validate_settled(env, quest_id)?;
+
+    let refunded = refund_remaining(env, quest_id)?;
+    quest::return_creation_deposit(env, quest_id)?;
+
+    Ok(refunded)
 }
 
 // ═══════════════════════════════════════════════════════════════
 // QUERIES: Read escrow state
 // ═══════════════════════════════════════════════════════════════
 
-/// Get the available (unspent, unrefunded) escrow balance for a quest.
+/// Get the available (unspent, unrefunded, net of fees) escrow balance for a quest.
 pub fn get_balance(env: &Env, quest_id: &Symbol) -> Result<i128, Error> {
     let escrow = storage::get_escrow(env, quest_id)?;
-    Ok(escrow.total_deposited - escrow.total_paid_out - escrow.total_refunded)
+    Ok(available(&escrow))
 }
 
 /// Get the full escrow info for a quest.
 pub fn get_info(env: &Env, quest_id: &Symbol) -> Result<EscrowInfo, Error> {
     storage::get_escrow(env, quest_id)
-}
\ No newline at end of file
+}
+
+/// Get each depositor's net contribution, ordered by most recent deposit (oldest first).
+pub fn get_contributions(env: &Env, quest_id: &Symbol) -> Vec<EscrowContribution> {
+    storage::get_escrow_contributions(env, quest_id)
+}
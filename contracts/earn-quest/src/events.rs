@@ -1,11 +1,15 @@
 #![allow(unused)]
-use soroban_sdk::{Env, Symbol, Address, BytesN, symbol_short};
-use crate::types::Badge;
+use crate::types::{
+    AccessMode, Badge, BondConfig, CreationDeposit, FeeMode, Prerequisites, ProofPolicy, ProofUse,
+    ProposalTarget, ProposalValue, SlashTarget, SponsorPolicy,
+};
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
 
 // Event Topics (Names)
 const TOPIC_QUEST_REGISTERED: Symbol = symbol_short!("quest_reg");
 const TOPIC_PROOF_SUBMITTED: Symbol = symbol_short!("proof_sub");
 const TOPIC_SUBMISSION_APPROVED: Symbol = symbol_short!("sub_appr");
+const TOPIC_SUBMISSION_REJECTED: Symbol = symbol_short!("sub_rej");
 const TOPIC_REWARD_CLAIMED: Symbol = symbol_short!("claimed");
 const TOPIC_XP_AWARDED: Symbol = symbol_short!("xp_award");
 const TOPIC_LEVEL_UP: Symbol = symbol_short!("level_up");
@@ -15,6 +19,39 @@ const TOPIC_EMERGENCY_UNPAUSED: Symbol = symbol_short!("eunpause");
 const TOPIC_EMERGENCY_WITHDRAW: Symbol = symbol_short!("ewdraw");
 const TOPIC_UNPAUSE_APPROVED: Symbol = symbol_short!("uappr");
 const TOPIC_TIMELOCK_SCHEDULED: Symbol = symbol_short!("tl_sched");
+const TOPIC_ASSET_CONFIGURED: Symbol = symbol_short!("asset_cfg");
+const TOPIC_CIRCUIT_TRIPPED: Symbol = symbol_short!("circ_trip");
+const TOPIC_CIRCUIT_CONFIGURED: Symbol = symbol_short!("circ_cfg");
+const TOPIC_OPS_PAUSED: Symbol = symbol_short!("ops_pause");
+const TOPIC_OPS_UNPAUSED: Symbol = symbol_short!("ops_resum");
+const TOPIC_RECOVERY_PROPOSED: Symbol = symbol_short!("rec_prop");
+const TOPIC_RECOVERY_APPROVED: Symbol = symbol_short!("rec_appr");
+const TOPIC_RECOVERY_SCHEDULED: Symbol = symbol_short!("rec_sched");
+const TOPIC_RECOVERY_SET: Symbol = symbol_short!("rec_set");
+const TOPIC_TREASURER_PROPOSED: Symbol = symbol_short!("trs_prop");
+const TOPIC_TREASURER_APPROVED: Symbol = symbol_short!("trs_appr");
+const TOPIC_TREASURER_SCHEDULED: Symbol = symbol_short!("trs_sched");
+const TOPIC_UPGRADE_PROPOSED: Symbol = symbol_short!("upg_prop");
+const TOPIC_UPGRADE_APPROVED: Symbol = symbol_short!("upg_appr");
+const TOPIC_UPGRADE_SCHEDULED: Symbol = symbol_short!("upg_sched");
+const TOPIC_UPGRADED: Symbol = symbol_short!("upgraded");
+const TOPIC_SWEEP_PROPOSED: Symbol = symbol_short!("swp_prop");
+const TOPIC_SWEEP_APPROVED: Symbol = symbol_short!("swp_appr");
+const TOPIC_SWEEP_SCHEDULED: Symbol = symbol_short!("swp_sched");
+const TOPIC_ADD_ADMIN_PROPOSED: Symbol = symbol_short!("adm_prop");
+const TOPIC_ADD_ADMIN_APPROVED: Symbol = symbol_short!("adm_appr");
+const TOPIC_ADD_ADMIN_SCHEDULED: Symbol = symbol_short!("adm_sched");
+const TOPIC_ADMIN_ADDED: Symbol = symbol_short!("adm_added");
+const TOPIC_REMOVE_ADMIN_PROPOSED: Symbol = symbol_short!("rma_prop");
+const TOPIC_REMOVE_ADMIN_APPROVED: Symbol = symbol_short!("rma_appr");
+const TOPIC_REMOVE_ADMIN_SCHEDULED: Symbol = symbol_short!("rma_sched");
+const TOPIC_ADMIN_REMOVED: Symbol = symbol_short!("adm_rmvd");
+const TOPIC_RECOVERED_RETURNED: Symbol = symbol_short!("rec_ret");
+const TOPIC_TTL_CONFIGURED: Symbol = symbol_short!("ttl_cfg");
+const TOPIC_STORAGE_MIGRATED: Symbol = symbol_short!("migrated");
+const TOPIC_QUESTS_UNFUNDED: Symbol = symbol_short!("unfunded");
+const TOPIC_QUEST_ARCHIVED: Symbol = symbol_short!("q_archive");
+const TOPIC_RETENTION_SET: Symbol = symbol_short!("retention");
 
 /// Emit when a new quest is created
 pub fn quest_registered(
@@ -47,6 +84,67 @@ pub fn emergency_unpaused(env: &Env, by: Address) {
     env.events().publish(topics, data);
 }
 
+/// Emit when an admin adds or updates a reward asset registry entry
+pub fn asset_configured(
+    env: &Env,
+    by: Address,
+    asset: Address,
+    allowed: bool,
+    min_reward: i128,
+    max_reward: i128,
+    decimals: u32,
+) {
+    // Topics: [EventName, Asset, Admin]
+    let topics = (TOPIC_ASSET_CONFIGURED, asset, by);
+    // Data: (Allowed, MinReward, MaxReward, Decimals)
+    let data = (allowed, min_reward, max_reward, decimals);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the payout circuit breaker trips and pauses the contract
+pub fn circuit_tripped(
+    env: &Env,
+    asset: Address,
+    window_start: u64,
+    total_paid: i128,
+    payout_count: u32,
+    limit: i128,
+) {
+    // Topics: [EventName, Asset]
+    let topics = (TOPIC_CIRCUIT_TRIPPED, asset);
+    // Data: (WindowStart, TotalPaid, PayoutCount, Limit)
+    let data = (window_start, total_paid, payout_count, limit);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin configures or removes a circuit breaker for an asset
+pub fn circuit_configured(
+    env: &Env,
+    by: Address,
+    asset: Address,
+    window_seconds: u64,
+    max_amount: i128,
+    max_bps_of_balance: u32,
+) {
+    let topics = (TOPIC_CIRCUIT_CONFIGURED, asset, by);
+    let data = (window_seconds, max_amount, max_bps_of_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin pauses individual operation categories
+pub fn operations_paused(env: &Env, by: Address, categories: u32, flags: u32) {
+    let topics = (TOPIC_OPS_PAUSED, by);
+    let data = (categories, flags);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin resumes individual operation categories
+pub fn operations_unpaused(env: &Env, by: Address, categories: u32, flags: u32) {
+    let topics = (TOPIC_OPS_UNPAUSED, by);
+    let data = (categories, flags);
+    env.events().publish(topics, data);
+}
+
 /// Emit when emergency withdrawal happens
 pub fn emergency_withdrawn(env: &Env, by: Address, asset: Address, to: Address, amount: i128) {
     let topics = (TOPIC_EMERGENCY_WITHDRAW, by.clone());
@@ -54,6 +152,13 @@ pub fn emergency_withdrawn(env: &Env, by: Address, asset: Address, to: Address,
     env.events().publish(topics, data);
 }
 
+/// Emit when tokens taken by an emergency withdrawal are returned to the contract
+pub fn recovered_returned(env: &Env, from: Address, asset: Address, amount: i128, remaining: i128) {
+    let topics = (TOPIC_RECOVERED_RETURNED, asset, from);
+    let data = (amount, remaining);
+    env.events().publish(topics, data);
+}
+
 /// Emit when an admin approves unpause
 pub fn unpause_approved(env: &Env, admin: Address) {
     let topics = (TOPIC_UNPAUSE_APPROVED, admin.clone());
@@ -68,13 +173,94 @@ pub fn timelock_scheduled(env: &Env, scheduled_time: u64) {
     env.events().publish(topics, data);
 }
 
-/// Emit when a user submits a proof
-pub fn proof_submitted(
+/// Emit when an admin proposes a new recovery address, treasurer, contract wasm,
+/// surplus sweep or admin set change
+pub fn proposed(
     env: &Env,
-    quest_id: Symbol,
-    submitter: Address,
-    proof_hash: BytesN<32>,
+    target: ProposalTarget,
+    proposal_id: u32,
+    by: Address,
+    value: ProposalValue,
+) {
+    let name = match target {
+        ProposalTarget::RecoveryAddress => TOPIC_RECOVERY_PROPOSED,
+        ProposalTarget::Treasurer => TOPIC_TREASURER_PROPOSED,
+        ProposalTarget::ContractCode => TOPIC_UPGRADE_PROPOSED,
+        ProposalTarget::SurplusSweep => TOPIC_SWEEP_PROPOSED,
+        ProposalTarget::AddAdmin => TOPIC_ADD_ADMIN_PROPOSED,
+        ProposalTarget::RemoveAdmin => TOPIC_REMOVE_ADMIN_PROPOSED,
+    };
+    let topics = (name, proposal_id, by);
+    let data = (value,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin approves a pending proposal
+pub fn proposal_approved(
+    env: &Env,
+    target: ProposalTarget,
+    proposal_id: u32,
+    admin: Address,
+    approvals: u32,
 ) {
+    let name = match target {
+        ProposalTarget::RecoveryAddress => TOPIC_RECOVERY_APPROVED,
+        ProposalTarget::Treasurer => TOPIC_TREASURER_APPROVED,
+        ProposalTarget::ContractCode => TOPIC_UPGRADE_APPROVED,
+        ProposalTarget::SurplusSweep => TOPIC_SWEEP_APPROVED,
+        ProposalTarget::AddAdmin => TOPIC_ADD_ADMIN_APPROVED,
+        ProposalTarget::RemoveAdmin => TOPIC_REMOVE_ADMIN_APPROVED,
+    };
+    let topics = (name, proposal_id, admin);
+    let data = (approvals,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a proposal reaches threshold and its timelock starts
+pub fn proposal_scheduled(env: &Env, target: ProposalTarget, proposal_id: u32, executable_at: u64) {
+    let name = match target {
+        ProposalTarget::RecoveryAddress => TOPIC_RECOVERY_SCHEDULED,
+        ProposalTarget::Treasurer => TOPIC_TREASURER_SCHEDULED,
+        ProposalTarget::ContractCode => TOPIC_UPGRADE_SCHEDULED,
+        ProposalTarget::SurplusSweep => TOPIC_SWEEP_SCHEDULED,
+        ProposalTarget::AddAdmin => TOPIC_ADD_ADMIN_SCHEDULED,
+        ProposalTarget::RemoveAdmin => TOPIC_REMOVE_ADMIN_SCHEDULED,
+    };
+    let topics = (name, proposal_id);
+    let data = (executable_at,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the contract wasm is replaced
+pub fn contract_upgraded(env: &Env, by: Address, wasm_hash: BytesN<32>) {
+    let topics = (TOPIC_UPGRADED, by);
+    let data = (wasm_hash,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin is added through an executed proposal
+pub fn admin_added(env: &Env, by: Address, admin: Address) {
+    let topics = (TOPIC_ADMIN_ADDED, by);
+    let data = (admin,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin is removed through an executed proposal
+pub fn admin_removed(env: &Env, by: Address, admin: Address) {
+    let topics = (TOPIC_ADMIN_REMOVED, by);
+    let data = (admin,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the recovery address is changed
+pub fn recovery_address_set(env: &Env, by: Address, address: Address) {
+    let topics = (TOPIC_RECOVERY_SET, by);
+    let data = (address,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a user submits a proof
+pub fn proof_submitted(env: &Env, quest_id: Symbol, submitter: Address, proof_hash: BytesN<32>) {
     // Topics: [EventName, QuestID, Submitter]
     let topics = (TOPIC_PROOF_SUBMITTED, quest_id, submitter);
     // Data: (ProofHash)
@@ -83,12 +269,7 @@ pub fn proof_submitted(
 }
 
 /// Emit when a verifier approves a submission
-pub fn submission_approved(
-    env: &Env,
-    quest_id: Symbol,
-    submitter: Address,
-    verifier: Address,
-) {
+pub fn submission_approved(env: &Env, quest_id: Symbol, submitter: Address, verifier: Address) {
     // Topics: [EventName, QuestID, Submitter]
     let topics = (TOPIC_SUBMISSION_APPROVED, quest_id, submitter);
     // Data: (Verifier)
@@ -96,6 +277,15 @@ pub fn submission_approved(
     env.events().publish(topics, data);
 }
 
+/// Emit when a verifier rejects a submission
+pub fn submission_rejected(env: &Env, quest_id: Symbol, submitter: Address, verifier: Address) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_SUBMISSION_REJECTED, quest_id, submitter);
+    // Data: (Verifier)
+    let data = (verifier,);
+    env.events().publish(topics, data);
+}
+
 /// Emit when a user claims their reward
 pub fn reward_claimed(
     env: &Env,
@@ -112,13 +302,7 @@ pub fn reward_claimed(
 }
 
 /// Emit when XP is awarded to a user
-pub fn xp_awarded(
-    env: &Env,
-    user: Address,
-    xp_amount: u64,
-    total_xp: u64,
-    level: u32,
-) {
+pub fn xp_awarded(env: &Env, user: Address, xp_amount: u64, total_xp: u64, level: u32) {
     // Topics: [EventName, User]
     let topics = (TOPIC_XP_AWARDED, user);
     // Data: (XP Amount, Total XP, Level)
@@ -127,11 +311,7 @@ pub fn xp_awarded(
 }
 
 /// Emit when a user levels up
-pub fn level_up(
-    env: &Env,
-    user: Address,
-    new_level: u32,
-) {
+pub fn level_up(env: &Env, user: Address, new_level: u32) {
     // Topics: [EventName, User]
     let topics = (TOPIC_LEVEL_UP, user);
     // Data: (New Level)
@@ -140,11 +320,7 @@ pub fn level_up(
 }
 
 /// Emit when a badge is granted to a user
-pub fn badge_granted(
-    env: &Env,
-    user: Address,
-    badge: Badge,
-) {
+pub fn badge_granted(env: &Env, user: Address, badge: Badge) {
     // Topics: [EventName, User]
     let topics = (TOPIC_BADGE_GRANTED, user);
     // Data: (Badge)
@@ -156,6 +332,46 @@ const TOPIC_ESCROW_DEPOSITED: Symbol = symbol_short!("esc_dep");
 const TOPIC_ESCROW_PAYOUT: Symbol = symbol_short!("esc_pay");
 const TOPIC_ESCROW_REFUNDED: Symbol = symbol_short!("esc_ref");
 const TOPIC_QUEST_CANCELLED: Symbol = symbol_short!("q_cancel");
+const TOPIC_QUEST_FUNDED: Symbol = symbol_short!("q_funded");
+const TOPIC_FUNDING_SET: Symbol = symbol_short!("fund_req");
+const TOPIC_FEE_CHARGED: Symbol = symbol_short!("fee_chg");
+const TOPIC_FEES_WITHDRAWN: Symbol = symbol_short!("fee_wdraw");
+const TOPIC_FEE_CONFIGURED: Symbol = symbol_short!("fee_cfg");
+const TOPIC_TREASURER_SET: Symbol = symbol_short!("treas_set");
+const TOPIC_SURPLUS_SWEPT: Symbol = symbol_short!("swept");
+const TOPIC_SPONSOR_POLICY: Symbol = symbol_short!("spons_cfg");
+const TOPIC_SPONSOR_ALLOWED: Symbol = symbol_short!("spons_ok");
+const TOPIC_CAMPAIGN_CREATED: Symbol = symbol_short!("camp_new");
+const TOPIC_CAMPAIGN_MANAGER: Symbol = symbol_short!("camp_mgr");
+const TOPIC_CAMPAIGN_DEPOSITED: Symbol = symbol_short!("camp_dep");
+const TOPIC_CAMPAIGN_DRAWN: Symbol = symbol_short!("camp_draw");
+const TOPIC_CAMPAIGN_RETURNED: Symbol = symbol_short!("camp_ret");
+const TOPIC_CAMPAIGN_WITHDRAWN: Symbol = symbol_short!("camp_wdrw");
+const TOPIC_MERKLE_ROOT_POSTED: Symbol = symbol_short!("root_post");
+const TOPIC_ATTESTOR_SET: Symbol = symbol_short!("attst_set");
+const TOPIC_ATTESTED: Symbol = symbol_short!("attested");
+const TOPIC_ANSWER_SALT: Symbol = symbol_short!("ans_salt");
+const TOPIC_PROOF_POLICY_SET: Symbol = symbol_short!("proof_cfg");
+const TOPIC_DUPLICATE_PROOF: Symbol = symbol_short!("proof_dup");
+const TOPIC_PREREQUISITES_SET: Symbol = symbol_short!("prereq");
+const TOPIC_ACCESS_MODE_SET: Symbol = symbol_short!("access");
+const TOPIC_PARTICIPANTS_SET: Symbol = symbol_short!("allowlist");
+const TOPIC_INVITES_SET: Symbol = symbol_short!("invites");
+const TOPIC_INVITE_REDEEMED: Symbol = symbol_short!("inv_used");
+const TOPIC_ADDRESS_FLAGGED: Symbol = symbol_short!("flagged");
+const TOPIC_ADDRESS_UNFLAGGED: Symbol = symbol_short!("unflagged");
+const TOPIC_SUBMISSION_VOIDED: Symbol = symbol_short!("sub_void");
+const TOPIC_FRAUD_PENALTY_SET: Symbol = symbol_short!("fraud_pen");
+const TOPIC_BOND_CONFIG_SET: Symbol = symbol_short!("bond_cfg");
+const TOPIC_BOND_POSTED: Symbol = symbol_short!("bond_post");
+const TOPIC_BOND_REFUNDED: Symbol = symbol_short!("bond_ref");
+const TOPIC_BOND_SLASHED: Symbol = symbol_short!("bond_slsh");
+const TOPIC_CREATION_DEPOSIT_SET: Symbol = symbol_short!("crt_cfg");
+const TOPIC_QUEST_CAP_SET: Symbol = symbol_short!("quest_cap");
+const TOPIC_CREATION_CHARGED: Symbol = symbol_short!("crt_paid");
+const TOPIC_CREATION_RETURNED: Symbol = symbol_short!("crt_ret");
+const TOPIC_LOTTERY_SET: Symbol = symbol_short!("lottery");
+const TOPIC_LOTTERY_DRAWN: Symbol = symbol_short!("lot_draw");
 
 /// Emit when tokens are deposited into escrow
 pub fn escrow_deposited(
@@ -183,26 +399,467 @@ pub fn escrow_payout(
     env.events().publish(topics, data);
 }
 
-/// Emit when remaining escrow is refunded to creator
-pub fn escrow_refunded(
+/// Emit when remaining escrow is refunded, once per depositor
+pub fn escrow_refunded(env: &Env, quest_id: Symbol, recipient: Address, amount: i128) {
+    let topics = (TOPIC_ESCROW_REFUNDED, quest_id, recipient);
+    let data = (amount,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an Unfunded quest's escrow meets the funding requirement and it opens
+pub fn quest_funded(env: &Env, quest_id: Symbol, escrow_balance: i128) {
+    let topics = (TOPIC_QUEST_FUNDED, quest_id);
+    let data = (escrow_balance,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a verifier posts a Merkle root of approved rewards
+pub fn merkle_root_posted(
     env: &Env,
     quest_id: Symbol,
-    recipient: Address,
+    round_id: u32,
+    root: BytesN<32>,
+    leaf_count: u32,
+    total_amount: i128,
+) {
+    let topics = (TOPIC_MERKLE_ROOT_POSTED, quest_id, round_id);
+    let data = (root, leaf_count, total_amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator registers or clears the quest's attestor key
+pub fn attestor_set(env: &Env, quest_id: Symbol, creator: Address, attestor: Option<BytesN<32>>) {
+    let topics = (TOPIC_ATTESTOR_SET, quest_id, creator);
+    let data = (attestor,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a submission is approved by a signed attestation
+pub fn attested(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    proof_hash: BytesN<32>,
+    nonce: u64,
+) {
+    let topics = (TOPIC_ATTESTED, quest_id, submitter);
+    let data = (proof_hash, nonce);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator publishes the salt of an answer-hash quest
+pub fn answer_salt_published(env: &Env, quest_id: Symbol, creator: Address, salt: Bytes) {
+    let topics = (TOPIC_ANSWER_SALT, quest_id, creator);
+    let data = (salt,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator changes the quest's proof-hash uniqueness rules
+pub fn proof_policy_set(env: &Env, quest_id: Symbol, creator: Address, policy: ProofPolicy) {
+    let topics = (TOPIC_PROOF_POLICY_SET, quest_id, creator);
+    let data = (policy,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a submission is flagged for reusing an earlier submission's proof hash
+pub fn duplicate_proof_flagged(env: &Env, quest_id: Symbol, submitter: Address, first: ProofUse) {
+    let topics = (TOPIC_DUPLICATE_PROOF, quest_id, submitter);
+    let data = (first.quest_id, first.submitter);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator sets or clears the quest's prerequisites
+pub fn prerequisites_set(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    prerequisites: Option<Prerequisites>,
+) {
+    let topics = (TOPIC_PREREQUISITES_SET, quest_id, creator);
+    let data = (prerequisites,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator changes who may submit proof
+pub fn access_mode_set(env: &Env, quest_id: Symbol, creator: Address, mode: AccessMode) {
+    let topics = (TOPIC_ACCESS_MODE_SET, quest_id, creator);
+    let data = (mode,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator admits or removes a batch of participants
+pub fn participants_set(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    participants: Vec<Address>,
+    admitted: bool,
+) {
+    let topics = (TOPIC_PARTICIPANTS_SET, quest_id, creator);
+    let data = (participants, admitted);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator stores or revokes a batch of invite code hashes
+pub fn invites_set(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    code_hashes: Vec<BytesN<32>>,
+    available: bool,
+) {
+    let topics = (TOPIC_INVITES_SET, quest_id, creator);
+    let data = (code_hashes, available);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a submitter redeems an invite code
+pub fn invite_redeemed(env: &Env, quest_id: Symbol, submitter: Address, code_hash: BytesN<32>) {
+    let topics = (TOPIC_INVITE_REDEEMED, quest_id, submitter);
+    let data = (code_hash,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a campaign is created
+pub fn campaign_created(env: &Env, campaign_id: Symbol, owner: Address, asset: Address) {
+    let topics = (TOPIC_CAMPAIGN_CREATED, campaign_id, owner);
+    let data = (asset,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a campaign manager is added or removed
+pub fn campaign_manager_set(env: &Env, campaign_id: Symbol, manager: Address, allowed: bool) {
+    let topics = (TOPIC_CAMPAIGN_MANAGER, campaign_id, manager);
+    let data = (allowed,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when tokens are deposited into a campaign pool
+pub fn campaign_deposited(
+    env: &Env,
+    campaign_id: Symbol,
+    depositor: Address,
     amount: i128,
+    pool_balance: i128,
 ) {
-    let topics = (TOPIC_ESCROW_REFUNDED, quest_id, recipient);
+    let topics = (TOPIC_CAMPAIGN_DEPOSITED, campaign_id, depositor);
+    let data = (amount, pool_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest draws a reward from its campaign pool
+pub fn campaign_drawn(
+    env: &Env,
+    campaign_id: Symbol,
+    quest_id: Symbol,
+    amount: i128,
+    pool_balance: i128,
+) {
+    let topics = (TOPIC_CAMPAIGN_DRAWN, campaign_id, quest_id);
+    let data = (amount, pool_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an ended quest returns its leftover escrow to the campaign pool
+pub fn campaign_returned(
+    env: &Env,
+    campaign_id: Symbol,
+    quest_id: Symbol,
+    amount: i128,
+    pool_balance: i128,
+) {
+    let topics = (TOPIC_CAMPAIGN_RETURNED, campaign_id, quest_id);
+    let data = (amount, pool_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a campaign owner withdraws the pool and closes the campaign
+pub fn campaign_withdrawn(env: &Env, campaign_id: Symbol, owner: Address, amount: i128) {
+    let topics = (TOPIC_CAMPAIGN_WITHDRAWN, campaign_id, owner);
     let data = (amount,);
     env.events().publish(topics, data);
 }
 
+/// Emit when a creator changes a quest's sponsorship rules
+pub fn sponsor_policy_set(env: &Env, quest_id: Symbol, by: Address, policy: SponsorPolicy) {
+    let topics = (TOPIC_SPONSOR_POLICY, quest_id, by);
+    let data = (policy.allowlist_only, policy.refund_policy);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a sponsor is added to or removed from a quest's allowlist
+pub fn sponsor_allowed(env: &Env, quest_id: Symbol, sponsor: Address, allowed: bool) {
+    let topics = (TOPIC_SPONSOR_ALLOWED, quest_id, sponsor);
+    let data = (allowed,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin changes the number of rewards a quest must be funded for
+pub fn funding_requirement_set(env: &Env, by: Address, rewards: u32) {
+    let topics = (TOPIC_FUNDING_SET, by);
+    let data = (rewards,);
+    env.events().publish(topics, data);
+}
+
 /// Emit when a quest is cancelled
-pub fn quest_cancelled(
+pub fn quest_cancelled(env: &Env, quest_id: Symbol, creator: Address, refunded: i128) {
+    let topics = (TOPIC_QUEST_CANCELLED, quest_id, creator);
+    let data = (refunded,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a platform fee is charged
+pub fn fee_charged(
+    env: &Env,
+    quest_id: Symbol,
+    asset: Address,
+    mode: FeeMode,
+    fee: i128,
+    treasury_balance: i128,
+) {
+    // Topics: [EventName, QuestID, Asset]
+    let topics = (TOPIC_FEE_CHARGED, quest_id, asset);
+    // Data: (Mode, Fee, TreasuryBalance)
+    let data = (mode, fee, treasury_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the treasurer withdraws accrued fees
+pub fn fees_withdrawn(env: &Env, treasurer: Address, asset: Address, to: Address, amount: i128) {
+    let topics = (TOPIC_FEES_WITHDRAWN, asset, treasurer);
+    let data = (to, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when untracked tokens are credited to the treasury
+pub fn surplus_swept(env: &Env, by: Address, asset: Address, amount: i128, treasury_balance: i128) {
+    let topics = (TOPIC_SURPLUS_SWEPT, asset, by);
+    let data = (amount, treasury_balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the global or a per-asset fee is configured.
+/// `asset` is `None` for the global configuration, which also sets `mode`.
+pub fn fee_configured(
+    env: &Env,
+    by: Address,
+    asset: Option<Address>,
+    fee_bps: Option<u32>,
+    mode: Option<FeeMode>,
+) {
+    let topics = (TOPIC_FEE_CONFIGURED, by);
+    let data = (asset, fee_bps, mode);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the treasurer is changed
+pub fn treasurer_set(env: &Env, by: Address, treasurer: Address) {
+    let topics = (TOPIC_TREASURER_SET, by);
+    let data = (treasurer,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin updates the persistent entry TTL settings
+pub fn ttl_configured(env: &Env, by: Address, threshold: u32, extend_to: u32) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_TTL_CONFIGURED, by);
+    // Data: (Threshold, ExtendTo)
+    let data = (threshold, extend_to);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin moves legacy instance entries into persistent storage
+pub fn storage_migrated(env: &Env, by: Address, migrated: u32) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_STORAGE_MIGRATED, by);
+    // Data: EntriesMigrated
+    env.events().publish(topics, migrated);
+}
+
+/// Emit when an admin moves legacy open quests without escrow to Unfunded
+pub fn quests_marked_unfunded(env: &Env, by: Address, marked: u32) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_QUESTS_UNFUNDED, by);
+    // Data: QuestsMarked
+    env.events().publish(topics, marked);
+}
+
+/// Emit when a finished quest is pruned from storage.
+/// This is the last on-chain record of the quest's outcome.
+pub fn quest_archived(
     env: &Env,
     quest_id: Symbol,
     creator: Address,
-    refunded: i128,
+    total_claims: u32,
+    total_paid: i128,
+    total_refunded: i128,
 ) {
-    let topics = (TOPIC_QUEST_CANCELLED, quest_id, creator);
-    let data = (refunded,);
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_QUEST_ARCHIVED, quest_id, creator);
+    // Data: (Claims, TotalPaid, TotalRefunded)
+    let data = (total_claims, total_paid, total_refunded);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin denylists an address for fraud
+pub fn address_flagged(env: &Env, by: Address, user: Address, voided: u32, xp_penalty: u64) {
+    // Topics: [EventName, Admin, User]
+    let topics = (TOPIC_ADDRESS_FLAGGED, by, user);
+    // Data: (SubmissionsVoided, XpRemoved)
+    let data = (voided, xp_penalty);
     env.events().publish(topics, data);
-}
\ No newline at end of file
+}
+
+/// Emit when an admin removes an address from the denylist
+pub fn address_unflagged(env: &Env, by: Address, user: Address) {
+    // Topics: [EventName, Admin, User]
+    let topics = (TOPIC_ADDRESS_UNFLAGGED, by, user);
+    env.events().publish(topics, ());
+}
+
+/// Emit when a flagged address's pending or approved submission is voided
+pub fn submission_voided(env: &Env, quest_id: Symbol, submitter: Address, by: Address) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_SUBMISSION_VOIDED, quest_id, submitter);
+    // Data: (Admin)
+    let data = (by,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin changes the XP penalty for flagged addresses
+pub fn fraud_penalty_set(env: &Env, by: Address, xp: u64) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_FRAUD_PENALTY_SET, by);
+    // Data: XpPenalty
+    env.events().publish(topics, xp);
+}
+
+/// Emit when a quest creator sets or clears the submission bond
+pub fn bond_config_set(env: &Env, quest_id: Symbol, by: Address, config: Option<BondConfig>) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_BOND_CONFIG_SET, quest_id, by);
+    // Data: (BondConfig)
+    let data = (config,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a submitter posts a bond with their proof
+pub fn bond_posted(env: &Env, quest_id: Symbol, submitter: Address, asset: Address, amount: i128) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_BOND_POSTED, quest_id, submitter);
+    // Data: (Asset, Amount)
+    let data = (asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a bond is returned to its submitter
+pub fn bond_refunded(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    asset: Address,
+    amount: i128,
+) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_BOND_REFUNDED, quest_id, submitter);
+    // Data: (Asset, Amount)
+    let data = (asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a bond is slashed for a spam submission
+pub fn bond_slashed(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    asset: Address,
+    amount: i128,
+    slash_to: SlashTarget,
+) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_BOND_SLASHED, quest_id, submitter);
+    // Data: (Asset, Amount, SlashTarget)
+    let data = (asset, amount, slash_to);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin sets or clears the quest creation deposit
+pub fn creation_deposit_set(env: &Env, by: Address, deposit: Option<CreationDeposit>) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_CREATION_DEPOSIT_SET, by);
+    // Data: (CreationDeposit)
+    let data = (deposit,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin changes the per-creator cap on non-terminal quests
+pub fn quest_cap_set(env: &Env, by: Address, max_active: u32) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_QUEST_CAP_SET, by);
+    // Data: MaxActive
+    env.events().publish(topics, max_active);
+}
+
+/// Emit when a creator pays the creation deposit or fee for a quest
+pub fn creation_charged(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    asset: Address,
+    amount: i128,
+    refundable: bool,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_CREATION_CHARGED, quest_id, creator);
+    // Data: (Asset, Amount, Refundable)
+    let data = (asset, amount, refundable);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a refundable creation deposit is returned to the creator
+pub fn creation_returned(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    asset: Address,
+    amount: i128,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_CREATION_RETURNED, quest_id, creator);
+    // Data: (Asset, Amount)
+    let data = (asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest creator makes a quest a lottery
+pub fn lottery_set(env: &Env, quest_id: Symbol, by: Address, winners: u32) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_LOTTERY_SET, quest_id, by);
+    // Data: Winners
+    env.events().publish(topics, winners);
+}
+
+/// Emit when a lottery quest draws its winners.
+/// `seed` lets anyone replay the shuffle of the approved submitters.
+pub fn lottery_drawn(
+    env: &Env,
+    quest_id: Symbol,
+    by: Address,
+    seed: BytesN<32>,
+    winners: Vec<Address>,
+    entrants: u32,
+) {
+    // Topics: [EventName, QuestID, Caller]
+    let topics = (TOPIC_LOTTERY_DRAWN, quest_id, by);
+    // Data: (Seed, Winners, Entrants)
+    let data = (seed, winners, entrants);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin changes the retention period for terminal quests
+pub fn retention_set(env: &Env, by: Address, seconds: u64) {
+    // Topics: [EventName, Admin]
+    let topics = (TOPIC_RETENTION_SET, by);
+    // Data: Seconds
+    env.events().publish(topics, seconds);
+}
@@ -0,0 +1,179 @@
+//! Fees module — platform fee taken from escrow deposits or reward payouts.
+//!
+//! MONEY FLOW:
+//!
+//!   OnDeposit:      Creator deposit  →  escrow (amount - fee) + treasury (fee)
+//!   OnPayout:       Escrow reward    →  submitter (reward - fee) + treasury (fee)
+//!   withdraw_fees:  Treasury balance →  any address (Treasurer admin only)
+//!
+//! The Treasurer is appointed through the same M-of-N + timelock proposal
+//! flow as the recovery address.
+//!
+//! Fee tokens never leave the contract until withdrawn; the treasury balance
+//! per asset is pure accounting on top of the contract's token balance.
+
+use soroban_sdk::{token, Address, Env, Symbol};
+
+use crate::admin;
+use crate::errors::Error;
+use crate::events;
+use crate::security;
+use crate::storage;
+use crate::types::{EscrowInfo, FeeConfig, FeeMode, ProposalTarget, ProposalValue};
+use crate::validation;
+
+/// Basis-point denominator
+const BPS_DENOMINATOR: i128 = 10_000;
+
+// ═══════════════════════════════════════════════════════════════
+// CONFIGURATION: Admin-managed fee settings
+// ═══════════════════════════════════════════════════════════════
+
+/// Set the global fee rate and charging mode (admin only).
+/// The mode applies to escrows created afterwards; existing escrows keep theirs.
+pub fn set_fee_config(
+    env: &Env,
+    caller: &Address,
+    fee_bps: u32,
+    mode: FeeMode,
+) -> Result<(), Error> {
+    admin::require_admin(env, caller)?;
+    validation::validate_fee_bps(fee_bps)?;
+
+    storage::set_fee_config(env, &FeeConfig { fee_bps, mode });
+    events::fee_configured(env, caller.clone(), None, Some(fee_bps), Some(mode));
+    Ok(())
+}
+
+/// Override the fee rate for one asset (admin only). `None` removes the override.
+pub fn set_asset_fee_bps(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    fee_bps: Option<u32>,
+) -> Result<(), Error> {
+    admin::require_admin(env, caller)?;
+    if let Some(bps) = fee_bps {
+        validation::validate_fee_bps(bps)?;
+    }
+
+    storage::set_asset_fee_bps(env, asset, fee_bps);
+    events::fee_configured(env, caller.clone(), Some(asset.clone()), fee_bps, None);
+    Ok(())
+}
+
+/// Propose a new Treasurer (admin only). The proposed address must be an admin.
+///
+/// Like the recovery address, the Treasurer only changes once the unpause
+/// threshold of admins approve and the unpause timelock has passed, so a
+/// single admin cannot appoint themselves and withdraw the fees.
+pub fn propose_treasurer(env: &Env, caller: &Address, treasurer: &Address) -> Result<u32, Error> {
+    if !storage::is_admin(env, treasurer) {
+        return Err(Error::Unauthorized);
+    }
+    security::propose(
+        env,
+        caller,
+        ProposalTarget::Treasurer,
+        ProposalValue::Address(treasurer.clone()),
+    )
+}
+
+/// Approve the pending Treasurer proposal (admin only).
+pub fn approve_treasurer(env: &Env, caller: &Address) -> Result<(), Error> {
+    security::approve(env, caller, ProposalTarget::Treasurer)
+}
+
+/// Apply the pending Treasurer proposal once approvals are met and the
+/// timelock expired. The proposed address must still be an admin.
+pub fn execute_treasurer(env: &Env, caller: &Address) -> Result<(), Error> {
+    let treasurer = security::take_executable_address(env, caller, ProposalTarget::Treasurer)?;
+    if !storage::is_admin(env, &treasurer) {
+        return Err(Error::Unauthorized);
+    }
+
+    storage::set_treasurer(env, &treasurer);
+    events::treasurer_set(env, caller.clone(), treasurer);
+    Ok(())
+}
+
+/// Fee rate for an asset: the per-asset override if set, else the global rate.
+pub fn effective_fee_bps(env: &Env, asset: &Address) -> u32 {
+    storage::get_asset_fee_bps(env, asset).unwrap_or_else(|| storage::get_fee_config(env).fee_bps)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// CHARGING: Compute and accrue fees
+// ═══════════════════════════════════════════════════════════════
+
+/// Fee owed on `amount` moving into or out of `escrow` at the given charging point.
+/// Returns 0 when the escrow is not charged at `mode`.
+pub fn fee_for(env: &Env, escrow: &EscrowInfo, amount: i128, mode: FeeMode) -> i128 {
+    if escrow.fee_mode != mode {
+        return 0;
+    }
+    amount * effective_fee_bps(env, &escrow.token) as i128 / BPS_DENOMINATOR
+}
+
+/// Charging point for escrows created now: the globally configured mode.
+pub fn current_mode(env: &Env) -> FeeMode {
+    storage::get_fee_config(env).mode
+}
+
+/// Credit a charged fee to the asset's treasury balance and emit `fee_charged`.
+pub fn accrue(env: &Env, quest_id: &Symbol, asset: &Address, mode: FeeMode, fee: i128) {
+    if fee <= 0 {
+        return;
+    }
+
+    let balance = storage::get_treasury_balance(env, asset) + fee;
+    storage::set_treasury_balance(env, asset, balance);
+    events::fee_charged(env, quest_id.clone(), asset.clone(), mode, fee, balance);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// WITHDRAW: Treasurer takes accrued fees out
+// ═══════════════════════════════════════════════════════════════
+
+/// Withdraw accrued fees for an asset.
+///
+/// # Requirements
+/// - Caller must be the designated Treasurer and still an admin
+/// - Amount must not exceed the asset's treasury balance
+///
+/// # Flow
+/// ```text
+/// Contract  ──(amount)──►  to
+/// Treasury balance -= amount
+/// ```
+pub fn withdraw_fees(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    match storage::get_treasurer(env) {
+        Some(treasurer) if treasurer == *caller && storage::is_admin(env, caller) => {}
+        _ => return Err(Error::Unauthorized),
+    }
+
+    validation::validate_reward_amount(amount)?;
+
+    let balance = storage::get_treasury_balance(env, asset);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let token_client = token::Client::new(env, asset);
+    match token_client.try_transfer(&env.current_contract_address(), to, &amount) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+
+    storage::set_treasury_balance(env, asset, balance - amount);
+    events::fees_withdrawn(env, caller.clone(), asset.clone(), to.clone(), amount);
+    Ok(())
+}
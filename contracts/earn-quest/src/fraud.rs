@@ -0,0 +1,159 @@
+//! Fraud denylist — admins flag sybil or farming addresses so they can no
+//! longer submit, claim or fund escrow, optionally voiding their open
+//! submissions and removing XP.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::admin;
+use crate::bond;
+use crate::campaign;
+use crate::errors::Error;
+use crate::events;
+use crate::reputation;
+use crate::storage::{self, QuestIndex};
+use crate::types::{FraudFlag, SubmissionStatus};
+use crate::validation;
+
+/// Fail with `AddressDenylisted` if `user` has been flagged.
+pub fn check(env: &Env, user: &Address) -> Result<(), Error> {
+    if storage::is_denylisted(env, user) {
+        return Err(Error::AddressDenylisted);
+    }
+    Ok(())
+}
+
+/// Denylist `user` (admin only).
+///
+/// If `void_submissions` is set, the user's Pending and Approved submissions
+/// on the first `validation::MAX_VOID_SUBMISSIONS` quests they submitted to
+/// are rejected (see `void_submissions` for the rest); bonds on pending
+/// submissions are slashed and approvals on campaign quests return their
+/// drawn reward to the pool. The configured XP penalty is applied the first
+/// time an address is flagged. Returns the number of submissions voided.
+pub fn flag(
+    env: &Env,
+    caller: &Address,
+    user: &Address,
+    void_submissions: bool,
+) -> Result<u32, Error> {
+    admin::require_admin(env, caller)?;
+
+    let previous = storage::get_fraud_flag(env, user);
+    let xp_penalty = match &previous {
+        Some(previous) => previous.xp_penalty,
+        None => reputation::penalize_xp(env, user, storage::get_fraud_xp_penalty(env)),
+    };
+
+    storage::set_fraud_flag(
+        env,
+        user,
+        &FraudFlag {
+            flagged_by: caller.clone(),
+            flagged_at: env.ledger().timestamp(),
+            xp_penalty,
+        },
+    );
+
+    let voided = if void_submissions {
+        void_page(env, caller, user, 0)?.0
+    } else {
+        0
+    };
+
+    let removed = if previous.is_some() { 0 } else { xp_penalty };
+    events::address_flagged(env, caller.clone(), user.clone(), voided, removed);
+
+    Ok(voided)
+}
+
+/// Void a flagged user's open submissions on the quests at positions
+/// `cursor..cursor + validation::MAX_VOID_SUBMISSIONS` of the quests they
+/// submitted to (admin only). Fails with `NotFlagged` unless the user is
+/// on the denylist.
+///
+/// # Returns
+/// * `Some(next)` - More quests remain; call again with `next`
+/// * `None` - Every quest from `cursor` on has been checked
+pub fn void_submissions(
+    env: &Env,
+    caller: &Address,
+    user: &Address,
+    cursor: u32,
+) -> Result<Option<u32>, Error> {
+    admin::require_admin(env, caller)?;
+
+    if !storage::is_denylisted(env, user) {
+        return Err(Error::NotFlagged);
+    }
+
+    Ok(void_page(env, caller, user, cursor)?.1)
+}
+
+/// Reject the user's Pending and Approved submissions on one page of the
+/// quests they submitted to. Returns the number voided and the next cursor,
+/// if quests remain.
+fn void_page(
+    env: &Env,
+    caller: &Address,
+    user: &Address,
+    cursor: u32,
+) -> Result<(u32, Option<u32>), Error> {
+    let index = QuestIndex::Submitter(user.clone());
+    let ids = storage::get_quest_index_page(env, &index, cursor, validation::MAX_VOID_SUBMISSIONS);
+    let mut voided = 0;
+
+    for quest_id in ids.iter() {
+        if void_submission(env, &quest_id, user)? {
+            events::submission_voided(env, quest_id, user.clone(), caller.clone());
+            voided += 1;
+        }
+    }
+
+    let next = cursor.saturating_add(validation::MAX_VOID_SUBMISSIONS);
+    let more = next < storage::get_quest_index_len(env, &index);
+    Ok((voided, more.then_some(next)))
+}
+
+fn void_submission(env: &Env, quest_id: &Symbol, user: &Address) -> Result<bool, Error> {
+    let (Ok(quest), Ok(submission)) = (
+        storage::get_quest(env, quest_id),
+        storage::get_submission(env, quest_id, user),
+    ) else {
+        return Ok(false);
+    };
+
+    match submission.status {
+        SubmissionStatus::Pending => {
+            storage::remove_verifier_pending(env, &quest.verifier, quest_id, user);
+            bond::slash(env, &quest, user)?;
+        }
+        SubmissionStatus::Approved => {
+            campaign::release(env, &quest, quest.reward_amount)?;
+            storage::remove_lottery_entrant(env, quest_id, user);
+        }
+        _ => return Ok(false),
+    }
+
+    storage::update_submission_status(env, quest_id, user, SubmissionStatus::Rejected)?;
+    Ok(true)
+}
+
+/// Remove `user` from the denylist (admin only). XP already removed is not restored.
+pub fn unflag(env: &Env, caller: &Address, user: &Address) -> Result<(), Error> {
+    admin::require_admin(env, caller)?;
+
+    storage::delete_fraud_flag(env, user);
+    events::address_unflagged(env, caller.clone(), user.clone());
+
+    Ok(())
+}
+
+/// Set the XP removed from an address when it is first flagged (admin only).
+pub fn set_xp_penalty(env: &Env, caller: &Address, xp: u64) -> Result<(), Error> {
+    admin::require_admin(env, caller)?;
+
+    storage::set_fraud_xp_penalty(env, xp);
+    events::fraud_penalty_set(env, caller.clone(), xp);
+
+    Ok(())
+}
@@ -1,21 +1,43 @@
 #![no_std]
 
+mod access;
 mod admin;
+pub mod answer;
+mod attestation;
+mod bond;
+mod campaign;
 pub mod errors;
+mod escrow;
 mod events;
-mod security;
+mod fees;
+mod fraud;
+mod lottery;
+pub mod merkle;
 mod payout;
+mod prerequisites;
+mod quest;
 mod reputation;
+mod security;
+mod solvency;
+mod stats;
 pub mod storage;
+mod submission;
 pub mod types;
+mod uniqueness;
 pub mod validation;
-mod quest;
-mod submission;
-mod escrow; 
 
 use crate::errors::Error;
-use crate::types::{Badge, BatchApprovalInput, BatchQuestInput, UserStats, EscrowInfo};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
+use crate::storage::QuestIndex;
+use crate::types::{
+    AccessMode, ActivityStats, AnswerConfig, AssetConfig, AssetEarnings, Attestation, Badge,
+    BatchApprovalInput, BatchQuestInput, Bond, BondConfig, Campaign, CircuitBreakerConfig,
+    CreationDeposit, Eligibility, EscrowContribution, EscrowInfo, FeeConfig, FeeMode, FraudFlag,
+    LotteryDraw, MerkleRound, PayoutWindow, Prerequisites, ProofPolicy, ProofUse, Proposal,
+    ProposalTarget, Quest, QuestStatus, SolvencyReport, SponsorPolicy, Submission, SubmissionKey,
+    SubmissionStatus, TtlConfig, UserQuestRecord, UserStats, PAUSE_APPROVALS, PAUSE_CLAIMS,
+    PAUSE_DEPOSITS, PAUSE_QUEST_REGISTRATION, PAUSE_REFUNDS, PAUSE_SUBMISSIONS,
+};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Symbol, Vec};
 
 #[contract]
 pub struct EarnQuestContract;
@@ -29,23 +51,169 @@ impl EarnQuestContract {
         Ok(())
     }
 
-    /// Add a new admin (admin only)
-    pub fn add_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), Error> {
+    /// Admin: propose adding a new admin; applied with `execute_add_admin`
+    /// after M-of-N approval and the timelock
+    pub fn add_admin(env: Env, caller: Address, new_admin: Address) -> Result<u32, Error> {
         security::require_not_paused(&env)?;
         admin::add_admin(&env, &caller, &new_admin)
     }
 
-    /// Remove an admin (admin only)
-    pub fn remove_admin(env: Env, caller: Address, admin_to_remove: Address) -> Result<(), Error> {
+    /// Admin: approve the pending add-admin proposal
+    pub fn approve_add_admin(env: Env, caller: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        admin::approve_add_admin(&env, &caller)
+    }
+
+    /// Admin: apply the pending add-admin proposal after approvals and timelock
+    pub fn execute_add_admin(env: Env, caller: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        admin::execute_add_admin(&env, &caller)
+    }
+
+    /// Get the pending add-admin proposal
+    pub fn get_pending_admin_add(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::AddAdmin)
+    }
+
+    /// Admin: propose removing an admin; applied with `execute_remove_admin`
+    /// after M-of-N approval and the timelock
+    pub fn remove_admin(env: Env, caller: Address, admin_to_remove: Address) -> Result<u32, Error> {
         security::require_not_paused(&env)?;
         admin::remove_admin(&env, &caller, &admin_to_remove)
     }
 
+    /// Admin: approve the pending remove-admin proposal
+    pub fn approve_remove_admin(env: Env, caller: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        admin::approve_remove_admin(&env, &caller)
+    }
+
+    /// Admin: apply the pending remove-admin proposal after approvals and timelock
+    pub fn execute_remove_admin(env: Env, caller: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        admin::execute_remove_admin(&env, &caller)
+    }
+
+    /// Get the pending remove-admin proposal
+    pub fn get_pending_admin_removal(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::RemoveAdmin)
+    }
+
     /// Check if an address is an admin
     pub fn is_admin(env: Env, address: Address) -> bool {
         admin::is_admin(&env, &address)
     }
 
+    /// Admin: add or update a reward asset in the registry
+    pub fn set_asset_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: AssetConfig,
+    ) -> Result<(), Error> {
+        admin::set_asset_config(&env, &caller, &asset, &config)
+    }
+
+    /// Get the registry entry for a reward asset
+    pub fn get_asset_config(env: Env, asset: Address) -> Option<AssetConfig> {
+        storage::get_asset_config(&env, &asset)
+    }
+
+    /// Admin: update the TTL extension settings for persistent entries
+    pub fn set_ttl_config(env: Env, caller: Address, config: TtlConfig) -> Result<(), Error> {
+        admin::set_ttl_config(&env, &caller, &config)
+    }
+
+    /// Get the TTL extension settings for persistent entries
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        storage::get_ttl_config(&env)
+    }
+
+    /// Admin: extend a quest's storage entries and the contract instance to the full TTL
+    pub fn bump_ttl(env: Env, caller: Address, quest_id: Symbol) -> Result<(), Error> {
+        admin::bump_ttl(&env, &caller, &quest_id)
+    }
+
+    /// Admin: move legacy instance-storage entries into persistent storage
+    pub fn migrate_storage(
+        env: Env,
+        caller: Address,
+        quest_ids: Vec<Symbol>,
+        submissions: Vec<SubmissionKey>,
+        users: Vec<Address>,
+    ) -> Result<u32, Error> {
+        admin::migrate_storage(&env, &caller, &quest_ids, &submissions, &users)
+    }
+
+    /// Admin: propose upgrading to uploaded wasm (requires M-of-N approvals + timelock)
+    pub fn propose_upgrade(env: Env, caller: Address, wasm_hash: BytesN<32>) -> Result<u32, Error> {
+        security::propose_upgrade(&env, &caller, &wasm_hash)
+    }
+
+    /// Admin: approve the pending upgrade proposal
+    pub fn approve_upgrade(env: Env, caller: Address) -> Result<(), Error> {
+        security::approve_upgrade(&env, &caller)
+    }
+
+    /// Admin: replace the contract wasm after approvals and timelock
+    pub fn upgrade(env: Env, caller: Address) -> Result<(), Error> {
+        security::execute_upgrade(&env, &caller)
+    }
+
+    /// Get the pending upgrade proposal
+    pub fn get_pending_upgrade(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::ContractCode)
+    }
+
+    /// Admin: set how many rewards a quest's escrow must cover before it opens
+    pub fn set_funding_requirement(env: Env, caller: Address, rewards: u32) -> Result<(), Error> {
+        admin::set_funding_requirement(&env, &caller, rewards)
+    }
+
+    /// Get how many rewards a quest's escrow must cover before it opens
+    pub fn get_funding_requirement(env: Env) -> u32 {
+        storage::get_min_funded_rewards(&env)
+    }
+
+    /// Admin: set (or clear with `None`) the deposit or fee creators pay for
+    /// each quest they register
+    pub fn set_creation_deposit(
+        env: Env,
+        caller: Address,
+        deposit: Option<CreationDeposit>,
+    ) -> Result<(), Error> {
+        admin::set_creation_deposit(&env, &caller, &deposit)
+    }
+
+    /// Get the deposit or fee creators pay for each quest they register
+    pub fn get_creation_deposit(env: Env) -> Option<CreationDeposit> {
+        storage::get_creation_deposit(&env)
+    }
+
+    /// Get the refundable creation deposit held for a quest
+    pub fn get_quest_creation_deposit(env: Env, quest_id: Symbol) -> Option<CreationDeposit> {
+        storage::get_quest_creation_deposit(&env, &quest_id)
+    }
+
+    /// Admin: cap the number of non-terminal quests per creator (0 = no cap)
+    pub fn set_max_active_quests(env: Env, caller: Address, max: u32) -> Result<(), Error> {
+        admin::set_max_active_quests(&env, &caller, max)
+    }
+
+    /// Get the number of non-terminal quests allowed per creator (0 = no cap)
+    pub fn get_max_active_quests(env: Env) -> u32 {
+        storage::get_max_active_quests(&env)
+    }
+
+    /// Admin: move legacy quests without escrow into the Unfunded state
+    pub fn mark_unfunded_quests(
+        env: Env,
+        caller: Address,
+        quest_ids: Vec<Symbol>,
+    ) -> Result<u32, Error> {
+        admin::mark_unfunded_quests(&env, &caller, &quest_ids)
+    }
+
     /// Register a new quest with full input validation
     pub fn register_quest(
         env: Env,
@@ -56,7 +224,7 @@ impl EarnQuestContract {
         verifier: Address,
         deadline: u64,
     ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
+        security::require_not_paused_for(&env, PAUSE_QUEST_REGISTRATION)?;
         creator.require_auth();
 
         quest::register_quest(
@@ -78,201 +246,1132 @@ impl EarnQuestContract {
         creator: Address,
         quests: Vec<BatchQuestInput>,
     ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
+        security::require_not_paused_for(&env, PAUSE_QUEST_REGISTRATION)?;
         creator.require_auth();
 
         quest::register_quests_batch(&env, &creator, &quests)
     }
 
-    /// Submit proof with input validation
-    pub fn submit_proof(
+    /// Register a quest that checks answers on-chain instead of using a verifier.
+    ///
+    /// `answer_hash` is `sha256(salt || answer)`; the salt stays with the
+    /// creator until `publish_answer_salt`. Submitters commit
+    /// `sha256(answer || submitter)` through `submit_proof` before `reveal_at`,
+    /// then call `reveal_answer` once the salt is published.
+    ///
+    /// # Who can call: Anyone (becomes the quest creator; the contract is the verifier)
+    /// # Requires: `reveal_at` is in the future and before `deadline`
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_answer_quest(
         env: Env,
-        quest_id: Symbol,
-        submitter: Address,
-        proof_hash: BytesN<32>,
+        id: Symbol,
+        creator: Address,
+        reward_asset: Address,
+        reward_amount: i128,
+        deadline: u64,
+        answer_hash: BytesN<32>,
+        reveal_at: u64,
     ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
-        submitter.require_auth();
+        security::require_not_paused_for(&env, PAUSE_QUEST_REGISTRATION)?;
+        creator.require_auth();
 
-        submission::submit_proof(&env, &quest_id, &submitter, &proof_hash)
+        answer::register_quest(
+            &env,
+            &id,
+            &creator,
+            &reward_asset,
+            reward_amount,
+            deadline,
+            &answer_hash,
+            reveal_at,
+        )
     }
 
-    /// Approve submission with status transition validation
-    pub fn approve_submission(
+    /// Publish the salt of an answer-hash quest, opening reveals.
+    ///
+    /// # Who can call: Quest creator
+    /// # Requires: `reveal_at` has passed and the salt is not yet published
+    pub fn publish_answer_salt(
         env: Env,
         quest_id: Symbol,
-        submitter: Address,
-        verifier: Address,
+        creator: Address,
+        salt: Bytes,
     ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
-        verifier.require_auth();
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        creator.require_auth();
 
-        submission::approve_submission(&env, &quest_id, &submitter, &verifier)
+        answer::publish_salt(&env, &quest_id, &creator, &salt)
     }
 
-    /// Approve multiple submissions in one transaction (gas-optimized).
-    /// Verifier must authorize; all items must be for quests where this address is verifier.
-    /// Batch size is limited; on first error the entire batch reverts.
-    pub fn approve_submissions_batch(
+    /// Reveal the answer behind a commitment to an answer-hash quest.
+    /// A correct answer approves the submission; a wrong one rejects it.
+    ///
+    /// # Who can call: The submitter
+    /// # Requires: The reveal window is open, the salt is published and `answer`
+    ///   matches the commitment
+    /// # Returns: Whether the submission was approved
+    pub fn reveal_answer(
         env: Env,
-        verifier: Address,
-        submissions: Vec<BatchApprovalInput>,
-    ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
-        verifier.require_auth();
-
-        submission::approve_submissions_batch(&env, &verifier, &submissions)
-    }
-
-    /// Claim approved reward with full validation
-    pub fn claim_reward(env: Env, quest_id: Symbol, submitter: Address) -> Result<(), Error> {
-        // 1. Auth
-        security::require_not_paused(&env)?;
+        quest_id: Symbol,
+        submitter: Address,
+        answer: Bytes,
+    ) -> Result<bool, Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
         submitter.require_auth();
 
-        // 2. Validate claim (status transitions, limits)
-        submission::validate_claim(&env, &quest_id, &submitter)?;
-
-        // 3. Data Retrieval for payout
-        let quest = storage::get_quest(&env, &quest_id)?;
-
-        // 4. Payout
-        payout::transfer_reward_from_escrow(&env, &quest_id, &quest.reward_asset, &submitter, quest.reward_amount)?;
-        // 5. State Update
-        storage::update_submission_status(
-            &env,
-            &quest_id,
-            &submitter,
-            types::SubmissionStatus::Paid,
-        )?;
-        storage::increment_quest_claims(&env, &quest_id)?;
+        answer::reveal(&env, &quest_id, &submitter, &answer)
+    }
 
-        // EMIT EVENT: RewardClaimed
-        events::reward_claimed(
-            &env,
-            quest_id.clone(),
-            submitter.clone(),
-            quest.reward_asset,
-            quest.reward_amount,
-        );
+    /// Query the expected answer of an answer-hash quest.
+    pub fn get_quest_answer(env: Env, quest_id: Symbol) -> Option<AnswerConfig> {
+        storage::get_quest_answer(&env, &quest_id)
+    }
 
-        // 6. Award XP for quest completion
-        reputation::award_xp(&env, &submitter, 100)?;
+    /// Submit proof with input validation
+    pub fn submit_proof(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_SUBMISSIONS)?;
+        submitter.require_auth();
 
-        Ok(())
+        submission::submit_proof(&env, &quest_id, &submitter, &proof_hash)
     }
 
-    /// Get user reputation stats
-    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
-        reputation::get_user_stats(&env, &user)
+    /// Set whether a quest rejects or flags proof hashes already used by
+    /// another submitter, within the quest or across all quests.
+    ///
+    /// # Who can call: Quest creator only
+    pub fn set_proof_policy(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        policy: ProofPolicy,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        uniqueness::set_policy(&env, &quest_id, &creator, &policy)
     }
 
-    /// Grant a badge to a user (admin only) with array length validation
-    pub fn grant_badge(env: Env, admin: Address, user: Address, badge: Badge) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
+    /// Query a quest's proof-hash uniqueness rules.
+    pub fn get_proof_policy(env: Env, quest_id: Symbol) -> ProofPolicy {
+        storage::get_proof_policy(&env, &quest_id)
+    }
 
-        // Validate badge count before granting
-        let stats = storage::get_user_stats_or_default(&env, &user);
-        validation::validate_badge_count(stats.badges.len())?;
+    /// Query which (quest, submitter) first used a proof hash, in the registry
+    /// the given quest checks against.
+    pub fn get_proof_first_use(
+        env: Env,
+        quest_id: Symbol,
+        proof_hash: BytesN<32>,
+    ) -> Option<ProofUse> {
+        uniqueness::first_use(&env, &quest_id, &proof_hash)
+    }
 
-        reputation::grant_badge(&env, &admin, &user, badge)
+    /// Query the earlier proof use a flagged submission duplicates.
+    pub fn get_duplicate_flag(env: Env, quest_id: Symbol, submitter: Address) -> Option<ProofUse> {
+        storage::get_duplicate_flag(&env, &quest_id, &submitter)
     }
 
-    /// Emergency: pause contract immediately (admin only)
-    pub fn emergency_pause(env: Env, caller: Address) -> Result<(), Error> {
-        security::emergency_pause(&env, &caller)
+    /// Set (or clear with `None`) the quests, level and badges a user needs
+    /// before submitting proof to a quest.
+    ///
+    /// # Who can call: Quest creator only
+    pub fn set_prerequisites(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        prerequisites: Option<Prerequisites>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        prerequisites::set_prerequisites(&env, &quest_id, &creator, &prerequisites)
     }
 
-    /// Emergency: approve unpause (admin multisig approval)
-    pub fn emergency_approve_unpause(env: Env, caller: Address) -> Result<(), Error> {
-        security::emergency_approve_unpause(&env, &caller)
+    /// Query a quest's prerequisites.
+    pub fn get_prerequisites(env: Env, quest_id: Symbol) -> Option<Prerequisites> {
+        storage::get_prerequisites(&env, &quest_id)
     }
 
-    /// Emergency: unpause contract after approvals and timelock
-    pub fn emergency_unpause(env: Env, caller: Address) -> Result<(), Error> {
-        security::emergency_unpause(&env, &caller)
+    /// Explain which of a quest's prerequisites a user is missing.
+    pub fn get_eligibility(env: Env, quest_id: Symbol, user: Address) -> Eligibility {
+        prerequisites::eligibility(&env, &quest_id, &user)
     }
 
-    /// Emergency withdrawal when paused (admin only) with amount validation
-    pub fn emergency_withdraw(
+    /// Redeem an invite code and submit proof in one call.
+    ///
+    /// # Who can call: The submitter
+    /// # Requires: `code` hashes to an unredeemed invite code of the quest;
+    /// the code is consumed and the submitter admitted as a participant
+    pub fn submit_proof_with_invite(
         env: Env,
-        caller: Address,
-        asset: Address,
-        to: Address,
-        amount: i128,
+        quest_id: Symbol,
+        submitter: Address,
+        proof_hash: BytesN<32>,
+        code: Bytes,
     ) -> Result<(), Error> {
-        // Validate withdraw amount range
-        validation::validate_reward_amount(amount)?;
-        security::emergency_withdraw(&env, &caller, &asset, &to, amount)
+        security::require_not_paused_for(&env, PAUSE_SUBMISSIONS)?;
+        submitter.require_auth();
+
+        access::redeem_invite(&env, &quest_id, &submitter, &code)?;
+        submission::submit_proof(&env, &quest_id, &submitter, &proof_hash)
     }
 
-     /// Deposit tokens into escrow for a quest.
-    ///
-    /// The creator sends tokens to the contract, earmarked for this quest.
-    /// Can be called multiple times to add more funds (top-up).
+    /// Set who may submit proof to a quest: anyone, allowlisted participants,
+    /// or submitters holding an invite code.
     ///
     /// # Who can call: Quest creator only
-    /// # Token flow: Creator wallet → Contract
-    pub fn deposit_escrow(
+    pub fn set_access_mode(
         env: Env,
         quest_id: Symbol,
-        depositor: Address,
-        token: Address,
-        amount: i128,
+        creator: Address,
+        mode: AccessMode,
     ) -> Result<(), Error> {
-        security::require_not_paused(&env)?;
-        depositor.require_auth();
-        escrow::deposit(&env, &quest_id, &depositor, &token, amount)
+        creator.require_auth();
+        access::set_mode(&env, &quest_id, &creator, mode)
     }
 
-    /// Cancel a quest and refund remaining escrow to creator.
+    /// Admit or remove a batch of participants.
     ///
     /// # Who can call: Quest creator only
-    /// # Requires: Quest is Active or Paused
-    /// # Token flow: Contract → Creator wallet (remaining balance)
-    /// # Returns: Amount refunded
-    pub fn cancel_quest(
+    pub fn set_participants(
         env: Env,
         quest_id: Symbol,
         creator: Address,
-    ) -> Result<i128, Error> {
-        security::require_not_paused(&env)?;
+        participants: Vec<Address>,
+        admitted: bool,
+    ) -> Result<(), Error> {
         creator.require_auth();
-        escrow::cancel_quest(&env, &quest_id, &creator)
+        access::set_participants(&env, &quest_id, &creator, &participants, admitted)
     }
 
-    /// Withdraw unclaimed escrow from a finished quest.
+    /// Store or revoke a batch of invite codes, given as `sha256(code)`.
     ///
     /// # Who can call: Quest creator only
-    /// # Requires: Quest is Completed, Expired, or Cancelled
-    /// # Token flow: Contract → Creator wallet (remaining balance)
-    /// # Returns: Amount withdrawn
-    pub fn withdraw_unclaimed(
+    pub fn set_invite_codes(
         env: Env,
         quest_id: Symbol,
         creator: Address,
-    ) -> Result<i128, Error> {
-        security::require_not_paused(&env)?;
+        code_hashes: Vec<BytesN<32>>,
+        available: bool,
+    ) -> Result<(), Error> {
         creator.require_auth();
-        escrow::withdraw_unclaimed(&env, &quest_id, &creator)
+        access::set_invite_codes(&env, &quest_id, &creator, &code_hashes, available)
     }
 
-    /// Query the available escrow balance for a quest.
-    pub fn get_escrow_balance(env: Env, quest_id: Symbol) -> Result<i128, Error> {
-        escrow::get_balance(&env, &quest_id)
+    /// Query who may submit proof to a quest.
+    pub fn get_access_mode(env: Env, quest_id: Symbol) -> AccessMode {
+        storage::get_access_mode(&env, &quest_id)
     }
 
-    /// Query the full escrow info for a quest.
-    pub fn get_escrow_info(env: Env, quest_id: Symbol) -> Result<EscrowInfo, Error> {
-        escrow::get_info(&env, &quest_id)
+    /// Check whether a user has been admitted to a quest.
+    pub fn is_participant(env: Env, quest_id: Symbol, user: Address) -> bool {
+        storage::is_participant(&env, &quest_id, &user)
+    }
+
+    /// Check whether an invite code hash is stored and not yet redeemed.
+    pub fn is_invite_code_available(env: Env, quest_id: Symbol, code_hash: BytesN<32>) -> bool {
+        storage::has_invite_code(&env, &quest_id, &code_hash)
+    }
+
+    /// Approve submission with status transition validation
+    pub fn approve_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        verifier.require_auth();
+
+        submission::approve_submission(&env, &quest_id, &submitter, &verifier)
+    }
+
+    /// Reject a pending submission (verifier only)
+    pub fn reject_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        verifier.require_auth();
+
+        submission::reject_submission(&env, &quest_id, &submitter, &verifier, false)
+    }
+
+    /// Reject a pending submission as spam, slashing any bond posted with it
+    /// to the quest creator or the treasury (verifier only)
+    pub fn reject_submission_as_spam(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        verifier.require_auth();
+
+        submission::reject_submission(&env, &quest_id, &submitter, &verifier, true)
+    }
+
+    /// Make a quest a lottery: approvals only make submitters eligible, and
+    /// `winners` of them are drawn after the deadline to claim the reward.
+    ///
+    /// # Who can call: Quest creator only
+    /// # Requires: Quest is still Unfunded
+    pub fn set_lottery(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        winners: u32,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        lottery::set_lottery(&env, &quest_id, &creator, winners)
+    }
+
+    /// Draw a lottery quest's winners from its approved submitters. Approved
+    /// submitters who are not drawn can then claim participation XP.
+    ///
+    /// # Who can call: Quest creator or verifier
+    /// # Requires: Deadline has passed and escrow covers `winners × reward`
+    /// # Returns: The winners, in draw order
+    pub fn draw_winners(
+        env: Env,
+        quest_id: Symbol,
+        caller: Address,
+    ) -> Result<Vec<Address>, Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        caller.require_auth();
+
+        lottery::draw_winners(&env, &quest_id, &caller)
+    }
+
+    /// Claim participation XP for an approved lottery entrant who was not drawn.
+    ///
+    /// # Who can call: The entrant
+    /// # Requires: Winners drawn, submission Approved, not already claimed
+    pub fn claim_participation_xp(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_CLAIMS)?;
+        submitter.require_auth();
+
+        lottery::claim_participation_xp(&env, &quest_id, &submitter)
+    }
+
+    /// Query how many winners a lottery quest draws (`None` if not a lottery).
+    pub fn get_lottery_winners(env: Env, quest_id: Symbol) -> Option<u32> {
+        storage::get_lottery_winners(&env, &quest_id)
+    }
+
+    /// Query the outcome of a lottery quest's draw, including its seed.
+    pub fn get_lottery_draw(env: Env, quest_id: Symbol) -> Option<LotteryDraw> {
+        storage::get_lottery_draw(&env, &quest_id)
+    }
+
+    /// Set (or clear with `None`) the bond submitters must post with their
+    /// proof. Bonds are returned on approval or a clean rejection.
+    ///
+    /// # Who can call: Quest creator only
+    pub fn set_submission_bond(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        config: Option<BondConfig>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        bond::set_config(&env, &quest_id, &creator, &config)
+    }
+
+    /// Query the bond a quest requires with each submission.
+    pub fn get_submission_bond(env: Env, quest_id: Symbol) -> Option<BondConfig> {
+        storage::get_bond_config(&env, &quest_id)
+    }
+
+    /// Query the bond currently held for a submitter.
+    pub fn get_bond(env: Env, quest_id: Symbol, submitter: Address) -> Option<Bond> {
+        storage::get_bond(&env, &quest_id, &submitter)
+    }
+
+    /// Reclaim a bond whose submission was never reviewed before the quest closed.
+    pub fn reclaim_bond(env: Env, quest_id: Symbol, submitter: Address) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_REFUNDS)?;
+        submitter.require_auth();
+
+        bond::reclaim(&env, &quest_id, &submitter)
+    }
+
+    /// Approve multiple submissions in one transaction (gas-optimized).
+    /// Verifier must authorize; all items must be for quests where this address is verifier.
+    /// Batch size is limited; on first error the entire batch reverts.
+    pub fn approve_submissions_batch(
+        env: Env,
+        verifier: Address,
+        submissions: Vec<BatchApprovalInput>,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        verifier.require_auth();
+
+        submission::approve_submissions_batch(&env, &verifier, &submissions)
+    }
+
+    /// Claim approved reward with full validation
+    ///
+    /// # Returns: `true` once paid; `false` if the payout tripped the circuit
+    /// breaker, which pauses the contract and pays nothing (the claim stays open)
+    pub fn claim_reward(env: Env, quest_id: Symbol, submitter: Address) -> Result<bool, Error> {
+        // 1. Auth
+        security::require_not_paused_for(&env, PAUSE_CLAIMS)?;
+        submitter.require_auth();
+
+        // 2. Validate claim (status transitions, limits)
+        submission::validate_claim(&env, &quest_id, &submitter)?;
+
+        // 3. Data Retrieval for payout
+        let quest = storage::get_quest(&env, &quest_id)?;
+
+        // Circuit breaker: a payout that would trip it pauses the contract instead
+        if !security::admit_payout(&env, &quest.reward_asset, quest.reward_amount) {
+            return Ok(false);
+        }
+
+        // 4. Payout (net of any platform fee)
+        let paid = payout::transfer_reward_from_escrow(
+            &env,
+            &quest_id,
+            &quest.reward_asset,
+            &submitter,
+            quest.reward_amount,
+        )?;
+        // 5. State Update
+        storage::update_submission_status(
+            &env,
+            &quest_id,
+            &submitter,
+            types::SubmissionStatus::Paid,
+        )?;
+        storage::set_completed(&env, &quest_id, &submitter);
+        storage::increment_quest_claims(&env, &quest_id)?;
+
+        // EMIT EVENT: RewardClaimed
+        events::reward_claimed(
+            &env,
+            quest_id.clone(),
+            submitter.clone(),
+            quest.reward_asset.clone(),
+            paid,
+        );
+
+        // 6. Award XP for quest completion and record earnings
+        reputation::award_xp(&env, &submitter, 100)?;
+        reputation::record_earnings(&env, &submitter, &quest.reward_asset, paid);
+        stats::record_claim(&env, &quest.creator, &quest.reward_asset, paid);
+
+        Ok(true)
+    }
+
+    /// Post a Merkle root of `(index, submitter, amount)` leaves approving a cohort.
+    ///
+    /// # Who can call: Quest verifier only
+    /// # Requires: Quest is Active and its escrow covers `total_amount`
+    /// # Returns: Round id to pass to `claim_with_proof`
+    pub fn post_merkle_root(
+        env: Env,
+        quest_id: Symbol,
+        verifier: Address,
+        root: BytesN<32>,
+        leaf_count: u32,
+        total_amount: i128,
+    ) -> Result<u32, Error> {
+        security::require_not_paused_for(&env, PAUSE_APPROVALS)?;
+        verifier.require_auth();
+        merkle::post_root(&env, &quest_id, &verifier, &root, leaf_count, total_amount)
+    }
+
+    /// Claim a reward included in a posted Merkle root.
+    ///
+    /// # Who can call: The submitter named in the leaf
+    /// # Token flow: Contract → Submitter wallet (`amount`, net of any payout fee)
+    /// # Returns: `true` once paid; `false` if the payout tripped the circuit
+    /// breaker, which pauses the contract and pays nothing (the leaf stays unclaimed)
+    pub fn claim_with_proof(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        round_id: u32,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<bool, Error> {
+        security::require_not_paused_for(&env, PAUSE_CLAIMS)?;
+        submitter.require_auth();
+        merkle::claim_with_proof(&env, &quest_id, &submitter, round_id, index, amount, &proof)
+    }
+
+    /// Query a posted Merkle round.
+    pub fn get_merkle_round(
+        env: Env,
+        quest_id: Symbol,
+        round_id: u32,
+    ) -> Result<MerkleRound, Error> {
+        storage::get_merkle_round(&env, &quest_id, round_id)
+    }
+
+    /// Check whether a leaf of a Merkle round has been claimed.
+    pub fn is_merkle_claimed(env: Env, quest_id: Symbol, round_id: u32, index: u32) -> bool {
+        storage::is_merkle_claimed(&env, &quest_id, round_id, index)
+    }
+
+    /// Register (or clear with `None`) the ed25519 key that signs attestations
+    /// for a quest.
+    ///
+    /// # Who can call: Quest creator only
+    pub fn set_quest_attestor(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        attestor: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        attestation::set_attestor(&env, &quest_id, &creator, &attestor)
+    }
+
+    /// Approve and pay a submission in one step from an attestor-signed payload.
+    ///
+    /// # Who can call: The submitter named in the payload
+    /// # Requires: `signature` is the quest attestor's ed25519 signature over
+    /// the XDR of `payload`, which is unexpired and carries an unused nonce
+    /// # Token flow: Contract → Submitter wallet (`payload.amount`, net of any payout fee)
+    /// # Returns: `true` once paid; `false` if the payout tripped the circuit
+    /// breaker, which pauses the contract and pays nothing (the nonce stays unused)
+    pub fn claim_with_attestation(
+        env: Env,
+        quest_id: Symbol,
+        payload: Attestation,
+        signature: BytesN<64>,
+    ) -> Result<bool, Error> {
+        security::require_not_paused_for(&env, PAUSE_CLAIMS)?;
+        payload.submitter.require_auth();
+        attestation::claim_with_attestation(&env, &quest_id, &payload, &signature)
+    }
+
+    /// Query a quest's attestor key.
+    pub fn get_quest_attestor(env: Env, quest_id: Symbol) -> Option<BytesN<32>> {
+        storage::get_quest_attestor(&env, &quest_id)
+    }
+
+    /// Check whether an attestation nonce has been used for a quest.
+    pub fn is_attestation_nonce_used(env: Env, quest_id: Symbol, nonce: u64) -> bool {
+        storage::is_attestation_nonce_used(&env, &quest_id, nonce)
+    }
+
+    /// Delete a finished, settled quest after its retention period (permissionless).
+    /// Returns false if submissions remain to be deleted; call again to continue.
+    pub fn prune_quest(env: Env, quest_id: Symbol) -> Result<bool, Error> {
+        security::require_not_paused(&env)?;
+        quest::prune_quest(&env, &quest_id)
+    }
+
+    /// Admin: denylist an address for fraud. It can no longer submit, claim or
+    /// deposit escrow, and loses the configured XP penalty on its first flag.
+    /// With `void_submissions`, its Pending and Approved submissions on the first
+    /// MAX_VOID_SUBMISSIONS quests it submitted to are rejected; continue with
+    /// `void_flagged_submissions`. Returns the number of submissions voided.
+    pub fn flag_address(
+        env: Env,
+        caller: Address,
+        user: Address,
+        void_submissions: bool,
+    ) -> Result<u32, Error> {
+        fraud::flag(&env, &caller, &user, void_submissions)
+    }
+
+    /// Admin: void a flagged address's open submissions on the next page of the
+    /// quests it submitted to, starting at `cursor`.
+    /// Returns the cursor to continue from, or `None` once all quests are checked.
+    pub fn void_flagged_submissions(
+        env: Env,
+        caller: Address,
+        user: Address,
+        cursor: u32,
+    ) -> Result<Option<u32>, Error> {
+        fraud::void_submissions(&env, &caller, &user, cursor)
+    }
+
+    /// Admin: remove an address from the denylist
+    pub fn unflag_address(env: Env, caller: Address, user: Address) -> Result<(), Error> {
+        fraud::unflag(&env, &caller, &user)
+    }
+
+    /// Admin: set the XP removed from an address when it is flagged
+    pub fn set_fraud_xp_penalty(env: Env, caller: Address, xp: u64) -> Result<(), Error> {
+        fraud::set_xp_penalty(&env, &caller, xp)
+    }
+
+    /// Query the fraud flag of an address, if it is denylisted
+    pub fn get_fraud_flag(env: Env, user: Address) -> Option<FraudFlag> {
+        storage::get_fraud_flag(&env, &user)
+    }
+
+    /// Get the XP removed from an address when it is flagged
+    pub fn get_fraud_xp_penalty(env: Env) -> u64 {
+        storage::get_fraud_xp_penalty(&env)
+    }
+
+    /// Admin: set how long terminal quests are retained before they can be pruned
+    pub fn set_retention_period(env: Env, caller: Address, seconds: u64) -> Result<(), Error> {
+        admin::set_retention_period(&env, &caller, seconds)
+    }
+
+    /// Get the retention period for terminal quests, in seconds
+    pub fn get_retention_period(env: Env) -> u64 {
+        storage::get_retention_period(&env)
+    }
+
+    /// Platform-wide quest, submission, claim and escrow counters
+    pub fn get_platform_stats(env: Env) -> ActivityStats {
+        stats::get_platform_stats(&env)
+    }
+
+    /// Quest, submission, claim and escrow counters for one creator's quests
+    pub fn get_creator_stats(env: Env, creator: Address) -> ActivityStats {
+        stats::get_creator_stats(&env, &creator)
+    }
+
+    /// Get a quest by id
+    pub fn get_quest(env: Env, quest_id: Symbol) -> Result<Quest, Error> {
+        storage::get_quest(&env, &quest_id)
+    }
+
+    /// Get a user's submission for a quest
+    pub fn get_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<Submission, Error> {
+        storage::get_submission(&env, &quest_id, &submitter)
+    }
+
+    /// List a quest's submissions in submission order, optionally filtered by status.
+    /// Each call scans `limit` index positions; advance `cursor` by `limit`.
+    pub fn list_submissions(
+        env: Env,
+        quest_id: Symbol,
+        status_filter: Option<SubmissionStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Submission> {
+        submission::list_submissions(&env, &quest_id, &status_filter, cursor, limit)
+    }
+
+    /// List submissions awaiting review by a verifier (page size capped at MAX_PAGE_SIZE)
+    pub fn pending_for_verifier(
+        env: Env,
+        verifier: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Submission> {
+        submission::pending_for_verifier(&env, &verifier, cursor, limit)
+    }
+
+    /// List quests in registration order (page size capped at MAX_PAGE_SIZE)
+    pub fn list_quests(env: Env, cursor: u32, limit: u32) -> Vec<Quest> {
+        quest::list_quests(&env, &QuestIndex::All, cursor, limit)
+    }
+
+    /// List quests created by an address in registration order
+    pub fn list_quests_by_creator(
+        env: Env,
+        creator: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Quest> {
+        quest::list_quests(&env, &QuestIndex::Creator(creator), cursor, limit)
+    }
+
+    /// List quests verified by an address in registration order
+    pub fn list_quests_by_verifier(
+        env: Env,
+        verifier: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Quest> {
+        quest::list_quests(&env, &QuestIndex::Verifier(verifier), cursor, limit)
+    }
+
+    /// List quests currently in a given status
+    pub fn list_quests_by_status(
+        env: Env,
+        status: QuestStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Quest> {
+        quest::list_quests_by_status(&env, &status, cursor, limit)
+    }
+
+    /// Get user reputation stats
+    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
+        reputation::get_user_stats(&env, &user)
+    }
+
+    /// List quests a user has submitted proof for, oldest first, with submission status
+    pub fn get_user_history(
+        env: Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<UserQuestRecord> {
+        reputation::get_user_history(&env, &user, cursor, limit)
+    }
+
+    /// List a user's lifetime earnings per reward asset
+    pub fn get_user_earnings(
+        env: Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<AssetEarnings> {
+        reputation::get_user_earnings(&env, &user, cursor, limit)
+    }
+
+    /// Grant a badge to a user (admin only) with array length validation
+    pub fn grant_badge(env: Env, admin: Address, user: Address, badge: Badge) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+
+        // Validate badge count before granting
+        let stats = storage::get_user_stats_or_default(&env, &user);
+        validation::validate_badge_count(stats.badges.len())?;
+
+        reputation::grant_badge(&env, &admin, &user, badge)
+    }
+
+    /// Emergency: pause contract immediately (admin only)
+    pub fn emergency_pause(env: Env, caller: Address) -> Result<(), Error> {
+        security::emergency_pause(&env, &caller)
+    }
+
+    /// Admin: pause individual operation categories (bitmap of `PAUSE_*` flags)
+    pub fn pause_operations(env: Env, caller: Address, categories: u32) -> Result<u32, Error> {
+        security::pause_operations(&env, &caller, categories)
+    }
+
+    /// Admin: resume individual operation categories (bitmap of `PAUSE_*` flags)
+    pub fn unpause_operations(env: Env, caller: Address, categories: u32) -> Result<u32, Error> {
+        security::unpause_operations(&env, &caller, categories)
+    }
+
+    /// Get the bitmap of individually paused operation categories.
+    /// A global emergency pause blocks every category regardless of this value.
+    pub fn get_pause_flags(env: Env) -> u32 {
+        storage::get_pause_flags(&env)
+    }
+
+    /// Emergency: approve unpause (admin multisig approval)
+    pub fn emergency_approve_unpause(env: Env, caller: Address) -> Result<(), Error> {
+        security::emergency_approve_unpause(&env, &caller)
+    }
+
+    /// Emergency: unpause contract after approvals and timelock
+    pub fn emergency_unpause(env: Env, caller: Address) -> Result<(), Error> {
+        security::emergency_unpause(&env, &caller)
+    }
+
+    /// Emergency withdrawal when paused (admin only) with amount validation.
+    /// Funds always go to the configured recovery address.
+    pub fn emergency_withdraw(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        // Validate withdraw amount range
+        validation::validate_reward_amount(amount)?;
+        security::emergency_withdraw(&env, &caller, &asset, amount)
+    }
+
+    /// Admin: propose a new recovery address (requires M-of-N approvals + timelock)
+    pub fn propose_recovery_address(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<u32, Error> {
+        security::propose_recovery_address(&env, &caller, &address)
+    }
+
+    /// Admin: approve the pending recovery address proposal
+    pub fn approve_recovery_address(env: Env, caller: Address) -> Result<(), Error> {
+        security::approve_recovery_address(&env, &caller)
+    }
+
+    /// Admin: apply the pending recovery address after approvals and timelock
+    pub fn execute_recovery_address(env: Env, caller: Address) -> Result<(), Error> {
+        security::execute_recovery_address(&env, &caller)
+    }
+
+    /// Get the configured recovery address
+    pub fn get_recovery_address(env: Env) -> Option<Address> {
+        storage::get_recovery_address(&env)
+    }
+
+    /// Get the pending recovery address proposal
+    pub fn get_pending_recovery(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::RecoveryAddress)
+    }
+
+    /// Deposit tokens into escrow for a quest.
+    ///
+    /// The creator or a sponsor sends tokens to the contract, earmarked for
+    /// this quest. Can be called multiple times to add more funds (top-up).
+    ///
+    /// # Who can call: Anyone, or the creator and allowlisted sponsors if the
+    /// quest's sponsor allowlist is enabled
+    /// # Token flow: Depositor wallet → Contract
+    pub fn deposit_escrow(
+        env: Env,
+        quest_id: Symbol,
+        depositor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_DEPOSITS)?;
+        depositor.require_auth();
+        escrow::deposit(&env, &quest_id, &depositor, &token, amount)
+    }
+
+    /// Cancel a quest and refund remaining escrow to its depositors.
+    ///
+    /// # Who can call: Quest creator only
+    /// # Requires: Quest is Active or Paused
+    /// # Token flow: Contract → Depositor wallets (remaining balance, split by refund policy)
+    /// # Returns: Amount refunded
+    pub fn cancel_quest(env: Env, quest_id: Symbol, creator: Address) -> Result<i128, Error> {
+        security::require_not_paused_for(&env, PAUSE_REFUNDS)?;
+        creator.require_auth();
+        escrow::cancel_quest(&env, &quest_id, &creator)
+    }
+
+    /// Withdraw unclaimed escrow from a finished quest.
+    ///
+    /// # Who can call: Quest creator only
+    /// # Requires: Quest is Completed, Expired, or Cancelled
+    /// # Token flow: Contract → Depositor wallets (remaining balance, split by refund policy)
+    /// # Returns: Amount withdrawn
+    pub fn withdraw_unclaimed(env: Env, quest_id: Symbol, creator: Address) -> Result<i128, Error> {
+        security::require_not_paused_for(&env, PAUSE_REFUNDS)?;
+        creator.require_auth();
+        escrow::withdraw_unclaimed(&env, &quest_id, &creator)
+    }
+
+    /// Query the available escrow balance for a quest.
+    pub fn get_escrow_balance(env: Env, quest_id: Symbol) -> Result<i128, Error> {
+        escrow::get_balance(&env, &quest_id)
+    }
+
+    /// Query the full escrow info for a quest.
+    pub fn get_escrow_info(env: Env, quest_id: Symbol) -> Result<EscrowInfo, Error> {
+        escrow::get_info(&env, &quest_id)
+    }
+
+    /// Create a campaign whose pool funds many quests in one asset.
+    ///
+    /// # Who can call: Anyone (becomes the campaign owner)
+    pub fn create_campaign(
+        env: Env,
+        campaign_id: Symbol,
+        owner: Address,
+        asset: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_QUEST_REGISTRATION)?;
+        owner.require_auth();
+        campaign::create_campaign(&env, &campaign_id, &owner, &asset)
+    }
+
+    /// Add or remove a campaign manager.
+    ///
+    /// # Who can call: Campaign owner only
+    pub fn set_campaign_manager(
+        env: Env,
+        campaign_id: Symbol,
+        owner: Address,
+        manager: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        campaign::set_manager(&env, &campaign_id, &owner, &manager, allowed)
+    }
+
+    /// Deposit tokens into a campaign's pool.
+    ///
+    /// # Who can call: Campaign owner or managers
+    /// # Token flow: Depositor wallet → Contract
+    pub fn deposit_campaign(
+        env: Env,
+        campaign_id: Symbol,
+        depositor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_DEPOSITS)?;
+        depositor.require_auth();
+        campaign::deposit(&env, &campaign_id, &depositor, amount)
+    }
+
+    /// Register a quest that draws its rewards from a campaign's pool.
+    ///
+    /// # Who can call: Campaign owner or managers (becomes the quest creator)
+    /// # Requires: Pool covers the funding requirement; the quest opens immediately
+    pub fn register_campaign_quest(
+        env: Env,
+        campaign_id: Symbol,
+        manager: Address,
+        quest_id: Symbol,
+        reward_amount: i128,
+        verifier: Address,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        security::require_not_paused_for(&env, PAUSE_QUEST_REGISTRATION)?;
+        manager.require_auth();
+        campaign::register_quest(
+            &env,
+            &campaign_id,
+            &manager,
+            &quest_id,
+            reward_amount,
+            &verifier,
+            deadline,
+        )
+    }
+
+    /// Withdraw a campaign's remaining pool and close it.
+    ///
+    /// # Who can call: Campaign owner only
+    /// # Token flow: Contract → Owner wallet (remaining pool balance)
+    /// # Returns: Amount withdrawn
+    pub fn withdraw_campaign_unclaimed(
+        env: Env,
+        campaign_id: Symbol,
+        owner: Address,
+    ) -> Result<i128, Error> {
+        security::require_not_paused_for(&env, PAUSE_REFUNDS)?;
+        owner.require_auth();
+        campaign::withdraw_unclaimed(&env, &campaign_id, &owner)
+    }
+
+    /// Query a campaign.
+    pub fn get_campaign(env: Env, campaign_id: Symbol) -> Result<Campaign, Error> {
+        storage::get_campaign(&env, &campaign_id)
+    }
+
+    /// Query the available balance of a campaign's pool.
+    pub fn get_campaign_balance(env: Env, campaign_id: Symbol) -> Result<i128, Error> {
+        campaign::get_balance(&env, &campaign_id)
+    }
+
+    /// Query the full escrow accounting of a campaign's pool.
+    pub fn get_campaign_escrow(env: Env, campaign_id: Symbol) -> Result<EscrowInfo, Error> {
+        storage::get_campaign_escrow(&env, &campaign_id)
+    }
+
+    /// List quests funded by a campaign, in registration order.
+    pub fn list_campaign_quests(
+        env: Env,
+        campaign_id: Symbol,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Quest> {
+        campaign::list_quests(&env, &campaign_id, cursor, limit)
+    }
+
+    /// Query each depositor's net contribution to a quest's escrow.
+    pub fn get_escrow_contributions(env: Env, quest_id: Symbol) -> Vec<EscrowContribution> {
+        escrow::get_contributions(&env, &quest_id)
+    }
+
+    /// Set who may sponsor a quest and how leftover escrow is refunded.
+    ///
+    /// # Who can call: Quest creator only
+    /// # Requires: Refund policy unchanged once a sponsor has contributed
+    pub fn set_sponsor_policy(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        policy: SponsorPolicy,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        escrow::set_sponsor_policy(&env, &quest_id, &creator, &policy)
+    }
+
+    /// Add or remove a sponsor from a quest's allowlist.
+    ///
+    /// # Who can call: Quest creator only
+    pub fn set_sponsor_allowed(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        sponsor: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        escrow::set_sponsor_allowed(&env, &quest_id, &creator, &sponsor, allowed)
+    }
+
+    /// Query a quest's sponsorship rules.
+    pub fn get_sponsor_policy(env: Env, quest_id: Symbol) -> SponsorPolicy {
+        storage::get_sponsor_policy(&env, &quest_id)
+    }
+
+    /// Query a quest's sponsor allowlist.
+    pub fn get_sponsor_allowlist(env: Env, quest_id: Symbol) -> Vec<Address> {
+        storage::get_sponsor_allowlist(&env, &quest_id)
+    }
+
+    /// Admin: configure the payout circuit breaker for an asset.
+    /// Set both limits to 0 to remove it.
+    pub fn set_circuit_breaker(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        window_seconds: u64,
+        max_amount: i128,
+        max_bps_of_balance: u32,
+    ) -> Result<(), Error> {
+        security::set_circuit_breaker(
+            &env,
+            &caller,
+            &asset,
+            window_seconds,
+            max_amount,
+            max_bps_of_balance,
+        )
+    }
+
+    /// Get the payout circuit breaker configuration for an asset
+    pub fn get_circuit_breaker(env: Env, asset: Address) -> Option<CircuitBreakerConfig> {
+        storage::get_circuit_breaker(&env, &asset)
+    }
+
+    /// Get the current payout window totals for an asset
+    pub fn get_payout_window(env: Env, asset: Address) -> Option<PayoutWindow> {
+        storage::get_payout_window(&env, &asset)
+    }
+
+    /// Admin: set the global platform fee (basis points) and when it is charged
+    pub fn set_fee_config(
+        env: Env,
+        caller: Address,
+        fee_bps: u32,
+        mode: FeeMode,
+    ) -> Result<(), Error> {
+        fees::set_fee_config(&env, &caller, fee_bps, mode)
+    }
+
+    /// Admin: override the platform fee for one asset (`None` removes the override)
+    pub fn set_asset_fee(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        fee_bps: Option<u32>,
+    ) -> Result<(), Error> {
+        fees::set_asset_fee_bps(&env, &caller, &asset, fee_bps)
+    }
+
+    /// Admin: propose the Treasurer admin allowed to withdraw fees
+    /// (requires M-of-N approvals + timelock)
+    pub fn propose_treasurer(env: Env, caller: Address, treasurer: Address) -> Result<u32, Error> {
+        fees::propose_treasurer(&env, &caller, &treasurer)
+    }
+
+    /// Admin: approve the pending Treasurer proposal
+    pub fn approve_treasurer(env: Env, caller: Address) -> Result<(), Error> {
+        fees::approve_treasurer(&env, &caller)
+    }
+
+    /// Admin: apply the pending Treasurer proposal after approvals and timelock
+    pub fn execute_treasurer(env: Env, caller: Address) -> Result<(), Error> {
+        fees::execute_treasurer(&env, &caller)
+    }
+
+    /// Treasurer: withdraw accrued fees for an asset
+    pub fn withdraw_fees(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        fees::withdraw_fees(&env, &caller, &asset, &to, amount)
+    }
+
+    /// Get the global fee configuration
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        storage::get_fee_config(&env)
+    }
+
+    /// Get the fee rate in effect for an asset (override or global)
+    pub fn get_asset_fee(env: Env, asset: Address) -> u32 {
+        fees::effective_fee_bps(&env, &asset)
+    }
+
+    /// Compare an asset's escrow and fee liabilities with the contract's token balance
+    pub fn audit_solvency(env: Env, asset: Address) -> SolvencyReport {
+        solvency::audit(&env, &asset)
+    }
+
+    /// Admin: propose crediting an asset's untracked surplus to the treasury
+    /// (requires M-of-N approvals + timelock)
+    pub fn propose_sweep(env: Env, caller: Address, asset: Address) -> Result<u32, Error> {
+        solvency::propose_sweep(&env, &caller, &asset)
+    }
+
+    /// Admin: approve the pending surplus sweep proposal
+    pub fn approve_sweep(env: Env, caller: Address) -> Result<(), Error> {
+        solvency::approve_sweep(&env, &caller)
+    }
+
+    /// Admin: credit the proposed asset's surplus to the treasury after approvals and timelock
+    pub fn sweep_surplus(env: Env, caller: Address) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        solvency::sweep_surplus(&env, &caller)
+    }
+
+    /// Get the pending surplus sweep proposal
+    pub fn get_pending_sweep(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::SurplusSweep)
+    }
+
+    /// Return tokens taken by an emergency withdrawal to the contract
+    pub fn return_recovered(
+        env: Env,
+        from: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        solvency::return_recovered(&env, &from, &asset, amount)
+    }
+
+    /// Get accrued, unwithdrawn fees for an asset
+    pub fn get_treasury_balance(env: Env, asset: Address) -> i128 {
+        storage::get_treasury_balance(&env, &asset)
+    }
+
+    /// Get the Treasurer admin
+    pub fn get_treasurer(env: Env) -> Option<Address> {
+        storage::get_treasurer(&env)
+    }
+
+    /// Get the pending Treasurer proposal
+    pub fn get_pending_treasurer(env: Env) -> Option<Proposal> {
+        storage::get_pending_proposal(&env, ProposalTarget::Treasurer)
     }
 
-    /// Admin: set unpause approvals threshold
+    /// Admin: raise the unpause/recovery approvals threshold (cannot be lowered)
     pub fn set_unpause_threshold(env: Env, caller: Address, threshold: u32) -> Result<(), Error> {
         security::set_unpause_threshold(&env, &caller, threshold)
     }
 
-    /// Admin: set unpause timelock seconds
+    /// Admin: raise the unpause/recovery timelock seconds (cannot be shortened)
     pub fn set_unpause_timelock(env: Env, caller: Address, seconds: u64) -> Result<(), Error> {
         security::set_unpause_timelock(&env, &caller, seconds)
     }
@@ -0,0 +1,233 @@
+//! Lottery quests — approvals only make a submitter eligible; after the
+//! deadline `draw_winners` picks a fixed number of winners who alone can
+//! claim the reward.
+//!
+//! DRAW:
+//!
+//!   seed    = sha256(prng entropy || quest_id as XDR)
+//!   winners = first N steps of a Fisher–Yates shuffle of the approved
+//!             entrants, driven by the PRNG reseeded with `seed`
+//!
+//! Approvals add the submitter to the quest's entrant slots, so the draw
+//! reads only the slots it picks — never the whole submission list.
+//! The seed is stored with the draw and published in the `lot_draw` event.
+//! Approved entrants who are not drawn claim `PARTICIPATION_XP` themselves
+//! with `claim_participation_xp`.
+//! The quest cannot be cancelled or its escrow withdrawn until every winner
+//! has claimed or `LOTTERY_CLAIM_WINDOW` has passed since the draw.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::escrow;
+use crate::events;
+use crate::reputation;
+use crate::storage;
+use crate::types::{LotteryDraw, QuestStatus, SubmissionStatus};
+use crate::validation;
+
+/// XP claimable by each approved entrant who is not drawn as a winner
+pub const PARTICIPATION_XP: u64 = 25;
+
+/// Make a quest pay only `winners` randomly drawn approved submitters.
+/// The escrow must cover `winners × reward_amount` by the time winners are drawn.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must still be Unfunded, so no submission predates the mode
+/// - Quest must not have an attestor; lottery rewards are only paid to
+///   drawn winners through `claim_reward`
+/// - `winners` is between 1 and `validation::MAX_LOTTERY_WINNERS`
+pub fn set_lottery(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    winners: u32,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if quest.status != QuestStatus::Unfunded {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    if winners == 0 || storage::get_quest_attestor(env, quest_id).is_some() {
+        return Err(Error::InvalidConfig);
+    }
+    validation::validate_array_length(winners, validation::MAX_LOTTERY_WINNERS)?;
+
+    storage::set_lottery_winners(env, quest_id, winners);
+    events::lottery_set(env, quest_id.clone(), caller.clone(), winners);
+
+    Ok(())
+}
+
+/// Draw a lottery quest's winners from its approved submitters.
+///
+/// # Requirements
+/// - Caller is the quest creator or verifier
+/// - The quest deadline has passed and winners have not been drawn yet
+/// - The quest's escrow covers `winners × reward_amount`
+///
+/// Returns the winners, in draw order.
+pub fn draw_winners(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<Vec<Address>, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+    let winners = storage::get_lottery_winners(env, quest_id).ok_or(Error::Unauthorized)?;
+
+    if *caller != quest.creator && *caller != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() <= quest.deadline {
+        return Err(Error::QuestStillActive);
+    }
+    if storage::get_lottery_draw(env, quest_id).is_some() {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    escrow::validate_sufficient(env, quest_id, quest.reward_amount * winners as i128)?;
+
+    let entrants = storage::get_lottery_entrant_len(env, quest_id);
+
+    let mut entropy = Bytes::from_array(env, &env.prng().gen::<BytesN<32>>().to_array());
+    entropy.append(&quest_id.clone().to_xdr(env));
+    let seed: BytesN<32> = env.crypto().sha256(&entropy).into();
+    env.prng().seed(seed.clone().into());
+
+    // Partial Fisher–Yates: slots swapped so far are tracked in memory
+    let mut swapped: Map<u32, Address> = Map::new(env);
+    let mut drawn = Vec::new(env);
+    for i in 0..winners.min(entrants) {
+        let j = env.prng().gen_range::<u64>(i as u64..entrants as u64) as u32;
+        let picked = entrant_at(env, quest_id, &swapped, j)?;
+        let current = entrant_at(env, quest_id, &swapped, i)?;
+        swapped.set(j, current);
+        drawn.push_back(picked);
+    }
+
+    let draw = LotteryDraw {
+        seed: seed.clone(),
+        winners: drawn,
+        entrants,
+        drawn_at: env.ledger().timestamp(),
+    };
+    storage::set_lottery_draw(env, quest_id, &draw);
+
+    events::lottery_drawn(
+        env,
+        quest_id.clone(),
+        caller.clone(),
+        seed,
+        draw.winners.clone(),
+        draw.entrants,
+    );
+
+    Ok(draw.winners)
+}
+
+/// Entrant at position `pos` of the draw's shuffle.
+fn entrant_at(
+    env: &Env,
+    quest_id: &Symbol,
+    swapped: &Map<u32, Address>,
+    pos: u32,
+) -> Result<Address, Error> {
+    match swapped.get(pos) {
+        Some(entrant) => Ok(entrant),
+        None => storage::get_lottery_entrant(env, quest_id, pos).ok_or(Error::SubmissionNotFound),
+    }
+}
+
+/// Claim `PARTICIPATION_XP` for an approved entrant who was not drawn.
+///
+/// # Requirements
+/// - The quest is a lottery and its winners have been drawn
+/// - The submitter's submission is Approved and they were not drawn
+/// - Participation XP has not already been claimed for this quest
+pub fn claim_participation_xp(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Result<(), Error> {
+    crate::fraud::check(env, submitter)?;
+
+    storage::get_lottery_winners(env, quest_id).ok_or(Error::Unauthorized)?;
+    if storage::get_lottery_draw(env, quest_id).is_none() {
+        return Err(Error::QuestStillActive);
+    }
+    if storage::is_lottery_winner(env, quest_id, submitter) {
+        return Err(Error::Unauthorized);
+    }
+
+    let submission = storage::get_submission(env, quest_id, submitter)?;
+    if submission.status != SubmissionStatus::Approved {
+        return Err(Error::InvalidStatusTransition);
+    }
+    if storage::is_participation_claimed(env, quest_id, submitter) {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    storage::set_participation_claimed(env, quest_id, submitter);
+    reputation::award_xp(env, submitter, PARTICIPATION_XP)?;
+
+    Ok(())
+}
+
+/// Enter an approved submitter into a lottery quest's draw. No-op for other quests.
+pub fn enter(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    if storage::get_lottery_winners(env, quest_id).is_some() {
+        storage::add_lottery_entrant(env, quest_id, submitter);
+    }
+}
+
+/// Reject approvals once a lottery quest has been drawn. No-op for other quests.
+pub fn validate_approval(env: &Env, quest_id: &Symbol) -> Result<(), Error> {
+    if storage::get_lottery_draw(env, quest_id).is_some() {
+        return Err(Error::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// Hold a drawn lottery quest's escrow until every winner has been paid or
+/// `validation::LOTTERY_CLAIM_WINDOW` has passed since the draw, failing with
+/// `EscrowNotSettled`. No-op for quests without a draw.
+pub fn validate_settled(env: &Env, quest_id: &Symbol) -> Result<(), Error> {
+    let Some(draw) = storage::get_lottery_draw(env, quest_id) else {
+        return Ok(());
+    };
+    if env.ledger().timestamp()
+        >= draw
+            .drawn_at
+            .saturating_add(validation::LOTTERY_CLAIM_WINDOW)
+    {
+        return Ok(());
+    }
+    for winner in draw.winners.iter() {
+        let paid = storage::get_submission(env, quest_id, &winner)
+            .is_ok_and(|s| s.status == SubmissionStatus::Paid);
+        if !paid {
+            return Err(Error::EscrowNotSettled);
+        }
+    }
+    Ok(())
+}
+
+/// Only drawn winners may claim a lottery quest's reward. No-op for other quests.
+///
+/// Fails with `QuestStillActive` before the draw and `Unauthorized` for
+/// submitters who were not drawn.
+pub fn validate_claim(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    if storage::get_lottery_winners(env, quest_id).is_none() {
+        return Ok(());
+    }
+    if storage::get_lottery_draw(env, quest_id).is_none() {
+        return Err(Error::QuestStillActive);
+    }
+    if !storage::is_lottery_winner(env, quest_id, submitter) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
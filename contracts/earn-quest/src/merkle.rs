@@ -0,0 +1,211 @@
+//! Merkle claims — verifiers approve large cohorts by posting one root,
+//! and each submitter claims their own reward with an inclusion proof.
+//!
+//! LEAF ENCODING:
+//!
+//!   leaf = sha256(index as u32 big-endian || submitter as XDR || amount as i128 big-endian)
+//!
+//! Inner nodes hash their two children in sorted order,
+//! `sha256(min(a, b) || max(a, b))`, so proofs carry no left/right flags.
+//! Including the index in the leaf ties each leaf to one bit of the
+//! round's claimed bitmap.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::campaign;
+use crate::errors::Error;
+use crate::escrow;
+use crate::events;
+use crate::payout;
+use crate::reputation;
+use crate::security;
+use crate::stats;
+use crate::storage;
+use crate::submission;
+use crate::types::{MerkleRound, QuestStatus, SubmissionStatus};
+use crate::validation;
+
+/// Hash of the leaf for `submitter` receiving `amount` at position `index`.
+pub fn leaf_hash(env: &Env, index: u32, submitter: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &index.to_be_bytes());
+    data.append(&submitter.clone().to_xdr(env));
+    data.extend_from_array(&amount.to_be_bytes());
+    env.crypto().sha256(&data).into()
+}
+
+/// Hash of an inner node from its two children, in sorted order.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    env.crypto().sha256(&data).into()
+}
+
+/// Fold a leaf up through its proof and compare with `root`.
+fn verify(env: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    node == *root
+}
+
+// ═══════════════════════════════════════════════════════════════
+// POST ROOT: Verifier approves a cohort
+// ═══════════════════════════════════════════════════════════════
+
+/// Post a Merkle root of approved rewards for a quest.
+///
+/// # Requirements
+/// - Caller must be the quest's verifier
+/// - Quest must be Active and not a lottery (only drawn winners are paid)
+/// - `leaf_count` at most `MAX_MERKLE_LEAVES` and `total_amount` > 0
+/// - The quest's escrow must cover `total_amount` on top of the unclaimed
+///   totals of earlier rounds (campaign quests draw it from their pool first)
+///
+/// The round total stays held back from other payouts until it is claimed.
+/// Returns the round id claimants pass to `claim_with_proof`.
+pub fn post_root(
+    env: &Env,
+    quest_id: &Symbol,
+    verifier: &Address,
+    root: &BytesN<32>,
+    leaf_count: u32,
+    total_amount: i128,
+) -> Result<u32, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *verifier != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    validation::validate_quest_is_active(&quest.status)?;
+
+    if storage::get_lottery_winners(env, quest_id).is_some() {
+        return Err(Error::InvalidConfig);
+    }
+
+    validation::validate_array_length(leaf_count, validation::MAX_MERKLE_LEAVES)?;
+    if total_amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    // Same escrow guarantee as a regular approval, for the whole cohort
+    campaign::draw(env, &quest, total_amount)?;
+    escrow::validate_sufficient(env, quest_id, total_amount)?;
+    storage::adjust_merkle_outstanding(env, quest_id, total_amount);
+
+    let round = MerkleRound {
+        root: root.clone(),
+        leaf_count,
+        total_amount,
+        claimed_amount: 0,
+        posted_at: env.ledger().timestamp(),
+    };
+    let round_id = storage::add_merkle_round(env, quest_id, &round);
+
+    events::merkle_root_posted(
+        env,
+        quest_id.clone(),
+        round_id,
+        root.clone(),
+        leaf_count,
+        total_amount,
+    );
+
+    Ok(round_id)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// CLAIM: Submitter proves inclusion and is paid once
+// ═══════════════════════════════════════════════════════════════
+
+/// Claim the reward for leaf `index` of a posted round.
+///
+/// Verifies the proof, checks the leaf has not been claimed and that the
+/// round's total is not exceeded, then pays from the quest's escrow and
+/// awards XP exactly like `claim_reward`. A leaf that would push claims past
+/// the posted total is rejected as an invalid proof.
+///
+/// The quest must not be Cancelled, and the submitter must not already have
+/// been paid for it. Their `Submission` is recorded as Paid, so the reward
+/// cannot also be claimed through `claim_reward` or an attestation.
+///
+/// Returns `false` without paying if the payout tripped the circuit breaker.
+pub fn claim_with_proof(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    round_id: u32,
+    index: u32,
+    amount: i128,
+    proof: &Vec<BytesN<32>>,
+) -> Result<bool, Error> {
+    crate::fraud::check(env, submitter)?;
+
+    let quest = storage::get_quest(env, quest_id)?;
+    if quest.status == QuestStatus::Cancelled {
+        return Err(Error::QuestNotActive);
+    }
+    let mut round = storage::get_merkle_round(env, quest_id, round_id)?;
+
+    validation::validate_array_length(proof.len(), validation::MAX_MERKLE_PROOF_DEPTH)?;
+
+    if index >= round.leaf_count || amount <= 0 {
+        return Err(Error::InvalidProof);
+    }
+
+    if storage::is_merkle_claimed(env, quest_id, round_id, index) {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    let leaf = leaf_hash(env, index, submitter, amount);
+    if !verify(env, &round.root, leaf.clone(), proof)
+        || round.claimed_amount + amount > round.total_amount
+    {
+        return Err(Error::InvalidProof);
+    }
+
+    if storage::get_submission(env, quest_id, submitter).map(|s| s.status)
+        == Ok(SubmissionStatus::Paid)
+    {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    validation::validate_quest_claims_limit(quest.total_claims)?;
+
+    if !security::admit_payout(env, &quest.reward_asset, amount) {
+        return Ok(false);
+    }
+
+    submission::record_paid(env, &quest, submitter, &leaf)?;
+
+    // Payout (net of any platform fee); the leaf's amount is no longer held back
+    storage::adjust_merkle_outstanding(env, quest_id, -amount);
+    let paid =
+        payout::transfer_reward_from_escrow(env, quest_id, &quest.reward_asset, submitter, amount)?;
+
+    storage::set_merkle_claimed(env, quest_id, round_id, index);
+    round.claimed_amount += amount;
+    storage::set_merkle_round(env, quest_id, round_id, &round);
+    storage::increment_quest_claims(env, quest_id)?;
+
+    events::reward_claimed(
+        env,
+        quest_id.clone(),
+        submitter.clone(),
+        quest.reward_asset.clone(),
+        paid,
+    );
+
+    reputation::award_xp(env, submitter, 100)?;
+    reputation::record_earnings(env, submitter, &quest.reward_asset, paid);
+    stats::record_claim(env, &quest.creator, &quest.reward_asset, paid);
+
+    Ok(true)
+}
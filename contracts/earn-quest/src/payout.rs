@@ -1,8 +1,8 @@
-use soroban_sdk::{token, Address, Env};
 use crate::errors::Error;
+use soroban_sdk::{token, Address, Env};
 
 /// Transfer rewards from the contract escrow to the user.
-/// 
+///
 /// This function handles the low-level token transfer and ensures
 /// the contract has sufficient balance.
 pub fn transfer_reward(
@@ -11,8 +11,8 @@ pub fn transfer_reward(
     to: &Address,
     amount: i128,
 ) -> Result<(), Error> {
-    // 0. Asset validation (basic check that it's a valid address is handled by SDK type)
-    // Additional validation could be checking against a whitelist if required.
+    // 0. Asset validation: quests and deposits are checked against the asset
+    // registry, so only allowed tokens ever reach this point.
 
     if amount <= 0 {
         return Err(Error::InvalidRewardAmount);
@@ -44,40 +44,53 @@ pub fn transfer_reward(
 // ═══════════════════════════════════════════════════════════════
 
 use crate::escrow;
+use crate::fees;
+use crate::security;
 use crate::storage;
+use crate::types::FeeMode;
 use soroban_sdk::Symbol;
 
 /// Transfer reward with escrow tracking.
 ///
-/// If the quest has escrow:
-///   1. Validate escrow has enough funds
+///   1. Validate the quest's escrow has enough funds
 ///   2. Transfer tokens (existing logic)
 ///   3. Record the deduction in escrow tracking
 ///
-/// If the quest has no escrow:
-///   Falls back to existing transfer_reward behavior.
-///   (backward compatible with quests created before escrow feature)
+/// Every payout comes out of the quest's own escrow; quests without
+/// escrow fail with `EscrowNotFound` rather than drawing on tokens
+/// escrowed for other quests.
+///
+/// Every payout is counted against the asset's circuit breaker window at
+/// its gross amount. Callers check `security::admit_payout` first, before
+/// changing any state, so a payout that would trip the breaker never runs.
+///
+/// When fees are charged on payout, the platform fee is withheld from
+/// `amount` and credited to the treasury. Returns the amount the
+/// recipient actually received.
 pub fn transfer_reward_from_escrow(
     env: &Env,
     quest_id: &Symbol,
     reward_asset: &Address,
     to: &Address,
     amount: i128,
-) -> Result<(), Error> {
-    let has_escrow = storage::has_escrow(env, quest_id);
-
+) -> Result<i128, Error> {
     // Pre-check: verify escrow has enough
-    if has_escrow {
-        escrow::validate_sufficient(env, quest_id, amount)?;
-    }
+    escrow::validate_sufficient(env, quest_id, amount)?;
+
+    // Platform fee (only when this escrow is charged on payout)
+    let escrow = storage::get_escrow(env, quest_id)?;
+    let fee = fees::fee_for(env, &escrow, amount, FeeMode::OnPayout);
+    let net = amount - fee;
 
     // Actual token transfer (existing logic)
-    transfer_reward(env, reward_asset, to, amount)?;
+    transfer_reward(env, reward_asset, to, net)?;
 
     // Post-transfer: update escrow accounting
-    if has_escrow {
-        escrow::record_payout(env, quest_id, to, amount)?;
-    }
+    escrow::record_payout(env, quest_id, to, net, fee)?;
+    fees::accrue(env, quest_id, reward_asset, FeeMode::OnPayout, fee);
+
+    // Circuit breaker: track per-asset payout volume
+    security::record_payout_volume(env, reward_asset, amount);
 
-    Ok(())
-}
\ No newline at end of file
+    Ok(net)
+}
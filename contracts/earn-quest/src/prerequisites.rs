@@ -0,0 +1,103 @@
+//! Quest prerequisites — quests can require earlier quests to have been
+//! paid, a minimum level, or badges before a user may submit proof,
+//! which lets creators chain quests into tracks.
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::events;
+use crate::reputation;
+use crate::storage;
+use crate::types::{Eligibility, Prerequisites, SubmissionStatus};
+use crate::validation;
+
+/// Set or clear a quest's prerequisites.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must not be terminal
+/// - Prerequisite quests must exist; at most `MAX_PREREQUISITES` quests and badges
+pub fn set_prerequisites(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    prerequisites: &Option<Prerequisites>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    if let Some(prerequisites) = prerequisites {
+        validation::validate_array_length(
+            prerequisites.quests.len(),
+            validation::MAX_PREREQUISITES,
+        )?;
+        validation::validate_array_length(
+            prerequisites.badges.len(),
+            validation::MAX_PREREQUISITES,
+        )?;
+        for required in prerequisites.quests.iter() {
+            if !storage::has_quest(env, &required) {
+                return Err(Error::QuestNotFound);
+            }
+        }
+    }
+
+    storage::set_prerequisites(env, quest_id, prerequisites);
+    events::prerequisites_set(env, quest_id.clone(), caller.clone(), prerequisites.clone());
+
+    Ok(())
+}
+
+/// Work out which of a quest's prerequisites `user` is missing.
+pub fn eligibility(env: &Env, quest_id: &Symbol, user: &Address) -> Eligibility {
+    let stats = reputation::get_user_stats(env, user);
+    let mut result = Eligibility {
+        eligible: true,
+        missing_quests: Vec::new(env),
+        min_level: 0,
+        level: stats.level,
+        missing_badges: Vec::new(env),
+    };
+
+    let Some(prerequisites) = storage::get_prerequisites(env, quest_id) else {
+        return result;
+    };
+
+    // Completion marks outlive pruning; submissions paid before they were
+    // recorded still count while the quest exists
+    for required in prerequisites.quests.iter() {
+        let paid = storage::has_completed(env, &required, user)
+            || storage::get_submission(env, &required, user)
+                .is_ok_and(|s| s.status == SubmissionStatus::Paid);
+        if !paid {
+            result.missing_quests.push_back(required);
+        }
+    }
+    for badge in prerequisites.badges.iter() {
+        if !stats.badges.contains(&badge) {
+            result.missing_badges.push_back(badge);
+        }
+    }
+    result.min_level = prerequisites.min_level;
+    result.eligible = result.missing_quests.is_empty()
+        && result.missing_badges.is_empty()
+        && stats.level >= prerequisites.min_level;
+
+    result
+}
+
+/// Fail with `PrerequisiteNotMet` unless `user` meets the quest's prerequisites.
+pub fn check(env: &Env, quest_id: &Symbol, user: &Address) -> Result<(), Error> {
+    if eligibility(env, quest_id, user).eligible {
+        Ok(())
+    } else {
+        Err(Error::PrerequisiteNotMet)
+    }
+}
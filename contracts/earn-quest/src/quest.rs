@@ -1,9 +1,11 @@
 use crate::errors::Error;
+use crate::escrow;
 use crate::events;
-use crate::storage;
-use crate::types::{BatchQuestInput, Quest, QuestStatus};
+use crate::stats;
+use crate::storage::{self, QuestIndex};
+use crate::types::{AccessMode, BatchQuestInput, Quest, QuestStatus, SubmissionStatus};
 use crate::validation;
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
 
 /// Register a new quest with full input validation.
 ///
@@ -12,6 +14,12 @@ use soroban_sdk::{Address, Env, Symbol, Vec};
 /// - Reward amount is within valid range (> 0 and <= MAX)
 /// - Deadline is in the future
 /// - Creator and verifier are distinct addresses
+/// - Reward asset is allowed in the registry and amount is within its limits
+/// - Creator is below the cap on non-terminal quests, if one is set
+///
+/// Charges the creator the creation deposit or fee, if one is configured.
+/// The quest starts `Unfunded` and opens for submissions once its escrow
+/// covers the funding requirement (see `escrow::deposit`).
 pub fn register_quest(
     env: &Env,
     id: &Symbol,
@@ -24,8 +32,8 @@ pub fn register_quest(
     // Validate quest ID symbol length
     validation::validate_symbol_length(id)?;
 
-    // Check quest doesn't already exist
-    if storage::has_quest(env, id) {
+    // Check quest doesn't already exist and was never pruned
+    if storage::has_quest(env, id) || storage::is_quest_archived(env, id) {
         return Err(Error::QuestAlreadyExists);
     }
 
@@ -38,6 +46,12 @@ pub fn register_quest(
     // Validate creator and verifier are different addresses
     validation::validate_addresses_distinct(creator, verifier)?;
 
+    // Validate reward asset against the registry
+    validation::validate_reward_for_asset(env, reward_asset, reward_amount)?;
+
+    // Enforce the per-creator cap on non-terminal quests
+    validate_active_quota(env, creator)?;
+
     let quest = Quest {
         id: id.clone(),
         creator: creator.clone(),
@@ -45,11 +59,13 @@ pub fn register_quest(
         reward_amount,
         verifier: verifier.clone(),
         deadline,
-        status: QuestStatus::Active,
+        status: QuestStatus::Unfunded,
         total_claims: 0,
     };
 
     storage::set_quest(env, id, &quest);
+    storage::index_quest(env, &quest);
+    stats::record_quest_created(env, creator);
 
     // EMIT EVENT: QuestRegistered
     events::quest_registered(
@@ -62,6 +78,101 @@ pub fn register_quest(
         deadline,
     );
 
+    charge_creation_deposit(env, id, creator)?;
+
+    Ok(())
+}
+
+//================================================================================
+// Creation deposits & quotas
+//================================================================================
+
+/// Fail with `ActiveQuestLimitReached` if `creator` already has the maximum
+/// number of Unfunded, Active or Paused quests.
+fn validate_active_quota(env: &Env, creator: &Address) -> Result<(), Error> {
+    let max = storage::get_max_active_quests(env);
+    if max == 0 {
+        return Ok(());
+    }
+
+    let stats = stats::get_creator_stats(env, creator);
+    let open = stats.quests_unfunded + stats.quests_active + stats.quests_paused;
+    if open >= max {
+        return Err(Error::ActiveQuestLimitReached);
+    }
+    Ok(())
+}
+
+/// Take the configured creation charge from `creator`. A refundable deposit is
+/// held for the quest; a fee is credited to the treasury balance.
+fn charge_creation_deposit(env: &Env, id: &Symbol, creator: &Address) -> Result<(), Error> {
+    let Some(deposit) = storage::get_creation_deposit(env) else {
+        return Ok(());
+    };
+
+    // Transfer tokens: creator → contract
+    let token_client = token::Client::new(env, &deposit.asset);
+    match token_client.try_transfer(creator, &env.current_contract_address(), &deposit.amount) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::CreationDepositUnpaid),
+    }
+
+    if deposit.refundable {
+        storage::set_quest_creation_deposit(env, id, &Some(deposit.clone()));
+        storage::adjust_deposit_liability(env, &deposit.asset, deposit.amount);
+    } else {
+        // Tokens stay in the contract until the Treasurer withdraws them
+        let balance = storage::get_treasury_balance(env, &deposit.asset) + deposit.amount;
+        storage::set_treasury_balance(env, &deposit.asset, balance);
+    }
+
+    events::creation_charged(
+        env,
+        id.clone(),
+        creator.clone(),
+        deposit.asset,
+        deposit.amount,
+        deposit.refundable,
+    );
+    Ok(())
+}
+
+/// Return a quest's refundable creation deposit to its creator once the quest
+/// is terminal and its escrow settled. No-op otherwise.
+pub fn return_creation_deposit(env: &Env, id: &Symbol) -> Result<(), Error> {
+    let Some(deposit) = storage::get_quest_creation_deposit(env, id) else {
+        return Ok(());
+    };
+    let quest = storage::get_quest(env, id)?;
+    if !validation::is_quest_terminal(&quest.status) {
+        return Ok(());
+    }
+    if let Ok(escrow) = storage::get_escrow(env, id) {
+        if escrow::available(&escrow) > 0 {
+            return Ok(());
+        }
+    }
+
+    // Transfer tokens: contract → creator
+    let token_client = token::Client::new(env, &deposit.asset);
+    match token_client.try_transfer(
+        &env.current_contract_address(),
+        &quest.creator,
+        &deposit.amount,
+    ) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+
+    storage::set_quest_creation_deposit(env, id, &None);
+    storage::adjust_deposit_liability(env, &deposit.asset, -deposit.amount);
+    events::creation_returned(
+        env,
+        id.clone(),
+        quest.creator,
+        deposit.asset,
+        deposit.amount,
+    );
     Ok(())
 }
 
@@ -106,3 +217,131 @@ pub fn register_quests_batch(
 
     Ok(())
 }
+
+//================================================================================
+// Listing queries
+//================================================================================
+
+/// Load up to `limit` quests from an index, starting at position `cursor`.
+/// `limit` is capped at `validation::MAX_PAGE_SIZE`. A pruned quest's position
+/// is taken by the last quest in the index.
+pub fn list_quests(env: &Env, index: &QuestIndex, cursor: u32, limit: u32) -> Vec<Quest> {
+    let ids = storage::get_quest_index_page(env, index, cursor, validation::page_limit(limit));
+    load_quests(env, &ids)
+}
+
+/// Load up to `limit` quests currently in `status`, starting at position `cursor`.
+/// A quest leaving the status is replaced by the last quest in the list, so
+/// callers paging through a changing list may see a quest twice or miss one.
+pub fn list_quests_by_status(
+    env: &Env,
+    status: &QuestStatus,
+    cursor: u32,
+    limit: u32,
+) -> Vec<Quest> {
+    list_quests(env, &QuestIndex::Status(status.clone()), cursor, limit)
+}
+
+fn load_quests(env: &Env, ids: &Vec<Symbol>) -> Vec<Quest> {
+    let mut quests = Vec::new(env);
+    for id in ids.iter() {
+        if let Ok(quest) = storage::get_quest(env, &id) {
+            quests.push_back(quest);
+        }
+    }
+    quests
+}
+
+//================================================================================
+// Pruning
+//================================================================================
+
+/// Delete a finished quest and everything stored under it. Permissionless.
+///
+/// Requires:
+/// - Quest is terminal (Completed, Expired or Cancelled)
+/// - Escrow, if any, has nothing left to pay out or refund
+/// - The retention period has passed since the quest closed
+///   (or since its deadline, for quests closed before close times were recorded)
+///
+/// Submissions are deleted newest first, at most `validation::MAX_PRUNE_SUBMISSIONS`
+/// per call. Once none remain the quest and its escrow record are deleted and a
+/// `quest_archived` event is emitted and the quest is dropped from every
+/// listing index. The id is then retired: registering a quest with it fails
+/// with `QuestAlreadyExists`.
+///
+/// # Returns
+/// * `Ok(true)` - The quest was archived
+/// * `Ok(false)` - Submissions remain; call again to continue
+pub fn prune_quest(env: &Env, quest_id: &Symbol) -> Result<bool, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if !validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotTerminal);
+    }
+
+    let escrow = storage::get_escrow(env, quest_id).ok();
+    if let Some(escrow) = &escrow {
+        if escrow::available(escrow) > 0 {
+            return Err(Error::EscrowNotSettled);
+        }
+    }
+
+    let closed_at = storage::get_quest_closed_at(env, quest_id).unwrap_or(quest.deadline);
+    let retain_until = closed_at.saturating_add(storage::get_retention_period(env));
+    if env.ledger().timestamp() < retain_until {
+        return Err(Error::RetentionPeriodActive);
+    }
+
+    for _ in 0..validation::MAX_PRUNE_SUBMISSIONS {
+        let Some(submitter) = storage::pop_submission_index(env, quest_id) else {
+            break;
+        };
+        if let Ok(submission) = storage::get_submission(env, quest_id, &submitter) {
+            if submission.status == SubmissionStatus::Pending {
+                storage::remove_verifier_pending(env, &quest.verifier, quest_id, &submitter);
+            }
+            storage::delete_proof_use(env, quest_id, &submission.proof_hash);
+        }
+        storage::remove_from_quest_index(env, &QuestIndex::Submitter(submitter.clone()), quest_id);
+        storage::set_participant(env, quest_id, &submitter, false);
+        crate::bond::refund(env, quest_id, &submitter)?;
+        storage::delete_submission(env, quest_id, &submitter);
+        storage::delete_duplicate_flag(env, quest_id, &submitter);
+        storage::delete_lottery_entry(env, quest_id, &submitter);
+    }
+    if storage::get_submission_index_len(env, quest_id) > 0 {
+        return Ok(false);
+    }
+
+    return_creation_deposit(env, quest_id)?;
+
+    storage::unindex_quest(env, &quest);
+    storage::delete_escrow(env, quest_id);
+    storage::delete_quest_campaign(env, quest_id);
+    storage::delete_merkle_rounds(env, quest_id);
+    storage::set_quest_attestor(env, quest_id, &None);
+    storage::delete_quest_answer(env, quest_id);
+    storage::delete_proof_policy(env, quest_id);
+    storage::set_prerequisites(env, quest_id, &None);
+    storage::set_access_mode(env, quest_id, AccessMode::Open);
+    storage::set_bond_config(env, quest_id, &None);
+    storage::delete_lottery(env, quest_id);
+    storage::delete_quest_closed_at(env, quest_id);
+    storage::delete_quest(env, quest_id)?;
+    storage::set_quest_archived(env, quest_id);
+
+    let (total_paid, total_refunded) = escrow
+        .map(|e| (e.total_paid_out, e.total_refunded))
+        .unwrap_or((0, 0));
+    events::quest_archived(
+        env,
+        quest_id.clone(),
+        quest.creator,
+        quest.total_claims,
+        total_paid,
+        total_refunded,
+    );
+
+    Ok(true)
+}
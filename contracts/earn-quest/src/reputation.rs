@@ -1,9 +1,10 @@
 use crate::admin;
 use crate::errors::Error;
 use crate::events;
-use crate::storage;
-use crate::types::{Badge, UserStats};
-use soroban_sdk::{Address, Env};
+use crate::storage::{self, QuestIndex};
+use crate::types::{AssetEarnings, Badge, UserQuestRecord, UserStats};
+use crate::validation;
+use soroban_sdk::{Address, Env, Vec};
 
 const LEVEL_2_XP: u64 = 300;
 const LEVEL_3_XP: u64 = 600;
@@ -32,6 +33,22 @@ pub fn award_xp(env: &Env, user: &Address, xp_amount: u64) -> Result<UserStats,
     Ok(stats)
 }
 
+/// Remove up to `xp_amount` XP from a user, lowering their level to match.
+/// Returns the XP actually removed.
+pub fn penalize_xp(env: &Env, user: &Address, xp_amount: u64) -> u64 {
+    let mut stats = storage::get_user_stats_or_default(env, user);
+    let removed = xp_amount.min(stats.xp);
+    if removed == 0 {
+        return 0;
+    }
+
+    stats.xp -= removed;
+    stats.level = calculate_level(stats.xp);
+    storage::set_user_stats(env, user, &stats);
+
+    removed
+}
+
 /// Calculate user level based on total XP
 pub fn calculate_level(xp: u64) -> u32 {
     if xp >= LEVEL_5_XP {
@@ -66,3 +83,45 @@ pub fn grant_badge(env: &Env, caller: &Address, user: &Address, badge: Badge) ->
 pub fn get_user_stats(env: &Env, user: &Address) -> UserStats {
     storage::get_user_stats_or_default(env, user)
 }
+
+/// Record a paid reward in the user's lifetime earnings
+pub fn record_earnings(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    storage::add_user_earnings(env, user, asset, amount);
+}
+
+/// Quests a user has submitted proof for, oldest first, with their current submission status
+pub fn get_user_history(
+    env: &Env,
+    user: &Address,
+    cursor: u32,
+    limit: u32,
+) -> Vec<UserQuestRecord> {
+    let index = QuestIndex::Submitter(user.clone());
+    let ids = storage::get_quest_index_page(env, &index, cursor, validation::page_limit(limit));
+
+    let mut history = Vec::new(env);
+    for quest_id in ids.iter() {
+        if let Ok(submission) = storage::get_submission(env, &quest_id, user) {
+            history.push_back(UserQuestRecord {
+                quest_id,
+                status: submission.status,
+            });
+        }
+    }
+    history
+}
+
+/// A user's lifetime earnings per asset, ordered by asset address
+pub fn get_user_earnings(env: &Env, user: &Address, cursor: u32, limit: u32) -> Vec<AssetEarnings> {
+    let earnings = storage::get_user_earnings(env, user);
+
+    let mut page = Vec::new(env);
+    for (asset, amount) in earnings
+        .iter()
+        .skip(cursor as usize)
+        .take(validation::page_limit(limit) as usize)
+    {
+        page.push_back(AssetEarnings { asset, amount });
+    }
+    page
+}
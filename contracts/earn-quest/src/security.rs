@@ -1,7 +1,11 @@
 use crate::errors::Error;
 use crate::events;
 use crate::storage;
-use soroban_sdk::{Address, Env, token};
+use crate::types::{
+    CircuitBreakerConfig, PayoutBucket, PayoutWindow, Proposal, ProposalTarget, ProposalValue,
+    PAUSE_ALL,
+};
+use soroban_sdk::{token, Address, BytesN, Env, Vec};
 
 /// Is contract paused?
 pub fn is_paused(env: &Env) -> bool {
@@ -15,6 +19,53 @@ pub fn require_not_paused(env: &Env) -> Result<(), Error> {
     Ok(())
 }
 
+/// Fails if the contract is globally paused or the given operation category is paused.
+pub fn require_not_paused_for(env: &Env, category: u32) -> Result<(), Error> {
+    require_not_paused(env)?;
+    if storage::get_pause_flags(env) & category != 0 {
+        return Err(Error::Paused);
+    }
+    Ok(())
+}
+
+/// Pause one or more operation categories (admin only).
+/// Categories not in `categories` keep their current state.
+pub fn pause_operations(env: &Env, caller: &Address, categories: u32) -> Result<u32, Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if categories == 0 || categories & !PAUSE_ALL != 0 {
+        return Err(Error::InvalidConfig);
+    }
+
+    let flags = storage::get_pause_flags(env) | categories;
+    storage::set_pause_flags(env, flags);
+    events::operations_paused(env, caller.clone(), categories, flags);
+    Ok(flags)
+}
+
+/// Resume one or more operation categories (admin only).
+/// Does not lift a global emergency pause, which still needs the M-of-N flow.
+pub fn unpause_operations(env: &Env, caller: &Address, categories: u32) -> Result<u32, Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if categories == 0 || categories & !PAUSE_ALL != 0 {
+        return Err(Error::InvalidConfig);
+    }
+
+    let flags = storage::get_pause_flags(env) & !categories;
+    storage::set_pause_flags(env, flags);
+    events::operations_unpaused(env, caller.clone(), categories, flags);
+    Ok(flags)
+}
+
 /// Pause the contract immediately (admin only)
 pub fn emergency_pause(env: &Env, caller: &Address) -> Result<(), Error> {
     caller.require_auth();
@@ -24,11 +75,15 @@ pub fn emergency_pause(env: &Env, caller: &Address) -> Result<(), Error> {
     }
 
     storage::set_paused(env, true);
+    storage::clear_unpause_approvals(env);
     events::emergency_paused(env, caller.clone());
     Ok(())
 }
 
 /// Approve an unpause. When approvals meet threshold a timelock is scheduled.
+///
+/// Only possible while paused: every pause clears earlier approvals, so
+/// approvals cannot be collected ahead of a pause or circuit breaker trip.
 pub fn emergency_approve_unpause(env: &Env, caller: &Address) -> Result<(), Error> {
     caller.require_auth();
 
@@ -36,6 +91,10 @@ pub fn emergency_approve_unpause(env: &Env, caller: &Address) -> Result<(), Erro
         return Err(Error::Unauthorized);
     }
 
+    if !storage::is_paused(env) {
+        return Err(Error::InvalidStatusTransition);
+    }
+
     if storage::has_unpause_approval(env, caller) {
         return Err(Error::AlreadyApproved);
     }
@@ -81,12 +140,16 @@ pub fn emergency_unpause(env: &Env, caller: &Address) -> Result<(), Error> {
     Ok(())
 }
 
-/// Emergency withdrawal when paused (admin only)
+/// Emergency withdrawal when paused (admin only).
+///
+/// Funds can only be sent to the pre-registered recovery address, so a single
+/// compromised admin key cannot redirect them. The amount is booked as
+/// recovered, so the solvency audit keeps counting it against the liabilities
+/// it backs (see `solvency::return_recovered`).
 pub fn emergency_withdraw(
     env: &Env,
     caller: &Address,
     asset: &Address,
-    to: &Address,
     amount: i128,
 ) -> Result<(), Error> {
     caller.require_auth();
@@ -103,6 +166,8 @@ pub fn emergency_withdraw(
         return Err(Error::InvalidRewardAmount);
     }
 
+    let to = storage::get_recovery_address(env).ok_or(Error::RecoveryAddressNotSet)?;
+
     let token_client = token::Client::new(env, asset);
     let contract_address = env.current_contract_address();
 
@@ -111,33 +176,418 @@ pub fn emergency_withdraw(
         return Err(Error::InsufficientBalance);
     }
 
-    let transfer_result = token_client.try_transfer(&contract_address, to, &amount);
+    let transfer_result = token_client.try_transfer(&contract_address, &to, &amount);
 
     match transfer_result {
         Ok(Ok(_)) => {
-            events::emergency_withdrawn(env, caller.clone(), asset.clone(), to.clone(), amount);
+            // The tokens still back the contract's liabilities until returned
+            storage::adjust_recovered(env, asset, amount);
+            events::emergency_withdrawn(env, caller.clone(), asset.clone(), to, amount);
             Ok(())
         }
         _ => Err(Error::TransferFailed),
     }
 }
 
-/// Admins can configure the required approvals threshold
+//================================================================================
+// Privileged addresses (M-of-N + timelock, same rules as unpause)
+//================================================================================
+
+/// Propose a new recovery address (admin only).
+/// Replaces any pending proposal; earlier approvals do not carry over.
+pub fn propose_recovery_address(
+    env: &Env,
+    caller: &Address,
+    address: &Address,
+) -> Result<u32, Error> {
+    propose(
+        env,
+        caller,
+        ProposalTarget::RecoveryAddress,
+        ProposalValue::Address(address.clone()),
+    )
+}
+
+/// Approve the pending recovery address proposal.
+/// When approvals meet the unpause threshold the unpause timelock is scheduled.
+pub fn approve_recovery_address(env: &Env, caller: &Address) -> Result<(), Error> {
+    approve(env, caller, ProposalTarget::RecoveryAddress)
+}
+
+/// Apply the pending recovery address once approvals are met and the timelock expired.
+pub fn execute_recovery_address(env: &Env, caller: &Address) -> Result<(), Error> {
+    let address = take_executable_address(env, caller, ProposalTarget::RecoveryAddress)?;
+    storage::set_recovery_address(env, &address);
+    events::recovery_address_set(env, caller.clone(), address);
+    Ok(())
+}
+
+/// Propose replacing the contract's wasm with an uploaded `wasm_hash` (admin only).
+///
+/// Upgrading swaps every rule in the contract, so it goes through the same
+/// M-of-N approval and timelock as the recovery address.
+pub fn propose_upgrade(env: &Env, caller: &Address, wasm_hash: &BytesN<32>) -> Result<u32, Error> {
+    propose(
+        env,
+        caller,
+        ProposalTarget::ContractCode,
+        ProposalValue::WasmHash(wasm_hash.clone()),
+    )
+}
+
+/// Approve the pending upgrade proposal.
+pub fn approve_upgrade(env: &Env, caller: &Address) -> Result<(), Error> {
+    approve(env, caller, ProposalTarget::ContractCode)
+}
+
+/// Replace the contract's wasm once the upgrade proposal's approvals are met
+/// and the timelock expired. Entries left by the previous version are moved
+/// with `migrate_storage` afterwards.
+pub fn execute_upgrade(env: &Env, caller: &Address) -> Result<(), Error> {
+    let proposal = take_executable_proposal(env, caller, ProposalTarget::ContractCode)?;
+    let ProposalValue::WasmHash(wasm_hash) = proposal.value else {
+        return Err(Error::InvalidAddress);
+    };
+    events::contract_upgraded(env, caller.clone(), wasm_hash.clone());
+    env.deployer().update_current_contract_wasm(wasm_hash);
+    Ok(())
+}
+
+/// Start a proposal to change `target` to `value` (admin only).
+pub fn propose(
+    env: &Env,
+    caller: &Address,
+    target: ProposalTarget,
+    value: ProposalValue,
+) -> Result<u32, Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    let id = storage::next_proposal_id(env);
+    let proposal = Proposal {
+        id,
+        value: value.clone(),
+        proposer: caller.clone(),
+        approvers: Vec::new(env),
+        executable_at: None,
+    };
+    storage::set_pending_proposal(env, target, &proposal);
+    events::proposed(env, target, id, caller.clone(), value);
+    Ok(id)
+}
+
+/// Approvals a proposal for `target` needs: the unpause threshold. For
+/// `AddAdmin` it is capped at the number of admins, so a deployment with
+/// fewer admins than the threshold can still appoint more.
+fn required_approvals(env: &Env, target: ProposalTarget) -> u32 {
+    let threshold = storage::get_unpause_threshold(env);
+    match (target, storage::get_admin_count(env)) {
+        (ProposalTarget::AddAdmin, Some(admins)) => threshold.min(admins).max(1),
+        _ => threshold,
+    }
+}
+
+/// Approvals of `proposal` from addresses that are still admins.
+fn current_approvals(env: &Env, proposal: &Proposal) -> u32 {
+    proposal
+        .approvers
+        .iter()
+        .filter(|approver| storage::is_admin(env, approver))
+        .count() as u32
+}
+
+/// Approve the pending proposal for `target` (admin only).
+/// When approvals meet the unpause threshold the unpause timelock is scheduled.
+pub fn approve(env: &Env, caller: &Address, target: ProposalTarget) -> Result<(), Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut proposal =
+        storage::get_pending_proposal(env, target).ok_or(Error::NoPendingProposal)?;
+
+    if proposal.approvers.contains(caller) {
+        return Err(Error::AlreadyApproved);
+    }
+
+    proposal.approvers.push_back(caller.clone());
+    let approvals = current_approvals(env, &proposal);
+    events::proposal_approved(env, target, proposal.id, caller.clone(), approvals);
+
+    if proposal.executable_at.is_none() && approvals >= required_approvals(env, target) {
+        let now = env.ledger().timestamp();
+        let tl = storage::get_unpause_timelock_seconds(env);
+        let scheduled = now + tl;
+        proposal.executable_at = Some(scheduled);
+        events::proposal_scheduled(env, target, proposal.id, scheduled);
+    }
+
+    storage::set_pending_proposal(env, target, &proposal);
+    Ok(())
+}
+
+/// Remove and return the pending proposal for `target` once approvals are
+/// met and the timelock expired (admin only). The caller applies the change.
+pub fn take_executable_proposal(
+    env: &Env,
+    caller: &Address,
+    target: ProposalTarget,
+) -> Result<Proposal, Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    let proposal = storage::get_pending_proposal(env, target).ok_or(Error::NoPendingProposal)?;
+
+    let executable_at = proposal.executable_at.ok_or(Error::InsufficientApprovals)?;
+
+    // Approvals from admins removed since are no longer counted
+    if current_approvals(env, &proposal) < required_approvals(env, target) {
+        return Err(Error::InsufficientApprovals);
+    }
+
+    if env.ledger().timestamp() < executable_at {
+        return Err(Error::TimelockNotExpired);
+    }
+
+    storage::clear_pending_proposal(env, target);
+    Ok(proposal)
+}
+
+/// `take_executable_proposal` for targets whose value is an address.
+pub fn take_executable_address(
+    env: &Env,
+    caller: &Address,
+    target: ProposalTarget,
+) -> Result<Address, Error> {
+    match take_executable_proposal(env, caller, target)?.value {
+        ProposalValue::Address(address) => Ok(address),
+        ProposalValue::WasmHash(_) => Err(Error::InvalidAddress),
+    }
+}
+
+/// Raise the number of admin approvals required for unpause and recovery changes.
+///
+/// The threshold can only go up: lowering it (or setting it to 0) would let a
+/// single admin schedule a recovery address change and drain the contract via
+/// `emergency_withdraw`, so it fails with `InsufficientApprovals`.
 pub fn set_unpause_threshold(env: &Env, caller: &Address, threshold: u32) -> Result<(), Error> {
     caller.require_auth();
     if !storage::is_admin(env, caller) {
         return Err(Error::Unauthorized);
     }
+    if threshold == 0 || threshold < storage::get_unpause_threshold(env) {
+        return Err(Error::InsufficientApprovals);
+    }
     storage::set_unpause_threshold(env, threshold);
     Ok(())
 }
 
-/// Admins can configure timelock seconds for unpause scheduling
+/// Raise the timelock applied to unpause and recovery changes.
+///
+/// Like the threshold, the timelock can only be lengthened; shortening it
+/// fails with `TimelockNotExpired`.
 pub fn set_unpause_timelock(env: &Env, caller: &Address, seconds: u64) -> Result<(), Error> {
     caller.require_auth();
     if !storage::is_admin(env, caller) {
         return Err(Error::Unauthorized);
     }
+    if seconds < storage::get_unpause_timelock_seconds(env) {
+        return Err(Error::TimelockNotExpired);
+    }
     storage::set_unpause_timelock_seconds(env, seconds);
     Ok(())
 }
+
+//================================================================================
+// Payout circuit breaker
+//================================================================================
+
+/// Basis-point denominator used for balance-share limits
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Number of sub-windows the rolling payout window is tracked in
+const PAYOUT_WINDOW_BUCKETS: u64 = 8;
+
+/// Configure the payout circuit breaker for an asset (admin only).
+/// Passing `max_amount == 0` and `max_bps_of_balance == 0` removes the breaker.
+pub fn set_circuit_breaker(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    window_seconds: u64,
+    max_amount: i128,
+    max_bps_of_balance: u32,
+) -> Result<(), Error> {
+    caller.require_auth();
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if max_amount < 0 || max_bps_of_balance as i128 > BPS_DENOMINATOR {
+        return Err(Error::InvalidConfig);
+    }
+
+    if max_amount == 0 && max_bps_of_balance == 0 {
+        storage::remove_circuit_breaker(env, asset);
+    } else {
+        if window_seconds == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        let config = CircuitBreakerConfig {
+            window_seconds,
+            max_amount,
+            max_bps_of_balance,
+        };
+        storage::set_circuit_breaker(env, asset, &config);
+    }
+    storage::clear_payout_window(env, asset);
+
+    events::circuit_configured(
+        env,
+        caller.clone(),
+        asset.clone(),
+        window_seconds,
+        max_amount,
+        max_bps_of_balance,
+    );
+    Ok(())
+}
+
+/// Check a payout against the asset's rolling circuit breaker window before
+/// anything is paid.
+///
+/// Returns `false` when the payout would push the window total above the
+/// limit. The breaker then trips: the contract is paused via
+/// `storage::set_paused` and a `circuit_tripped` event is emitted. Callers must
+/// return `Ok(false)` without paying so the pause is kept (an error would
+/// revert it); the claim stays open until the normal M-of-N unpause flow
+/// completes and the window has room again.
+pub fn admit_payout(env: &Env, asset: &Address, amount: i128) -> bool {
+    let config = match storage::get_circuit_breaker(env, asset) {
+        Some(c) => c,
+        None => return true,
+    };
+
+    let now = env.ledger().timestamp();
+    let window = rolling_window(env, asset, &config, now);
+
+    // Balance before this window's payouts, used as the reference for share limits
+    let reference_balance = if config.max_bps_of_balance > 0 {
+        token::Client::new(env, asset).balance(&env.current_contract_address()) + window.total_paid
+    } else {
+        0
+    };
+
+    let limit = payout_limit(&config, reference_balance);
+    if window.total_paid + amount <= limit {
+        return true;
+    }
+
+    storage::set_paused(env, true);
+    storage::clear_unpause_approvals(env);
+    let window_start = match window.buckets.first() {
+        Some(bucket) => bucket.start,
+        None => now,
+    };
+    events::circuit_tripped(
+        env,
+        asset.clone(),
+        window_start,
+        window.total_paid + amount,
+        window.payout_count + 1,
+        limit,
+    );
+    false
+}
+
+/// Add a completed payout to the asset's rolling circuit breaker window.
+///
+/// Called by `payout::transfer_reward_from_escrow` after a successful
+/// transfer that was admitted by `admit_payout`.
+pub fn record_payout_volume(env: &Env, asset: &Address, amount: i128) {
+    let config = match storage::get_circuit_breaker(env, asset) {
+        Some(c) => c,
+        None => return,
+    };
+
+    let now = env.ledger().timestamp();
+    let mut window = rolling_window(env, asset, &config, now);
+    let start = now - now % bucket_seconds(&config);
+
+    let mut bucket = match window.buckets.last() {
+        Some(last) if last.start == start => {
+            window.buckets.pop_back();
+            last
+        }
+        _ => PayoutBucket {
+            start,
+            paid: 0,
+            count: 0,
+        },
+    };
+    bucket.paid += amount;
+    bucket.count += 1;
+    window.buckets.push_back(bucket);
+    window.total_paid += amount;
+    window.payout_count += 1;
+
+    storage::set_payout_window(env, asset, &window);
+}
+
+/// Length of one sub-window of the rolling payout window.
+fn bucket_seconds(config: &CircuitBreakerConfig) -> u64 {
+    (config.window_seconds / PAYOUT_WINDOW_BUCKETS).max(1)
+}
+
+/// The asset's payout window with sub-windows that have rolled out of it dropped.
+///
+/// A sub-window is kept while any part of it lies within `window_seconds` of
+/// the current sub-window, so the total never undercounts the last
+/// `window_seconds` of payouts.
+fn rolling_window(
+    env: &Env,
+    asset: &Address,
+    config: &CircuitBreakerConfig,
+    now: u64,
+) -> PayoutWindow {
+    let mut window = storage::get_payout_window(env, asset).unwrap_or(PayoutWindow {
+        buckets: Vec::new(env),
+        total_paid: 0,
+        payout_count: 0,
+    });
+
+    let current = now - now % bucket_seconds(config);
+    while let Some(oldest) = window.buckets.first() {
+        if oldest.start.saturating_add(config.window_seconds) >= current {
+            break;
+        }
+        window.total_paid -= oldest.paid;
+        window.payout_count -= oldest.count;
+        window.buckets.pop_front();
+    }
+    window
+}
+
+/// Effective per-window limit: the tighter of the absolute and balance-share caps.
+fn payout_limit(config: &CircuitBreakerConfig, reference_balance: i128) -> i128 {
+    let share_limit = if config.max_bps_of_balance > 0 {
+        Some(reference_balance * config.max_bps_of_balance as i128 / BPS_DENOMINATOR)
+    } else {
+        None
+    };
+
+    match (config.max_amount > 0, share_limit) {
+        (true, Some(share)) => config.max_amount.min(share),
+        (true, None) => config.max_amount,
+        (false, Some(share)) => share,
+        (false, None) => i128::MAX,
+    }
+}
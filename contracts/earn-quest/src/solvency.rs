@@ -0,0 +1,137 @@
+//! Solvency module — reconciles tracked liabilities against real token balances.
+//!
+//! MONEY FLOW:
+//!
+//!   Liabilities per asset = escrow liability (sum of `escrow::available` over
+//!                           every quest funded in that asset) + treasury balance
+//!                           + submission bonds held
+//!                           + refundable creation deposits held
+//!   audit:          Contract token balance + recovered  vs  liabilities  →  surplus or deficit
+//!   sweep_surplus:  Untracked tokens (e.g. direct transfers)  →  treasury balance
+//!
+//! The escrow liability is maintained by escrow deposits, payouts and refunds;
+//! the bond and deposit liabilities by bonds and creation deposits being
+//! taken and returned.
+//! Escrows written by older versions are booked when `migrate_storage` moves them.
+//! Tokens moved out by `emergency_withdraw` are booked as recovered until
+//! returned, so they are never mistaken for a deficit or, once returned, a surplus.
+//! Like the treasury balance it is pure accounting; no tokens move during an audit.
+
+use soroban_sdk::{token, Address, Env};
+
+use crate::errors::Error;
+use crate::events;
+use crate::security;
+use crate::storage;
+use crate::types::{ProposalTarget, ProposalValue, SolvencyReport};
+
+// ═══════════════════════════════════════════════════════════════
+// AUDIT: Compare liabilities with the contract's token balance
+// ═══════════════════════════════════════════════════════════════
+
+/// Reconcile an asset's liabilities against the contract's token balance.
+pub fn audit(env: &Env, asset: &Address) -> SolvencyReport {
+    let balance = token::Client::new(env, asset).balance(&env.current_contract_address());
+    let escrow_liability = storage::get_escrow_liability(env, asset);
+    let treasury_liability = storage::get_treasury_balance(env, asset);
+    let bond_liability = storage::get_bond_liability(env, asset);
+    let deposit_liability = storage::get_deposit_liability(env, asset);
+    let recovered = storage::get_recovered(env, asset);
+
+    let net = balance + recovered
+        - escrow_liability
+        - treasury_liability
+        - bond_liability
+        - deposit_liability;
+    SolvencyReport {
+        asset: asset.clone(),
+        balance,
+        escrow_liability,
+        treasury_liability,
+        bond_liability,
+        deposit_liability,
+        recovered,
+        surplus: net.max(0),
+        deficit: (-net).max(0),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// SWEEP: Credit untracked tokens to the treasury
+// ═══════════════════════════════════════════════════════════════
+
+/// Propose crediting an asset's surplus to the treasury (admin only).
+///
+/// Sweeping follows the same M-of-N approval and timelock as the recovery
+/// address: a surplus the ledger misjudges (e.g. an escrow not yet migrated)
+/// must not be movable to the treasury by a single admin.
+pub fn propose_sweep(env: &Env, caller: &Address, asset: &Address) -> Result<u32, Error> {
+    security::propose(
+        env,
+        caller,
+        ProposalTarget::SurplusSweep,
+        ProposalValue::Address(asset.clone()),
+    )
+}
+
+/// Approve the pending sweep proposal (admin only).
+pub fn approve_sweep(env: &Env, caller: &Address) -> Result<(), Error> {
+    security::approve(env, caller, ProposalTarget::SurplusSweep)
+}
+
+/// Credit the proposed asset's surplus, as audited now, to its treasury
+/// balance once approvals are met and the timelock expired.
+/// The tokens stay in the contract until the Treasurer withdraws them.
+///
+/// # Flow
+/// ```text
+/// Treasury balance += surplus
+/// ```
+///
+/// Returns the amount swept.
+pub fn sweep_surplus(env: &Env, caller: &Address) -> Result<i128, Error> {
+    let asset = security::take_executable_address(env, caller, ProposalTarget::SurplusSweep)?;
+
+    let report = audit(env, &asset);
+    if report.surplus <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    let treasury = report.treasury_liability + report.surplus;
+    storage::set_treasury_balance(env, &asset, treasury);
+    events::surplus_swept(env, caller.clone(), asset, report.surplus, treasury);
+
+    Ok(report.surplus)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RETURN: Bring emergency-withdrawn tokens back
+// ═══════════════════════════════════════════════════════════════
+
+/// Transfer tokens taken by `emergency_withdraw` back into the contract.
+/// Anyone may return them (typically the recovery address); at most the
+/// amount still booked as recovered is accepted.
+pub fn return_recovered(
+    env: &Env,
+    from: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    from.require_auth();
+
+    let recovered = storage::get_recovered(env, asset);
+    if amount <= 0 || amount > recovered {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    // Transfer tokens: returner → contract
+    let token_client = token::Client::new(env, asset);
+    match token_client.try_transfer(from, &env.current_contract_address(), &amount) {
+        Ok(Ok(_)) => {}
+        _ => return Err(Error::TransferFailed),
+    }
+    storage::adjust_recovered(env, asset, -amount);
+    events::recovered_returned(env, from.clone(), asset.clone(), amount, recovered - amount);
+
+    Ok(())
+}
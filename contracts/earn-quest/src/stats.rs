@@ -0,0 +1,107 @@
+//! Platform and per-creator activity counters.
+//!
+//! Every counter is kept twice: once for the whole platform and once for the
+//! quest's creator. Counters are cumulative and survive quest pruning.
+
+use soroban_sdk::{Address, Env, Map};
+
+use crate::storage;
+use crate::types::{ActivityStats, QuestStatus};
+
+/// A new quest was registered (it starts Unfunded).
+pub fn record_quest_created(env: &Env, creator: &Address) {
+    update(env, creator, |stats| stats.quests_unfunded += 1);
+}
+
+/// A quest moved from one status to another.
+pub fn record_status_change(env: &Env, creator: &Address, from: &QuestStatus, to: &QuestStatus) {
+    update(env, creator, |stats| {
+        let from_count = status_count(stats, from);
+        *from_count = from_count.saturating_sub(1);
+        *status_count(stats, to) += 1;
+    });
+}
+
+/// A first-time proof submission was recorded.
+pub fn record_submission(env: &Env, creator: &Address) {
+    update(env, creator, |stats| stats.total_submissions += 1);
+}
+
+/// A submission was approved.
+pub fn record_approval(env: &Env, creator: &Address) {
+    update(env, creator, |stats| stats.total_approvals += 1);
+}
+
+/// A reward was claimed; `amount` is what the claimant received.
+pub fn record_claim(env: &Env, creator: &Address, asset: &Address, amount: i128) {
+    update(env, creator, |stats| {
+        stats.total_claims += 1;
+        add_to(&mut stats.paid_volume, asset, amount);
+    });
+}
+
+/// Tokens held in escrow for `asset` changed by `delta`.
+pub fn record_escrow_change(env: &Env, creator: &Address, asset: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    update(env, creator, |stats| {
+        add_to(&mut stats.active_escrow, asset, delta)
+    });
+}
+
+/// Platform-wide counters.
+pub fn get_platform_stats(env: &Env) -> ActivityStats {
+    storage::get_platform_stats(env).unwrap_or_else(|| empty(env))
+}
+
+/// Counters for quests created by `creator`.
+pub fn get_creator_stats(env: &Env, creator: &Address) -> ActivityStats {
+    storage::get_creator_stats(env, creator).unwrap_or_else(|| empty(env))
+}
+
+fn update(env: &Env, creator: &Address, apply: impl Fn(&mut ActivityStats)) {
+    let mut platform = get_platform_stats(env);
+    apply(&mut platform);
+    storage::set_platform_stats(env, &platform);
+
+    let mut by_creator = get_creator_stats(env, creator);
+    apply(&mut by_creator);
+    storage::set_creator_stats(env, creator, &by_creator);
+}
+
+fn status_count<'a>(stats: &'a mut ActivityStats, status: &QuestStatus) -> &'a mut u32 {
+    match status {
+        QuestStatus::Unfunded => &mut stats.quests_unfunded,
+        QuestStatus::Active => &mut stats.quests_active,
+        QuestStatus::Paused => &mut stats.quests_paused,
+        QuestStatus::Completed => &mut stats.quests_completed,
+        QuestStatus::Expired => &mut stats.quests_expired,
+        QuestStatus::Cancelled => &mut stats.quests_cancelled,
+    }
+}
+
+fn add_to(totals: &mut Map<Address, i128>, asset: &Address, delta: i128) {
+    let total = totals.get(asset.clone()).unwrap_or(0) + delta;
+    if total == 0 {
+        totals.remove(asset.clone());
+    } else {
+        totals.set(asset.clone(), total);
+    }
+}
+
+fn empty(env: &Env) -> ActivityStats {
+    ActivityStats {
+        quests_unfunded: 0,
+        quests_active: 0,
+        quests_paused: 0,
+        quests_completed: 0,
+        quests_expired: 0,
+        quests_cancelled: 0,
+        total_submissions: 0,
+        total_approvals: 0,
+        total_claims: 0,
+        paid_volume: Map::new(env),
+        active_escrow: Map::new(env),
+    }
+}
@@ -1,10 +1,18 @@
 use crate::errors::Error;
-use crate::types::{Quest, QuestStatus, Submission, SubmissionStatus, UserStats};
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::types::{
+    AccessMode, ActivityStats, AnswerConfig, AssetConfig, Bond, BondConfig, Campaign,
+    CircuitBreakerConfig, CreationDeposit, DuplicateAction, EscrowContribution, EscrowInfo,
+    FeeConfig, FeeMode, FraudFlag, LegacyEscrowInfo, LotteryDraw, MerkleRound, PayoutWindow,
+    Prerequisites, ProofPolicy, ProofScope, ProofUse, Proposal, ProposalTarget, Quest, QuestStatus,
+    RefundPolicy, SponsorPolicy, Submission, SubmissionKey, SubmissionStatus, TtlConfig, UserStats,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
 
 /// Storage key definitions for the contract's persistent data.
 ///
-/// This enum defines all possible keys used to store data in the contract's instance storage.
+/// Per-entity records (`Quest`, `Submission`, `UserStats`, `Escrow`) and the indices
+/// over them live in persistent storage with their TTL extended on read and write. Everything else is configuration
+/// or admin state and lives in instance storage, which is loaded on every invocation.
 /// Each variant represents a different type of data with its associated key structure.
 #[contracttype]
 pub enum DataKey {
@@ -18,17 +26,188 @@ pub enum DataKey {
     Admin(Address),
     /// Global paused flag
     Paused,
-    /// Stores per-admin approval for unpause
-    UnpauseApproval(Address),
+    /// Bitmap of individually paused operation categories
+    PauseFlags,
     /// Number of approvals required to unpause
     UnpauseThreshold,
-    /// Count of approvals recorded
-    UnpauseApprovalCount,
+    /// Admins who approved lifting the current emergency pause
+    UnpauseApprovers,
     /// Timelock seconds to wait after approvals before unpause can be executed
     UnpauseTimelockSeconds,
     /// Scheduled unpause ledger timestamp
     ScheduledUnpauseTime,
+    /// Address that receives emergency withdrawals
+    RecoveryAddress,
+    /// Pending address change awaiting approvals / timelock, keyed by target
+    PendingProposal(ProposalTarget),
+    /// Counter used to assign address proposal ids
+    ProposalCount,
+    /// Number of admins
+    AdminCount,
+    /// Reward asset registry entry, keyed by asset
+    AssetConfig(Address),
+    /// Global platform fee configuration
+    FeeConfig,
+    /// Per-asset platform fee override in basis points
+    AssetFeeBps(Address),
+    /// Accrued platform fees awaiting withdrawal, keyed by asset
+    TreasuryBalance(Address),
+    /// Admin allowed to withdraw accrued fees
+    Treasurer,
+    /// Payout circuit breaker configuration, keyed by asset
+    CircuitBreaker(Address),
+    /// Current payout window totals, keyed by asset
+    PayoutWindow(Address),
     Escrow(Symbol),
+    /// TTL extension thresholds for persistent entries
+    TtlConfig,
+    /// Number of quest ids recorded in a quest index
+    QuestIndexLen(QuestIndex),
+    /// Quest id at a position in a quest index
+    QuestIndexAt(QuestIndex, u32),
+    /// Number of submitters recorded for a quest
+    SubmissionIndexLen(Symbol),
+    /// Submitter at a position in a quest's submission index
+    SubmissionIndexAt(Symbol, u32),
+    /// Number of pending submissions awaiting a verifier
+    VerifierPendingLen(Address),
+    /// Pending submission at a position in a verifier's queue
+    VerifierPendingAt(Address, u32),
+    /// Lifetime reward earnings of a user, keyed by asset
+    UserEarnings(Address),
+    /// Ledger timestamp at which a quest entered a terminal status
+    QuestClosedAt(Symbol),
+    /// Seconds a terminal quest is retained before it can be pruned
+    RetentionPeriod,
+    /// Platform-wide activity counters
+    PlatformStats,
+    /// Activity counters for quests created by an address
+    CreatorStats(Address),
+    /// Per-asset totals reconciled by the solvency audit
+    Solvency(Address, SolvencyEntry),
+    /// Charge taken from creators for each quest they register
+    CreationDeposit,
+    /// Most non-terminal quests a creator may have at once (0 = no cap)
+    MaxActiveQuests,
+    /// Number of rewards a quest's escrow must cover before it opens
+    MinFundedRewards,
+    /// Net contribution of each depositor to a quest's escrow, most recent last
+    EscrowContributions(Symbol),
+    /// Creator-controlled sponsorship rules for a quest
+    SponsorPolicy(Symbol),
+    /// Sponsors allowed to fund a quest when its allowlist is enabled
+    SponsorAllowlist(Symbol),
+    /// Campaign record, keyed by campaign id
+    Campaign(Symbol),
+    /// Escrow pool shared by a campaign's quests
+    CampaignEscrow(Symbol),
+    /// Campaign a quest draws its rewards from
+    QuestCampaign(Symbol),
+    /// Fraud flag of a denylisted address
+    FraudFlag(Address),
+    /// XP removed from an address when it is flagged for fraud
+    FraudXpPenalty,
+    /// Auxiliary per-quest records for optional quest features
+    QuestData(Symbol, QuestKey),
+    /// First use of a proof hash across quests using the global registry
+    ProofUse(BytesN<32>),
+}
+
+/// Per-quest records of optional quest features, stored under
+/// `DataKey::QuestData(quest_id, ..)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QuestKey {
+    /// Number of Merkle roots posted (only deleted by pruning, so round ids are not reused)
+    MerkleRoundCount,
+    /// Posted Merkle round totals not yet claimed, held back from new payouts
+    MerkleOutstanding,
+    /// Merkle root posted, keyed by round id
+    MerkleRound(u32),
+    /// Claimed-leaf bitmap of a Merkle round; each word covers 128 leaf indices
+    MerkleClaimed(u32, u32),
+    /// ed25519 public key allowed to sign attestations
+    Attestor,
+    /// Attestation nonce already used
+    AttestationNonce(u64),
+    /// Expected answer of an answer-hash quest
+    Answer,
+    /// Proof-hash uniqueness rules
+    ProofPolicy,
+    /// First use of a proof hash within the quest
+    ProofUse(BytesN<32>),
+    /// Earlier use of the proof hash a flagged submission duplicates
+    DuplicateFlag(Address),
+    /// Requirements for submitting proof
+    Prerequisites,
+    /// Who may submit proof
+    AccessMode,
+    /// Participant admitted to a non-open quest
+    Participant(Address),
+    /// Unredeemed invite code, keyed by its sha256 hash
+    InviteCode(BytesN<32>),
+    /// Bond submitters must post with their proof
+    BondConfig,
+    /// Bond held for a submitter
+    Bond(Address),
+    /// Refundable creation deposit held for the creator
+    CreationDeposit,
+    /// Number of winners a lottery quest draws
+    Lottery,
+    /// Outcome of a lottery quest's draw
+    LotteryDraw,
+    /// Submitter drawn as a lottery winner
+    LotteryWinner(Address),
+    /// Number of approved entrants of a lottery quest
+    LotteryEntrants,
+    /// Approved lottery entrant at a position
+    LotteryEntrantAt(u32),
+    /// Position of an approved submitter among the lottery entrants
+    LotteryEntrantSlot(Address),
+    /// Entrant not drawn who has claimed participation XP
+    ParticipationClaimed(Address),
+    /// Position of the quest in a `QuestIndex`
+    IndexSlot(QuestIndex),
+    /// Ledger timestamp the quest was pruned; its id is never registered again
+    Archived,
+    /// Position of a pending submission in the verifier's queue
+    PendingSlot(Address),
+    /// User has been paid for the quest; kept when the quest is pruned
+    Completed(Address),
+}
+
+/// Per-asset totals reconciled by the solvency audit, stored under
+/// `DataKey::Solvency(asset, ..)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolvencyEntry {
+    /// Sum of available escrow across all quests
+    EscrowLiability,
+    /// Sum of submission bonds held
+    BondLiability,
+    /// Sum of refundable quest creation deposits held
+    DepositLiability,
+    /// Tokens sent to the recovery address by emergency withdrawals and not yet returned
+    Recovered,
+}
+
+/// Quest indices. Quests are recorded in registration order and
+/// swap-removed when pruned; `Status` also swap-removes quests as they leave it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QuestIndex {
+    /// Every quest
+    All,
+    /// Quests created by an address
+    Creator(Address),
+    /// Quests verified by an address
+    Verifier(Address),
+    /// Quests an address has submitted proof for
+    Submitter(Address),
+    /// Quests funded by a campaign
+    Campaign(Symbol),
+    /// Quests currently in a status
+    Status(QuestStatus),
 }
 
 //================================================================================
@@ -45,10 +224,10 @@ pub enum DataKey {
 /// * `true` if the quest exists, `false` otherwise
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (existence check only)
+/// * Reads from: Persistent storage (existence check only)
 /// * Gas Cost: Low (existence check is cheaper than full read)
 pub fn has_quest(env: &Env, id: &Symbol) -> bool {
-    env.storage().instance().has(&DataKey::Quest(id.clone()))
+    env.storage().persistent().has(&DataKey::Quest(id.clone()))
 }
 
 /// Retrieves a quest by its ID from storage.
@@ -62,13 +241,17 @@ pub fn has_quest(env: &Env, id: &Symbol) -> bool {
 /// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage
+/// * Reads from: Persistent storage
 /// * Gas Cost: Moderate (full struct read)
 pub fn get_quest(env: &Env, id: &Symbol) -> Result<Quest, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Quest(id.clone()))
-        .ok_or(Error::QuestNotFound)
+    let key = DataKey::Quest(id.clone());
+    let quest = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::QuestNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(quest)
 }
 
 /// Stores or updates a quest in storage.
@@ -79,16 +262,16 @@ pub fn get_quest(env: &Env, id: &Symbol) -> Result<Quest, Error> {
 /// * `quest` - The quest data to store
 ///
 /// # Storage Access
-/// * Writes to: Instance storage
+/// * Writes to: Persistent storage
 /// * Gas Cost: High (full struct write)
 ///
 /// # Notes
 /// * Overwrites existing quest data if the ID already exists
 /// * For partial updates, consider using specialized functions like `update_quest_status()`
 pub fn set_quest(env: &Env, id: &Symbol, quest: &Quest) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Quest(id.clone()), quest);
+    let key = DataKey::Quest(id.clone());
+    env.storage().persistent().set(&key, quest);
+    extend_persistent_ttl(env, &key);
 }
 
 //================================================================================
@@ -106,11 +289,11 @@ pub fn set_quest(env: &Env, id: &Symbol, quest: &Quest) {
 /// * `true` if the submission exists, `false` otherwise
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (existence check only)
+/// * Reads from: Persistent storage (existence check only)
 /// * Gas Cost: Low
 pub fn has_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool {
     env.storage()
-        .instance()
+        .persistent()
         .has(&DataKey::Submission(quest_id.clone(), submitter.clone()))
 }
 
@@ -126,17 +309,21 @@ pub fn has_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool
 /// * `Err(Error::SubmissionNotFound)` - If the submission doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage
+/// * Reads from: Persistent storage
 /// * Gas Cost: Moderate
 pub fn get_submission(
     env: &Env,
     quest_id: &Symbol,
     submitter: &Address,
 ) -> Result<Submission, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Submission(quest_id.clone(), submitter.clone()))
-        .ok_or(Error::SubmissionNotFound)
+    let key = DataKey::Submission(quest_id.clone(), submitter.clone());
+    let submission = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::SubmissionNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(submission)
 }
 
 /// Stores or updates a submission in storage.
@@ -148,17 +335,16 @@ pub fn get_submission(
 /// * `submission` - The submission data to store
 ///
 /// # Storage Access
-/// * Writes to: Instance storage
+/// * Writes to: Persistent storage
 /// * Gas Cost: High
 ///
 /// # Notes
 /// * Overwrites existing submission data if it already exists
 /// * For status updates only, consider using `update_submission_status()`
 pub fn set_submission(env: &Env, quest_id: &Symbol, submitter: &Address, submission: &Submission) {
-    env.storage().instance().set(
-        &DataKey::Submission(quest_id.clone(), submitter.clone()),
-        submission,
-    );
+    let key = DataKey::Submission(quest_id.clone(), submitter.clone());
+    env.storage().persistent().set(&key, submission);
+    extend_persistent_ttl(env, &key);
 }
 
 //================================================================================
@@ -175,11 +361,11 @@ pub fn set_submission(env: &Env, quest_id: &Symbol, submitter: &Address, submiss
 /// * `true` if the user has stats stored, `false` otherwise
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (existence check only)
+/// * Reads from: Persistent storage (existence check only)
 /// * Gas Cost: Low
 pub fn has_user_stats(env: &Env, user: &Address) -> bool {
     env.storage()
-        .instance()
+        .persistent()
         .has(&DataKey::UserStats(user.clone()))
 }
 
@@ -194,16 +380,20 @@ pub fn has_user_stats(env: &Env, user: &Address) -> bool {
 /// * `Err(Error::UserStatsNotFound)` - If the user has no stats
 ///
 /// # Storage Access
-/// * Reads from: Instance storage
+/// * Reads from: Persistent storage
 /// * Gas Cost: Moderate
 ///
 /// # Notes
 /// * For new users who may not have stats, consider using `get_user_stats_or_default()`
 pub fn get_user_stats(env: &Env, user: &Address) -> Result<UserStats, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::UserStats(user.clone()))
-        .ok_or(Error::UserStatsNotFound)
+    let key = DataKey::UserStats(user.clone());
+    let stats = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::UserStatsNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(stats)
 }
 
 /// Stores or updates user stats in storage.
@@ -214,15 +404,15 @@ pub fn get_user_stats(env: &Env, user: &Address) -> Result<UserStats, Error> {
 /// * `stats` - The user stats to store
 ///
 /// # Storage Access
-/// * Writes to: Instance storage
+/// * Writes to: Persistent storage
 /// * Gas Cost: High
 ///
 /// # Notes
 /// * For XP updates only, consider using `add_user_xp()` for atomic updates
 pub fn set_user_stats(env: &Env, user: &Address, stats: &UserStats) {
-    env.storage()
-        .instance()
-        .set(&DataKey::UserStats(user.clone()), stats);
+    let key = DataKey::UserStats(user.clone());
+    env.storage().persistent().set(&key, stats);
+    extend_persistent_ttl(env, &key);
 }
 
 //================================================================================
@@ -241,8 +431,8 @@ pub fn set_user_stats(env: &Env, user: &Address, stats: &UserStats) {
 /// * `Err(Error::QuestStillActive)` - If the quest is still active or paused
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (to check status)
-/// * Writes to: Instance storage (removal)
+/// * Reads from: Persistent storage (to check status)
+/// * Writes to: Persistent storage (removal)
 /// * Gas Cost: Moderate (read + delete)
 ///
 /// # Safety
@@ -257,7 +447,9 @@ pub fn delete_quest(env: &Env, id: &Symbol) -> Result<(), Error> {
         return Err(Error::QuestStillActive);
     }
 
-    env.storage().instance().remove(&DataKey::Quest(id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Quest(id.clone()));
     Ok(())
 }
 
@@ -269,7 +461,7 @@ pub fn delete_quest(env: &Env, id: &Symbol) -> Result<(), Error> {
 /// * `submitter` - The submitter's address
 ///
 /// # Storage Access
-/// * Writes to: Instance storage (removal)
+/// * Writes to: Persistent storage (removal)
 /// * Gas Cost: Low (delete only, no validation)
 ///
 /// # Notes
@@ -277,7 +469,7 @@ pub fn delete_quest(env: &Env, id: &Symbol) -> Result<(), Error> {
 /// * Use after reward payout to clean up storage
 pub fn delete_submission(env: &Env, quest_id: &Symbol, submitter: &Address) {
     env.storage()
-        .instance()
+        .persistent()
         .remove(&DataKey::Submission(quest_id.clone(), submitter.clone()));
 }
 
@@ -288,7 +480,7 @@ pub fn delete_submission(env: &Env, quest_id: &Symbol, submitter: &Address) {
 /// * `user` - The user's address
 ///
 /// # Storage Access
-/// * Writes to: Instance storage (removal)
+/// * Writes to: Persistent storage (removal)
 /// * Gas Cost: Low
 ///
 /// # Notes
@@ -296,7 +488,7 @@ pub fn delete_submission(env: &Env, quest_id: &Symbol, submitter: &Address) {
 /// * Use with caution - this permanently removes all user reputation data
 pub fn delete_user_stats(env: &Env, user: &Address) {
     env.storage()
-        .instance()
+        .persistent()
         .remove(&DataKey::UserStats(user.clone()));
 }
 
@@ -316,8 +508,8 @@ pub fn delete_user_stats(env: &Env, user: &Address) {
 /// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (full quest read)
-/// * Writes to: Instance storage (full quest write)
+/// * Reads from: Persistent storage (full quest read)
+/// * Writes to: Persistent storage (full quest write)
 /// * Gas Cost: High (read + write, but clearer intent than manual update)
 ///
 /// # Benefits
@@ -326,6 +518,12 @@ pub fn delete_user_stats(env: &Env, user: &Address) {
 /// * Easier to add validation logic in the future
 pub fn update_quest_status(env: &Env, id: &Symbol, status: QuestStatus) -> Result<(), Error> {
     let mut quest = get_quest(env, id)?;
+    if quest.status != status {
+        move_quest_status(env, id, &quest.status, &status);
+        if crate::validation::is_quest_terminal(&status) {
+            set_quest_closed_at(env, id, env.ledger().timestamp());
+        }
+    }
     quest.status = status;
     set_quest(env, id, &quest);
     Ok(())
@@ -342,8 +540,8 @@ pub fn update_quest_status(env: &Env, id: &Symbol, status: QuestStatus) -> Resul
 /// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (full quest read)
-/// * Writes to: Instance storage (full quest write)
+/// * Reads from: Persistent storage (full quest read)
+/// * Writes to: Persistent storage (full quest write)
 /// * Gas Cost: High
 ///
 /// # Benefits
@@ -370,8 +568,8 @@ pub fn increment_quest_claims(env: &Env, id: &Symbol) -> Result<(), Error> {
 /// * `Err(Error::SubmissionNotFound)` - If the submission doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage
-/// * Writes to: Instance storage
+/// * Reads from: Persistent storage
+/// * Writes to: Persistent storage
 /// * Gas Cost: High
 ///
 /// # Benefits
@@ -401,8 +599,8 @@ pub fn update_submission_status(
 /// * `Err(Error::UserStatsNotFound)` - If the user has no stats
 ///
 /// # Storage Access
-/// * Reads from: Instance storage
-/// * Writes to: Instance storage
+/// * Reads from: Persistent storage
+/// * Writes to: Persistent storage
 /// * Gas Cost: High
 ///
 /// # Level Calculation
@@ -457,7 +655,7 @@ pub fn add_user_xp(env: &Env, user: &Address, xp_delta: u64) -> Result<UserStats
 /// * Badges: Empty vector
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (if exists)
+/// * Reads from: Persistent storage (if exists)
 /// * Gas Cost: Low (if exists) or None (if new user)
 ///
 /// # Use Cases
@@ -485,7 +683,7 @@ pub fn get_user_stats_or_default(env: &Env, user: &Address) -> UserStats {
 /// * `None` - If the submission doesn't exist
 ///
 /// # Storage Access
-/// * Reads from: Instance storage (if exists)
+/// * Reads from: Persistent storage (if exists)
 /// * Gas Cost: Low (existence check) or Moderate (if exists)
 ///
 /// # Use Cases
@@ -500,6 +698,393 @@ pub fn get_submission_if_exists(
     get_submission(env, quest_id, submitter).ok()
 }
 
+//================================================================================
+// Quest Indices
+//================================================================================
+
+/// Record a newly stored quest in the global, creator, verifier and status indices.
+///
+/// # Storage Access
+/// * Writes to: Persistent storage (4 index slots, 4 lengths, 1 status slot)
+/// * Gas Cost: Medium
+pub fn index_quest(env: &Env, quest: &Quest) {
+    add_to_quest_index(env, QuestIndex::All, &quest.id);
+    add_to_quest_index(env, QuestIndex::Creator(quest.creator.clone()), &quest.id);
+    add_to_quest_index(env, QuestIndex::Verifier(quest.verifier.clone()), &quest.id);
+    add_to_quest_index(env, QuestIndex::Status(quest.status.clone()), &quest.id);
+}
+
+/// Drop a pruned quest from the indices `index_quest` and campaign funding
+/// added it to. Submitter indices are cleaned per submitter as the quest's
+/// submissions are deleted.
+pub fn unindex_quest(env: &Env, quest: &Quest) {
+    remove_from_quest_index(env, &QuestIndex::All, &quest.id);
+    remove_from_quest_index(env, &QuestIndex::Creator(quest.creator.clone()), &quest.id);
+    remove_from_quest_index(
+        env,
+        &QuestIndex::Verifier(quest.verifier.clone()),
+        &quest.id,
+    );
+    remove_from_quest_index(env, &QuestIndex::Status(quest.status.clone()), &quest.id);
+    if let Some(campaign_id) = get_quest_campaign(env, &quest.id) {
+        remove_from_quest_index(env, &QuestIndex::Campaign(campaign_id), &quest.id);
+    }
+}
+
+fn set_quest_index_len(env: &Env, index: QuestIndex, len: u32) {
+    let len_key = DataKey::QuestIndexLen(index);
+    if len == 0 {
+        env.storage().persistent().remove(&len_key);
+    } else {
+        env.storage().persistent().set(&len_key, &len);
+        extend_persistent_ttl(env, &len_key);
+    }
+}
+
+/// Number of quest ids recorded in an index.
+pub fn get_quest_index_len(env: &Env, index: &QuestIndex) -> u32 {
+    let key = DataKey::QuestIndexLen(index.clone());
+    match env.storage().persistent().get(&key) {
+        Some(len) => {
+            extend_persistent_ttl(env, &key);
+            len
+        }
+        None => 0,
+    }
+}
+
+/// Read up to `limit` quest ids from an index, starting at position `cursor`.
+///
+/// # Storage Access
+/// * Reads from: Persistent storage (1 length + 1 slot per id returned)
+/// * Gas Cost: Proportional to `limit`
+pub fn get_quest_index_page(env: &Env, index: &QuestIndex, cursor: u32, limit: u32) -> Vec<Symbol> {
+    let len = get_quest_index_len(env, index);
+    let end = cursor.saturating_add(limit).min(len);
+
+    let mut ids = Vec::new(env);
+    for i in cursor..end {
+        let slot = DataKey::QuestIndexAt(index.clone(), i);
+        if let Some(id) = env.storage().persistent().get::<_, Symbol>(&slot) {
+            extend_persistent_ttl(env, &slot);
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// Append a quest id to an index and record its position. No-op if the
+/// quest is already in the index.
+pub fn add_to_quest_index(env: &Env, index: QuestIndex, id: &Symbol) {
+    let pos_key = DataKey::QuestData(id.clone(), QuestKey::IndexSlot(index.clone()));
+    if env.storage().persistent().has(&pos_key) {
+        return;
+    }
+
+    let pos = get_quest_index_len(env, &index);
+    let slot = DataKey::QuestIndexAt(index.clone(), pos);
+    env.storage().persistent().set(&slot, id);
+    extend_persistent_ttl(env, &slot);
+    set_quest_index_len(env, index, pos + 1);
+
+    env.storage().persistent().set(&pos_key, &pos);
+    extend_persistent_ttl(env, &pos_key);
+}
+
+/// Drop a quest id from an index (used when the quest changes status or is
+/// pruned). The last id in the index is moved into its slot.
+///
+/// # Storage Access
+/// * Reads/Writes: Persistent storage (at most 2 slots, 1 length, 2 positions)
+/// * Gas Cost: Low (independent of the index length)
+pub fn remove_from_quest_index(env: &Env, index: &QuestIndex, id: &Symbol) {
+    let pos_key = DataKey::QuestData(id.clone(), QuestKey::IndexSlot(index.clone()));
+    let Some(pos) = env.storage().persistent().get::<_, u32>(&pos_key) else {
+        return;
+    };
+    env.storage().persistent().remove(&pos_key);
+
+    let len = get_quest_index_len(env, index);
+    if len == 0 {
+        return;
+    }
+    let last = len - 1;
+    let last_slot = DataKey::QuestIndexAt(index.clone(), last);
+    if pos < last {
+        if let Some(moved) = env.storage().persistent().get::<_, Symbol>(&last_slot) {
+            let slot = DataKey::QuestIndexAt(index.clone(), pos);
+            env.storage().persistent().set(&slot, &moved);
+            extend_persistent_ttl(env, &slot);
+
+            let moved_key = DataKey::QuestData(moved, QuestKey::IndexSlot(index.clone()));
+            env.storage().persistent().set(&moved_key, &pos);
+            extend_persistent_ttl(env, &moved_key);
+        }
+    }
+    env.storage().persistent().remove(&last_slot);
+    set_quest_index_len(env, index.clone(), last);
+}
+
+/// Move a quest id from one status index to another.
+fn move_quest_status(env: &Env, id: &Symbol, from: &QuestStatus, to: &QuestStatus) {
+    remove_from_quest_index(env, &QuestIndex::Status(from.clone()), id);
+    add_to_quest_index(env, QuestIndex::Status(to.clone()), id);
+}
+
+//================================================================================
+// Submission Indices
+//================================================================================
+
+/// Append a submitter to a quest's submission index.
+/// Call once per submission, when it is first stored.
+pub fn index_submission(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let len = get_submission_index_len(env, quest_id);
+    let slot = DataKey::SubmissionIndexAt(quest_id.clone(), len);
+    env.storage().persistent().set(&slot, submitter);
+    extend_persistent_ttl(env, &slot);
+
+    let len_key = DataKey::SubmissionIndexLen(quest_id.clone());
+    env.storage().persistent().set(&len_key, &(len + 1));
+    extend_persistent_ttl(env, &len_key);
+}
+
+/// Number of submitters recorded for a quest.
+pub fn get_submission_index_len(env: &Env, quest_id: &Symbol) -> u32 {
+    let key = DataKey::SubmissionIndexLen(quest_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(len) => {
+            extend_persistent_ttl(env, &key);
+            len
+        }
+        None => 0,
+    }
+}
+
+/// Read the submitters at positions `cursor..end` of a quest's submission index.
+///
+/// # Storage Access
+/// * Reads from: Persistent storage (1 slot per position)
+/// * Gas Cost: Proportional to `end - cursor`
+pub fn get_submission_index_page(
+    env: &Env,
+    quest_id: &Symbol,
+    cursor: u32,
+    end: u32,
+) -> Vec<Address> {
+    let mut submitters = Vec::new(env);
+    for i in cursor..end {
+        let slot = DataKey::SubmissionIndexAt(quest_id.clone(), i);
+        if let Some(submitter) = env.storage().persistent().get::<_, Address>(&slot) {
+            extend_persistent_ttl(env, &slot);
+            submitters.push_back(submitter);
+        }
+    }
+    submitters
+}
+
+/// Remove and return the most recently indexed submitter of a quest.
+/// Returns `None` once the quest's submission index is empty.
+pub fn pop_submission_index(env: &Env, quest_id: &Symbol) -> Option<Address> {
+    let len = get_submission_index_len(env, quest_id);
+    if len == 0 {
+        return None;
+    }
+
+    let slot = DataKey::SubmissionIndexAt(quest_id.clone(), len - 1);
+    let submitter = env.storage().persistent().get(&slot);
+    env.storage().persistent().remove(&slot);
+
+    let len_key = DataKey::SubmissionIndexLen(quest_id.clone());
+    if len == 1 {
+        env.storage().persistent().remove(&len_key);
+    } else {
+        env.storage().persistent().set(&len_key, &(len - 1));
+    }
+    submitter
+}
+
+/// Number of pending submissions queued for `verifier`.
+pub fn get_verifier_pending_len(env: &Env, verifier: &Address) -> u32 {
+    let key = DataKey::VerifierPendingLen(verifier.clone());
+    match env.storage().persistent().get(&key) {
+        Some(len) => {
+            extend_persistent_ttl(env, &key);
+            len
+        }
+        None => 0,
+    }
+}
+
+fn set_verifier_pending_len(env: &Env, verifier: &Address, len: u32) {
+    let key = DataKey::VerifierPendingLen(verifier.clone());
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &len);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Read up to `limit` pending submissions queued for `verifier`, starting at
+/// position `cursor`.
+///
+/// # Storage Access
+/// * Reads from: Persistent storage (1 length + 1 slot per entry returned)
+/// * Gas Cost: Proportional to `limit`
+pub fn get_verifier_pending_page(
+    env: &Env,
+    verifier: &Address,
+    cursor: u32,
+    limit: u32,
+) -> Vec<SubmissionKey> {
+    let len = get_verifier_pending_len(env, verifier);
+    let end = cursor.saturating_add(limit).min(len);
+
+    let mut pending = Vec::new(env);
+    for i in cursor..end {
+        let slot = DataKey::VerifierPendingAt(verifier.clone(), i);
+        if let Some(entry) = env.storage().persistent().get::<_, SubmissionKey>(&slot) {
+            extend_persistent_ttl(env, &slot);
+            pending.push_back(entry);
+        }
+    }
+    pending
+}
+
+/// Queue a submission for its verifier. No-op if it is already queued.
+pub fn add_verifier_pending(env: &Env, verifier: &Address, quest_id: &Symbol, submitter: &Address) {
+    let pos_key = DataKey::QuestData(quest_id.clone(), QuestKey::PendingSlot(submitter.clone()));
+    if env.storage().persistent().has(&pos_key) {
+        return;
+    }
+
+    let len = get_verifier_pending_len(env, verifier);
+    let slot = DataKey::VerifierPendingAt(verifier.clone(), len);
+    let entry = SubmissionKey {
+        quest_id: quest_id.clone(),
+        submitter: submitter.clone(),
+    };
+    env.storage().persistent().set(&slot, &entry);
+    extend_persistent_ttl(env, &slot);
+    set_verifier_pending_len(env, verifier, len + 1);
+
+    env.storage().persistent().set(&pos_key, &len);
+    extend_persistent_ttl(env, &pos_key);
+}
+
+/// Remove a submission from its verifier's queue once it has been reviewed.
+/// The last queued submission is moved into its slot.
+///
+/// # Storage Access
+/// * Reads/Writes: Persistent storage (at most 2 slots, 1 length, 2 positions)
+/// * Gas Cost: Low (independent of the queue length)
+pub fn remove_verifier_pending(
+    env: &Env,
+    verifier: &Address,
+    quest_id: &Symbol,
+    submitter: &Address,
+) {
+    let pos_key = DataKey::QuestData(quest_id.clone(), QuestKey::PendingSlot(submitter.clone()));
+    let Some(pos) = env.storage().persistent().get::<_, u32>(&pos_key) else {
+        return;
+    };
+    env.storage().persistent().remove(&pos_key);
+
+    let len = get_verifier_pending_len(env, verifier);
+    if len == 0 {
+        return;
+    }
+    let last = len - 1;
+    let last_slot = DataKey::VerifierPendingAt(verifier.clone(), last);
+    if pos < last {
+        if let Some(moved) = env
+            .storage()
+            .persistent()
+            .get::<_, SubmissionKey>(&last_slot)
+        {
+            let slot = DataKey::VerifierPendingAt(verifier.clone(), pos);
+            env.storage().persistent().set(&slot, &moved);
+            extend_persistent_ttl(env, &slot);
+
+            let moved_key =
+                DataKey::QuestData(moved.quest_id, QuestKey::PendingSlot(moved.submitter));
+            env.storage().persistent().set(&moved_key, &pos);
+            extend_persistent_ttl(env, &moved_key);
+        }
+    }
+    env.storage().persistent().remove(&last_slot);
+    set_verifier_pending_len(env, verifier, last);
+}
+
+//================================================================================
+// User Earnings
+//================================================================================
+
+/// Lifetime reward earnings of a user, keyed by asset.
+///
+/// # Storage Access
+/// * Reads from: Persistent storage
+/// * Gas Cost: Low (one entry per user, one map slot per asset earned)
+pub fn get_user_earnings(env: &Env, user: &Address) -> Map<Address, i128> {
+    let key = DataKey::UserEarnings(user.clone());
+    match env.storage().persistent().get(&key) {
+        Some(earnings) => {
+            extend_persistent_ttl(env, &key);
+            earnings
+        }
+        None => Map::new(env),
+    }
+}
+
+/// Add a paid reward to a user's lifetime earnings for `asset`.
+pub fn add_user_earnings(env: &Env, user: &Address, asset: &Address, amount: i128) {
+    let mut earnings = get_user_earnings(env, user);
+    let total = earnings.get(asset.clone()).unwrap_or(0);
+    earnings.set(asset.clone(), total + amount);
+
+    let key = DataKey::UserEarnings(user.clone());
+    env.storage().persistent().set(&key, &earnings);
+    extend_persistent_ttl(env, &key);
+}
+
+//================================================================================
+// Activity Statistics
+//================================================================================
+
+/// Platform-wide activity counters, if any activity has been recorded.
+pub fn get_platform_stats(env: &Env) -> Option<ActivityStats> {
+    let key = DataKey::PlatformStats;
+    let stats = env.storage().persistent().get(&key);
+    if stats.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    stats
+}
+
+/// Save platform-wide activity counters.
+pub fn set_platform_stats(env: &Env, stats: &ActivityStats) {
+    let key = DataKey::PlatformStats;
+    env.storage().persistent().set(&key, stats);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Activity counters for a creator, if any activity has been recorded.
+pub fn get_creator_stats(env: &Env, creator: &Address) -> Option<ActivityStats> {
+    let key = DataKey::CreatorStats(creator.clone());
+    let stats = env.storage().persistent().get(&key);
+    if stats.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    stats
+}
+
+/// Save activity counters for a creator.
+pub fn set_creator_stats(env: &Env, creator: &Address, stats: &ActivityStats) {
+    let key = DataKey::CreatorStats(creator.clone());
+    env.storage().persistent().set(&key, stats);
+    extend_persistent_ttl(env, &key);
+}
+
 //================================================================================
 // Admin Storage Functions
 //================================================================================
@@ -524,9 +1109,14 @@ pub fn is_admin(env: &Env, address: &Address) -> bool {
 /// * `env` - The contract environment
 /// * `address` - The address to set as admin
 pub fn set_admin(env: &Env, address: &Address) {
+    if is_admin(env, address) {
+        return;
+    }
     env.storage()
         .instance()
         .set(&DataKey::Admin(address.clone()), &true);
+    let count = get_admin_count(env).unwrap_or(0);
+    set_admin_count(env, count + 1);
 }
 
 /// Removes admin status from an address.
@@ -535,9 +1125,43 @@ pub fn set_admin(env: &Env, address: &Address) {
 /// * `env` - The contract environment
 /// * `address` - The address to remove admin status from
 pub fn remove_admin(env: &Env, address: &Address) {
+    if !is_admin(env, address) {
+        return;
+    }
     env.storage()
         .instance()
         .remove(&DataKey::Admin(address.clone()));
+    if let Some(count) = get_admin_count(env) {
+        set_admin_count(env, count.saturating_sub(1));
+    }
+}
+
+/// Number of admins, if tracked. Deployments that added admins before the
+/// count existed have none recorded.
+pub fn get_admin_count(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::AdminCount)
+}
+
+fn set_admin_count(env: &Env, count: u32) {
+    env.storage().instance().set(&DataKey::AdminCount, &count);
+}
+
+//================================================================================
+// Asset Registry Storage Functions
+//================================================================================
+
+/// Get the registry entry for a reward asset, if any.
+pub fn get_asset_config(env: &Env, asset: &Address) -> Option<AssetConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AssetConfig(asset.clone()))
+}
+
+/// Create or replace the registry entry for a reward asset.
+pub fn set_asset_config(env: &Env, asset: &Address, config: &AssetConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AssetConfig(asset.clone()), config);
 }
 
 //================================================================================
@@ -558,37 +1182,51 @@ pub fn is_paused(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::Paused)
 }
 
-/// Approve or revoke unpause by admin
-pub fn set_unpause_approval(env: &Env, admin: &Address, approved: bool) {
-    if approved {
-        // If not already approved, set and increment counter
-        if !has_unpause_approval(env, admin) {
-            env.storage()
-                .instance()
-                .set(&DataKey::UnpauseApproval(admin.clone()), &true);
-            inc_unpause_approval_count(env);
-        }
+/// Set the bitmap of paused operation categories
+pub fn set_pause_flags(env: &Env, flags: u32) {
+    if flags == 0 {
+        env.storage().instance().remove(&DataKey::PauseFlags);
     } else {
-        if has_unpause_approval(env, admin) {
-            env.storage()
-                .instance()
-                .remove(&DataKey::UnpauseApproval(admin.clone()));
-            dec_unpause_approval_count(env);
-        }
+        env.storage().instance().set(&DataKey::PauseFlags, &flags);
     }
 }
 
-pub fn has_unpause_approval(env: &Env, admin: &Address) -> bool {
+/// Get the bitmap of paused operation categories
+pub fn get_pause_flags(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .has(&DataKey::UnpauseApproval(admin.clone()))
+        .get(&DataKey::PauseFlags)
+        .unwrap_or(0u32)
 }
 
-pub fn count_unpause_approvals(env: &Env) -> u32 {
+fn get_unpause_approvers(env: &Env) -> Vec<Address> {
     env.storage()
         .instance()
-        .get(&DataKey::UnpauseApprovalCount)
-        .unwrap_or(0u32)
+        .get(&DataKey::UnpauseApprovers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Approve or revoke unpause by admin
+pub fn set_unpause_approval(env: &Env, admin: &Address, approved: bool) {
+    let mut approvers = get_unpause_approvers(env);
+    match (approvers.first_index_of(admin), approved) {
+        (None, true) => approvers.push_back(admin.clone()),
+        (Some(i), false) => {
+            approvers.remove(i);
+        }
+        _ => return,
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::UnpauseApprovers, &approvers);
+}
+
+pub fn has_unpause_approval(env: &Env, admin: &Address) -> bool {
+    get_unpause_approvers(env).contains(admin)
+}
+
+pub fn count_unpause_approvals(env: &Env) -> u32 {
+    get_unpause_approvers(env).len()
 }
 
 pub fn set_unpause_threshold(env: &Env, threshold: u32) {
@@ -624,61 +1262,1437 @@ pub fn set_scheduled_unpause_time(env: &Env, ts: u64) {
 }
 
 pub fn get_scheduled_unpause_time(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::ScheduledUnpauseTime)
+}
+
+/// Forget every unpause approval and the scheduled unpause, so the next
+/// pause starts a fresh approval round.
+pub fn clear_unpause_approvals(env: &Env) {
+    env.storage().instance().remove(&DataKey::UnpauseApprovers);
     env.storage()
         .instance()
-        .get(&DataKey::ScheduledUnpauseTime)
+        .remove(&DataKey::ScheduledUnpauseTime);
 }
 
-pub fn clear_unpause_approvals(env: &Env) {
-    // There's no easy iteration to remove all UnpauseApproval keys.
-    // The higher level security module will simply leave entries; remove scheduled time and paused flag.
-    env.storage().instance().remove(&DataKey::ScheduledUnpauseTime);
+//================================================================================
+// Recovery Address Storage Helpers
+//================================================================================
+
+/// Get the configured recovery address, if any
+pub fn get_recovery_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::RecoveryAddress)
 }
 
-fn inc_unpause_approval_count(env: &Env) {
-    let mut cur: u32 = env
-        .storage()
+/// Set the recovery address
+pub fn set_recovery_address(env: &Env, address: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RecoveryAddress, address);
+}
+
+/// Get the pending proposal for a target, if any
+pub fn get_pending_proposal(env: &Env, target: ProposalTarget) -> Option<Proposal> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingProposal(target))
+}
+
+/// Save the pending proposal for a target
+pub fn set_pending_proposal(env: &Env, target: ProposalTarget, proposal: &Proposal) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingProposal(target), proposal);
+}
+
+/// Remove the pending proposal for a target
+pub fn clear_pending_proposal(env: &Env, target: ProposalTarget) {
+    env.storage()
         .instance()
-        .get(&DataKey::UnpauseApprovalCount)
-        .unwrap_or(0u32);
-    cur = cur.saturating_add(1);
-    env.storage().instance().set(&DataKey::UnpauseApprovalCount, &cur);
+        .remove(&DataKey::PendingProposal(target));
 }
 
-fn dec_unpause_approval_count(env: &Env) {
-    let mut cur: u32 = env
+/// Allocate the next address proposal id (shared by all targets)
+pub fn next_proposal_id(env: &Env) -> u32 {
+    let id: u32 = env
         .storage()
         .instance()
-        .get(&DataKey::UnpauseApprovalCount)
-        .unwrap_or(0u32);
-    if cur > 0 {
-        cur -= 1;
-    }
-    env.storage().instance().set(&DataKey::UnpauseApprovalCount, &cur);
+        .get(&DataKey::ProposalCount)
+        .unwrap_or(0u32)
+        .saturating_add(1);
+    env.storage().instance().set(&DataKey::ProposalCount, &id);
+    id
 }
 
 //================================================================================
-// Escrow Storage Functions
+// Fee / Treasury Storage Helpers
 //================================================================================
 
-/// Check if escrow exists for a quest
-pub fn has_escrow(env: &Env, quest_id: &Symbol) -> bool {
+/// Get the global fee configuration (defaults to no fee, charged on payout)
+pub fn get_fee_config(env: &Env) -> FeeConfig {
     env.storage()
         .instance()
-        .has(&DataKey::Escrow(quest_id.clone()))
+        .get(&DataKey::FeeConfig)
+        .unwrap_or(FeeConfig {
+            fee_bps: 0,
+            mode: FeeMode::OnPayout,
+        })
 }
 
-/// Get escrow info for a quest
-pub fn get_escrow(env: &Env, quest_id: &Symbol) -> Result<EscrowInfo, Error> {
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+/// Get the per-asset fee override, if any
+pub fn get_asset_fee_bps(env: &Env, asset: &Address) -> Option<u32> {
     env.storage()
         .instance()
-        .get(&DataKey::Escrow(quest_id.clone()))
-        .ok_or(Error::EscrowNotFound)
+        .get(&DataKey::AssetFeeBps(asset.clone()))
 }
 
-/// Save escrow info for a quest
-pub fn set_escrow(env: &Env, quest_id: &Symbol, escrow: &EscrowInfo) {
+pub fn set_asset_fee_bps(env: &Env, asset: &Address, fee_bps: Option<u32>) {
+    match fee_bps {
+        Some(bps) => env
+            .storage()
+            .instance()
+            .set(&DataKey::AssetFeeBps(asset.clone()), &bps),
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::AssetFeeBps(asset.clone())),
+    }
+}
+
+/// Get accrued fees for an asset
+pub fn get_treasury_balance(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TreasuryBalance(asset.clone()))
+        .unwrap_or(0i128)
+}
+
+pub fn set_treasury_balance(env: &Env, asset: &Address, balance: i128) {
     env.storage()
         .instance()
-        .set(&DataKey::Escrow(quest_id.clone()), escrow);
-}
\ No newline at end of file
+        .set(&DataKey::TreasuryBalance(asset.clone()), &balance);
+}
+
+/// Default number of rewards a quest's escrow must cover before it opens
+pub const DEFAULT_MIN_FUNDED_REWARDS: u32 = 1;
+
+/// Number of rewards a quest's escrow must cover before it opens
+pub fn get_min_funded_rewards(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinFundedRewards)
+        .unwrap_or(DEFAULT_MIN_FUNDED_REWARDS)
+}
+
+pub fn set_min_funded_rewards(env: &Env, rewards: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinFundedRewards, &rewards);
+}
+
+/// Get the total available escrow held for an asset
+pub fn get_escrow_liability(env: &Env, asset: &Address) -> i128 {
+    get_solvency_entry(env, asset, SolvencyEntry::EscrowLiability)
+}
+
+/// Adjust the total available escrow held for an asset by `delta`
+pub fn adjust_escrow_liability(env: &Env, asset: &Address, delta: i128) {
+    adjust_solvency_entry(env, asset, SolvencyEntry::EscrowLiability, delta);
+}
+
+/// Get the total submission bonds held for an asset
+pub fn get_bond_liability(env: &Env, asset: &Address) -> i128 {
+    get_solvency_entry(env, asset, SolvencyEntry::BondLiability)
+}
+
+/// Adjust the total submission bonds held for an asset by `delta`
+pub fn adjust_bond_liability(env: &Env, asset: &Address, delta: i128) {
+    adjust_solvency_entry(env, asset, SolvencyEntry::BondLiability, delta);
+}
+
+/// Get the total refundable creation deposits held for an asset
+pub fn get_deposit_liability(env: &Env, asset: &Address) -> i128 {
+    get_solvency_entry(env, asset, SolvencyEntry::DepositLiability)
+}
+
+/// Adjust the total refundable creation deposits held for an asset by `delta`
+pub fn adjust_deposit_liability(env: &Env, asset: &Address, delta: i128) {
+    adjust_solvency_entry(env, asset, SolvencyEntry::DepositLiability, delta);
+}
+
+/// Get the tokens of an asset held by the recovery address after emergency withdrawals
+pub fn get_recovered(env: &Env, asset: &Address) -> i128 {
+    get_solvency_entry(env, asset, SolvencyEntry::Recovered)
+}
+
+/// Adjust the tokens of an asset held by the recovery address by `delta`
+pub fn adjust_recovered(env: &Env, asset: &Address, delta: i128) {
+    adjust_solvency_entry(env, asset, SolvencyEntry::Recovered, delta);
+}
+
+fn get_solvency_entry(env: &Env, asset: &Address, entry: SolvencyEntry) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Solvency(asset.clone(), entry))
+        .unwrap_or(0i128)
+}
+
+fn adjust_solvency_entry(env: &Env, asset: &Address, entry: SolvencyEntry, delta: i128) {
+    let total = get_solvency_entry(env, asset, entry.clone()) + delta;
+    env.storage()
+        .instance()
+        .set(&DataKey::Solvency(asset.clone(), entry), &total);
+}
+
+/// Charge taken from creators for each quest they register, if any
+pub fn get_creation_deposit(env: &Env) -> Option<CreationDeposit> {
+    env.storage().instance().get(&DataKey::CreationDeposit)
+}
+
+pub fn set_creation_deposit(env: &Env, deposit: &Option<CreationDeposit>) {
+    match deposit {
+        Some(deposit) => env
+            .storage()
+            .instance()
+            .set(&DataKey::CreationDeposit, deposit),
+        None => env.storage().instance().remove(&DataKey::CreationDeposit),
+    }
+}
+
+/// Most non-terminal quests a creator may have at once (0 = no cap)
+pub fn get_max_active_quests(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxActiveQuests)
+        .unwrap_or(0)
+}
+
+pub fn set_max_active_quests(env: &Env, max: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxActiveQuests, &max);
+}
+
+pub fn get_treasurer(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasurer)
+}
+
+pub fn set_treasurer(env: &Env, treasurer: &Address) {
+    env.storage().instance().set(&DataKey::Treasurer, treasurer);
+}
+
+//================================================================================
+// Circuit Breaker Storage Helpers
+//================================================================================
+
+pub fn get_circuit_breaker(env: &Env, asset: &Address) -> Option<CircuitBreakerConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CircuitBreaker(asset.clone()))
+}
+
+pub fn set_circuit_breaker(env: &Env, asset: &Address, config: &CircuitBreakerConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CircuitBreaker(asset.clone()), config);
+}
+
+pub fn remove_circuit_breaker(env: &Env, asset: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::CircuitBreaker(asset.clone()));
+}
+
+pub fn get_payout_window(env: &Env, asset: &Address) -> Option<PayoutWindow> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutWindow(asset.clone()))
+}
+
+pub fn set_payout_window(env: &Env, asset: &Address, window: &PayoutWindow) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PayoutWindow(asset.clone()), window);
+}
+
+pub fn clear_payout_window(env: &Env, asset: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::PayoutWindow(asset.clone()));
+}
+
+//================================================================================
+// Escrow Storage Functions
+//================================================================================
+
+/// Check if escrow exists for a quest
+pub fn has_escrow(env: &Env, quest_id: &Symbol) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Escrow(quest_id.clone()))
+}
+
+/// Get escrow info for a quest
+pub fn get_escrow(env: &Env, quest_id: &Symbol) -> Result<EscrowInfo, Error> {
+    let key = DataKey::Escrow(quest_id.clone());
+    let escrow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::EscrowNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(escrow)
+}
+
+/// Save escrow info for a quest
+pub fn set_escrow(env: &Env, quest_id: &Symbol, escrow: &EscrowInfo) {
+    let key = DataKey::Escrow(quest_id.clone());
+    env.storage().persistent().set(&key, escrow);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete the escrow record for a quest and its sponsorship records
+/// (safe to call if none exist)
+pub fn delete_escrow(env: &Env, quest_id: &Symbol) {
+    let persistent = env.storage().persistent();
+    persistent.remove(&DataKey::Escrow(quest_id.clone()));
+    persistent.remove(&DataKey::EscrowContributions(quest_id.clone()));
+    persistent.remove(&DataKey::SponsorPolicy(quest_id.clone()));
+    persistent.remove(&DataKey::SponsorAllowlist(quest_id.clone()));
+}
+
+//================================================================================
+// Campaign Storage Functions
+//================================================================================
+
+/// Check if a campaign exists
+pub fn has_campaign(env: &Env, campaign_id: &Symbol) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Campaign(campaign_id.clone()))
+}
+
+/// Get a campaign by id
+pub fn get_campaign(env: &Env, campaign_id: &Symbol) -> Result<Campaign, Error> {
+    let key = DataKey::Campaign(campaign_id.clone());
+    let campaign = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::CampaignNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(campaign)
+}
+
+/// Save a campaign
+pub fn set_campaign(env: &Env, campaign_id: &Symbol, campaign: &Campaign) {
+    let key = DataKey::Campaign(campaign_id.clone());
+    env.storage().persistent().set(&key, campaign);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Get the escrow pool of a campaign
+pub fn get_campaign_escrow(env: &Env, campaign_id: &Symbol) -> Result<EscrowInfo, Error> {
+    let key = DataKey::CampaignEscrow(campaign_id.clone());
+    let escrow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::CampaignNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(escrow)
+}
+
+/// Save the escrow pool of a campaign
+pub fn set_campaign_escrow(env: &Env, campaign_id: &Symbol, escrow: &EscrowInfo) {
+    let key = DataKey::CampaignEscrow(campaign_id.clone());
+    env.storage().persistent().set(&key, escrow);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Campaign a quest draws its rewards from, if any
+pub fn get_quest_campaign(env: &Env, quest_id: &Symbol) -> Option<Symbol> {
+    let key = DataKey::QuestCampaign(quest_id.clone());
+    let campaign_id = env.storage().persistent().get(&key);
+    if campaign_id.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    campaign_id
+}
+
+/// Record that a quest draws its rewards from a campaign
+pub fn set_quest_campaign(env: &Env, quest_id: &Symbol, campaign_id: &Symbol) {
+    let key = DataKey::QuestCampaign(quest_id.clone());
+    env.storage().persistent().set(&key, campaign_id);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Remove a quest's campaign link (safe to call if none exists)
+pub fn delete_quest_campaign(env: &Env, quest_id: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QuestCampaign(quest_id.clone()));
+}
+
+//================================================================================
+// Merkle Claim Storage Functions
+//================================================================================
+
+/// Number of Merkle roots ever posted for a quest
+pub fn get_merkle_round_count(env: &Env, quest_id: &Symbol) -> u32 {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::MerkleRoundCount);
+    match env.storage().persistent().get(&key) {
+        Some(count) => {
+            extend_persistent_ttl(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Sum of the quest's posted Merkle round totals not yet claimed
+pub fn get_merkle_outstanding(env: &Env, quest_id: &Symbol) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QuestData(
+            quest_id.clone(),
+            QuestKey::MerkleOutstanding,
+        ))
+        .unwrap_or(0)
+}
+
+/// Add `delta` to the quest's outstanding Merkle total
+pub fn adjust_merkle_outstanding(env: &Env, quest_id: &Symbol, delta: i128) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::MerkleOutstanding);
+    let outstanding = get_merkle_outstanding(env, quest_id) + delta;
+    if outstanding > 0 {
+        env.storage().persistent().set(&key, &outstanding);
+        extend_persistent_ttl(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Store a new Merkle round for a quest and return its round id
+pub fn add_merkle_round(env: &Env, quest_id: &Symbol, round: &MerkleRound) -> u32 {
+    let id = get_merkle_round_count(env, quest_id);
+    set_merkle_round(env, quest_id, id, round);
+
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::MerkleRoundCount);
+    env.storage().persistent().set(&key, &(id + 1));
+    extend_persistent_ttl(env, &key);
+    id
+}
+
+/// Get a Merkle round by id
+pub fn get_merkle_round(env: &Env, quest_id: &Symbol, round_id: u32) -> Result<MerkleRound, Error> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::MerkleRound(round_id));
+    let round = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::MerkleRoundNotFound)?;
+    extend_persistent_ttl(env, &key);
+    Ok(round)
+}
+
+/// Save a Merkle round
+pub fn set_merkle_round(env: &Env, quest_id: &Symbol, round_id: u32, round: &MerkleRound) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::MerkleRound(round_id));
+    env.storage().persistent().set(&key, round);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete a quest's Merkle round records and round count. Claimed bitmaps
+/// are left to expire; the quest id is archived, so they are never read again.
+pub fn delete_merkle_rounds(env: &Env, quest_id: &Symbol) {
+    for round_id in 0..get_merkle_round_count(env, quest_id) {
+        env.storage().persistent().remove(&DataKey::QuestData(
+            quest_id.clone(),
+            QuestKey::MerkleRound(round_id),
+        ));
+    }
+    env.storage().persistent().remove(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::MerkleRoundCount,
+    ));
+    env.storage().persistent().remove(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::MerkleOutstanding,
+    ));
+}
+
+/// Whether a leaf of a Merkle round has been claimed
+pub fn is_merkle_claimed(env: &Env, quest_id: &Symbol, round_id: u32, index: u32) -> bool {
+    let word: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::QuestData(
+            quest_id.clone(),
+            QuestKey::MerkleClaimed(round_id, index / 128),
+        ))
+        .unwrap_or(0);
+    word & (1u128 << (index % 128)) != 0
+}
+
+/// Mark a leaf of a Merkle round as claimed
+pub fn set_merkle_claimed(env: &Env, quest_id: &Symbol, round_id: u32, index: u32) {
+    let key = DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::MerkleClaimed(round_id, index / 128),
+    );
+    let word: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &(word | (1u128 << (index % 128))));
+    extend_persistent_ttl(env, &key);
+}
+
+//================================================================================
+// Attestation Storage Functions
+//================================================================================
+
+/// ed25519 public key of a quest's attestor, if one is registered
+pub fn get_quest_attestor(env: &Env, quest_id: &Symbol) -> Option<BytesN<32>> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Attestor);
+    let attestor = env.storage().persistent().get(&key);
+    if attestor.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    attestor
+}
+
+/// Register or clear a quest's attestor key
+pub fn set_quest_attestor(env: &Env, quest_id: &Symbol, attestor: &Option<BytesN<32>>) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Attestor);
+    match attestor {
+        Some(attestor) => {
+            env.storage().persistent().set(&key, attestor);
+            extend_persistent_ttl(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Whether an attestation nonce has been used for a quest
+pub fn is_attestation_nonce_used(env: &Env, quest_id: &Symbol, nonce: u64) -> bool {
+    env.storage().persistent().has(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::AttestationNonce(nonce),
+    ))
+}
+
+/// Mark an attestation nonce as used. Nonces are not deleted when the quest
+/// is pruned, so a re-registered quest cannot replay old attestations.
+pub fn use_attestation_nonce(env: &Env, quest_id: &Symbol, nonce: u64) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::AttestationNonce(nonce));
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+//================================================================================
+// Answer Quest Storage Functions
+//================================================================================
+
+/// Expected answer of an answer-hash quest, if the quest is one
+pub fn get_quest_answer(env: &Env, quest_id: &Symbol) -> Option<AnswerConfig> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Answer);
+    let answer = env.storage().persistent().get(&key);
+    if answer.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    answer
+}
+
+/// Save the expected answer of an answer-hash quest
+pub fn set_quest_answer(env: &Env, quest_id: &Symbol, answer: &AnswerConfig) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Answer);
+    env.storage().persistent().set(&key, answer);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete the expected answer of a quest
+pub fn delete_quest_answer(env: &Env, quest_id: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QuestData(quest_id.clone(), QuestKey::Answer));
+}
+
+//================================================================================
+// Lottery Storage Functions
+//================================================================================
+
+/// Number of winners a lottery quest draws, if the quest is a lottery
+pub fn get_lottery_winners(env: &Env, quest_id: &Symbol) -> Option<u32> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Lottery);
+    let winners = env.storage().persistent().get(&key);
+    if winners.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    winners
+}
+
+/// Make a quest a lottery drawing `winners` winners
+pub fn set_lottery_winners(env: &Env, quest_id: &Symbol, winners: u32) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Lottery);
+    env.storage().persistent().set(&key, &winners);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Outcome of a lottery quest's draw, once drawn
+pub fn get_lottery_draw(env: &Env, quest_id: &Symbol) -> Option<LotteryDraw> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryDraw);
+    let draw = env.storage().persistent().get(&key);
+    if draw.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    draw
+}
+
+/// Save the outcome of a lottery quest's draw and mark its winners
+pub fn set_lottery_draw(env: &Env, quest_id: &Symbol, draw: &LotteryDraw) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryDraw);
+    env.storage().persistent().set(&key, draw);
+    extend_persistent_ttl(env, &key);
+
+    for winner in draw.winners.iter() {
+        let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryWinner(winner));
+        env.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Whether a submitter was drawn as a winner of a lottery quest
+pub fn is_lottery_winner(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool {
+    env.storage().persistent().has(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::LotteryWinner(submitter.clone()),
+    ))
+}
+
+/// Number of approved entrants of a lottery quest.
+pub fn get_lottery_entrant_len(env: &Env, quest_id: &Symbol) -> u32 {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrants);
+    match env.storage().persistent().get(&key) {
+        Some(len) => {
+            extend_persistent_ttl(env, &key);
+            len
+        }
+        None => 0,
+    }
+}
+
+fn set_lottery_entrant_len(env: &Env, quest_id: &Symbol, len: u32) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrants);
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &len);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Approved lottery entrant at position `pos`, if any.
+pub fn get_lottery_entrant(env: &Env, quest_id: &Symbol, pos: u32) -> Option<Address> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrantAt(pos));
+    let entrant = env.storage().persistent().get(&key);
+    if entrant.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    entrant
+}
+
+/// Add an approved submitter to a lottery quest's entrants. No-op if already entered.
+pub fn add_lottery_entrant(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let pos_key = DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::LotteryEntrantSlot(submitter.clone()),
+    );
+    if env.storage().persistent().has(&pos_key) {
+        return;
+    }
+
+    let len = get_lottery_entrant_len(env, quest_id);
+    let slot = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrantAt(len));
+    env.storage().persistent().set(&slot, submitter);
+    extend_persistent_ttl(env, &slot);
+    set_lottery_entrant_len(env, quest_id, len + 1);
+
+    env.storage().persistent().set(&pos_key, &len);
+    extend_persistent_ttl(env, &pos_key);
+}
+
+/// Remove a submitter from a lottery quest's entrants. The last entrant is
+/// moved into its slot.
+///
+/// # Storage Access
+/// * Reads/Writes: Persistent storage (at most 2 slots, 1 length, 2 positions)
+/// * Gas Cost: Low (independent of the number of entrants)
+pub fn remove_lottery_entrant(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let pos_key = DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::LotteryEntrantSlot(submitter.clone()),
+    );
+    let Some(pos) = env.storage().persistent().get::<_, u32>(&pos_key) else {
+        return;
+    };
+    env.storage().persistent().remove(&pos_key);
+
+    let len = get_lottery_entrant_len(env, quest_id);
+    if len == 0 {
+        return;
+    }
+    let last = len - 1;
+    let last_slot = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrantAt(last));
+    if pos < last {
+        if let Some(moved) = env.storage().persistent().get::<_, Address>(&last_slot) {
+            let slot = DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrantAt(pos));
+            env.storage().persistent().set(&slot, &moved);
+            extend_persistent_ttl(env, &slot);
+
+            let moved_key =
+                DataKey::QuestData(quest_id.clone(), QuestKey::LotteryEntrantSlot(moved));
+            env.storage().persistent().set(&moved_key, &pos);
+            extend_persistent_ttl(env, &moved_key);
+        }
+    }
+    env.storage().persistent().remove(&last_slot);
+    set_lottery_entrant_len(env, quest_id, last);
+}
+
+/// Whether an entrant has claimed participation XP for a lottery quest
+pub fn is_participation_claimed(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool {
+    env.storage().persistent().has(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::ParticipationClaimed(submitter.clone()),
+    ))
+}
+
+/// Mark an entrant's participation XP for a lottery quest as claimed
+pub fn set_participation_claimed(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let key = DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::ParticipationClaimed(submitter.clone()),
+    );
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete a submitter's lottery entry and participation claim mark
+pub fn delete_lottery_entry(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    remove_lottery_entrant(env, quest_id, submitter);
+    env.storage().persistent().remove(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::ParticipationClaimed(submitter.clone()),
+    ));
+}
+
+/// Delete a quest's lottery settings, draw and winner marks
+pub fn delete_lottery(env: &Env, quest_id: &Symbol) {
+    let persistent = env.storage().persistent();
+    if let Some(draw) = persistent
+        .get::<_, LotteryDraw>(&DataKey::QuestData(quest_id.clone(), QuestKey::LotteryDraw))
+    {
+        for winner in draw.winners.iter() {
+            persistent.remove(&DataKey::QuestData(
+                quest_id.clone(),
+                QuestKey::LotteryWinner(winner),
+            ));
+        }
+    }
+    persistent.remove(&DataKey::QuestData(quest_id.clone(), QuestKey::LotteryDraw));
+    persistent.remove(&DataKey::QuestData(quest_id.clone(), QuestKey::Lottery));
+}
+
+//================================================================================
+// Proof Uniqueness Storage Functions
+//================================================================================
+
+/// Proof-hash uniqueness rules for a quest (defaults to unchecked)
+pub fn get_proof_policy(env: &Env, quest_id: &Symbol) -> ProofPolicy {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::ProofPolicy);
+    match env.storage().persistent().get(&key) {
+        Some(policy) => {
+            extend_persistent_ttl(env, &key);
+            policy
+        }
+        None => ProofPolicy {
+            scope: ProofScope::Off,
+            on_duplicate: DuplicateAction::Reject,
+        },
+    }
+}
+
+/// Set the proof-hash uniqueness rules for a quest
+pub fn set_proof_policy(env: &Env, quest_id: &Symbol, policy: &ProofPolicy) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::ProofPolicy);
+    env.storage().persistent().set(&key, policy);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete the proof-hash uniqueness rules of a quest. Recorded proof uses are
+/// kept so the global registry still detects reuse after the quest is pruned.
+pub fn delete_proof_policy(env: &Env, quest_id: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QuestData(quest_id.clone(), QuestKey::ProofPolicy));
+}
+
+fn proof_use_key(quest_id: &Option<Symbol>, proof_hash: &BytesN<32>) -> DataKey {
+    match quest_id {
+        Some(quest_id) => {
+            DataKey::QuestData(quest_id.clone(), QuestKey::ProofUse(proof_hash.clone()))
+        }
+        None => DataKey::ProofUse(proof_hash.clone()),
+    }
+}
+
+/// First use of a proof hash within `quest_id`, or in the global registry if `None`
+pub fn get_proof_use(
+    env: &Env,
+    quest_id: &Option<Symbol>,
+    proof_hash: &BytesN<32>,
+) -> Option<ProofUse> {
+    env.storage()
+        .persistent()
+        .get(&proof_use_key(quest_id, proof_hash))
+}
+
+/// Record the first use of a proof hash within `quest_id`, or globally if `None`
+pub fn set_proof_use(
+    env: &Env,
+    quest_id: &Option<Symbol>,
+    proof_hash: &BytesN<32>,
+    first: &ProofUse,
+) {
+    let key = proof_use_key(quest_id, proof_hash);
+    env.storage().persistent().set(&key, first);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Delete a quest's own record of a proof hash. The global registry entry
+/// is kept so the hash stays blocked for other quests.
+pub fn delete_proof_use(env: &Env, quest_id: &Symbol, proof_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&proof_use_key(&Some(quest_id.clone()), proof_hash));
+}
+
+/// Earlier use of the proof hash a submission duplicates, if it was flagged
+pub fn get_duplicate_flag(env: &Env, quest_id: &Symbol, submitter: &Address) -> Option<ProofUse> {
+    env.storage().persistent().get(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::DuplicateFlag(submitter.clone()),
+    ))
+}
+
+/// Flag a submission as duplicating an earlier use of its proof hash
+pub fn set_duplicate_flag(env: &Env, quest_id: &Symbol, submitter: &Address, first: &ProofUse) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::DuplicateFlag(submitter.clone()));
+    env.storage().persistent().set(&key, first);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Clear a submission's duplicate flag
+pub fn delete_duplicate_flag(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    env.storage().persistent().remove(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::DuplicateFlag(submitter.clone()),
+    ));
+}
+
+//================================================================================
+// Prerequisite Storage Functions
+//================================================================================
+
+/// Requirements for submitting proof to a quest, if it declares any
+pub fn get_prerequisites(env: &Env, quest_id: &Symbol) -> Option<Prerequisites> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Prerequisites);
+    let prerequisites = env.storage().persistent().get(&key);
+    if prerequisites.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    prerequisites
+}
+
+/// Set or clear the requirements for submitting proof to a quest
+pub fn set_prerequisites(env: &Env, quest_id: &Symbol, prerequisites: &Option<Prerequisites>) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Prerequisites);
+    match prerequisites {
+        Some(prerequisites) => {
+            env.storage().persistent().set(&key, prerequisites);
+            extend_persistent_ttl(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Whether a user has been paid for a quest, even if it has since been pruned
+pub fn has_completed(env: &Env, quest_id: &Symbol, user: &Address) -> bool {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Completed(user.clone()));
+    let completed = env.storage().persistent().has(&key);
+    if completed {
+        extend_persistent_ttl(env, &key);
+    }
+    completed
+}
+
+/// Record that a user has been paid for a quest
+pub fn set_completed(env: &Env, quest_id: &Symbol, user: &Address) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Completed(user.clone()));
+    env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(env, &key);
+}
+
+//================================================================================
+// Access Storage Functions
+//================================================================================
+
+/// Who may submit proof to a quest (defaults to open)
+pub fn get_access_mode(env: &Env, quest_id: &Symbol) -> AccessMode {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::AccessMode);
+    match env.storage().persistent().get(&key) {
+        Some(mode) => {
+            extend_persistent_ttl(env, &key);
+            mode
+        }
+        None => AccessMode::Open,
+    }
+}
+
+/// Set who may submit proof to a quest
+pub fn set_access_mode(env: &Env, quest_id: &Symbol, mode: AccessMode) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::AccessMode);
+    if mode == AccessMode::Open {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &mode);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Whether a user has been admitted to a quest
+pub fn is_participant(env: &Env, quest_id: &Symbol, user: &Address) -> bool {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Participant(user.clone()));
+    let admitted = env.storage().persistent().has(&key);
+    if admitted {
+        extend_persistent_ttl(env, &key);
+    }
+    admitted
+}
+
+/// Admit or remove a participant
+pub fn set_participant(env: &Env, quest_id: &Symbol, user: &Address, admitted: bool) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Participant(user.clone()));
+    if admitted {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Whether an invite code hash is stored and not yet redeemed
+pub fn has_invite_code(env: &Env, quest_id: &Symbol, code_hash: &BytesN<32>) -> bool {
+    env.storage().persistent().has(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::InviteCode(code_hash.clone()),
+    ))
+}
+
+/// Store or consume an invite code hash
+pub fn set_invite_code(env: &Env, quest_id: &Symbol, code_hash: &BytesN<32>, available: bool) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::InviteCode(code_hash.clone()));
+    if available {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+//================================================================================
+// Submission Bond Storage Functions
+//================================================================================
+
+/// Bond a quest requires with each submission, if any
+pub fn get_bond_config(env: &Env, quest_id: &Symbol) -> Option<BondConfig> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::BondConfig);
+    let config = env.storage().persistent().get(&key);
+    if config.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    config
+}
+
+/// Set or clear the bond a quest requires with each submission
+pub fn set_bond_config(env: &Env, quest_id: &Symbol, config: &Option<BondConfig>) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::BondConfig);
+    match config {
+        Some(config) => {
+            env.storage().persistent().set(&key, config);
+            extend_persistent_ttl(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Bond currently held for a submitter, if any
+pub fn get_bond(env: &Env, quest_id: &Symbol, submitter: &Address) -> Option<Bond> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Bond(submitter.clone()));
+    let bond = env.storage().persistent().get(&key);
+    if bond.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    bond
+}
+
+/// Record a bond held for a submitter
+pub fn set_bond(env: &Env, quest_id: &Symbol, submitter: &Address, bond: &Bond) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::Bond(submitter.clone()));
+    env.storage().persistent().set(&key, bond);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Forget a submitter's bond once it is refunded or slashed
+pub fn delete_bond(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    env.storage().persistent().remove(&DataKey::QuestData(
+        quest_id.clone(),
+        QuestKey::Bond(submitter.clone()),
+    ));
+}
+
+/// Refundable creation deposit held for a quest's creator, if any
+pub fn get_quest_creation_deposit(env: &Env, quest_id: &Symbol) -> Option<CreationDeposit> {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::CreationDeposit);
+    let deposit = env.storage().persistent().get(&key);
+    if deposit.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    deposit
+}
+
+/// Record or forget the refundable creation deposit held for a quest
+pub fn set_quest_creation_deposit(env: &Env, quest_id: &Symbol, deposit: &Option<CreationDeposit>) {
+    let key = DataKey::QuestData(quest_id.clone(), QuestKey::CreationDeposit);
+    match deposit {
+        Some(deposit) => {
+            env.storage().persistent().set(&key, deposit);
+            extend_persistent_ttl(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+//================================================================================
+// Fraud Denylist Storage Functions
+//================================================================================
+
+/// Fraud flag of an address, if it is denylisted
+pub fn get_fraud_flag(env: &Env, user: &Address) -> Option<FraudFlag> {
+    let key = DataKey::FraudFlag(user.clone());
+    let flag = env.storage().persistent().get(&key);
+    if flag.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    flag
+}
+
+/// Whether an address is denylisted
+pub fn is_denylisted(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FraudFlag(user.clone()))
+}
+
+/// Denylist an address
+pub fn set_fraud_flag(env: &Env, user: &Address, flag: &FraudFlag) {
+    let key = DataKey::FraudFlag(user.clone());
+    env.storage().persistent().set(&key, flag);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Remove an address from the denylist
+pub fn delete_fraud_flag(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::FraudFlag(user.clone()));
+}
+
+/// XP removed from an address when it is flagged (defaults to 0)
+pub fn get_fraud_xp_penalty(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FraudXpPenalty)
+        .unwrap_or(0)
+}
+
+/// Set the XP removed from an address when it is flagged
+pub fn set_fraud_xp_penalty(env: &Env, xp: u64) {
+    env.storage().instance().set(&DataKey::FraudXpPenalty, &xp);
+}
+
+//================================================================================
+// Escrow Sponsorship
+//================================================================================
+
+/// Net contribution of each depositor to a quest's escrow, ordered by
+/// their most recent deposit (oldest first).
+pub fn get_escrow_contributions(env: &Env, quest_id: &Symbol) -> Vec<EscrowContribution> {
+    let key = DataKey::EscrowContributions(quest_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(contributions) => {
+            extend_persistent_ttl(env, &key);
+            contributions
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Record a net deposit by `sponsor`. The sponsor moves to the end of the
+/// ledger so that last-in-first-out refunds repay the latest depositor first.
+///
+/// # Returns
+/// * `Err(Error::ArrayTooLong)` - If a new sponsor would exceed `MAX_SPONSORS`
+pub fn add_escrow_contribution(
+    env: &Env,
+    quest_id: &Symbol,
+    sponsor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let mut contributions = get_escrow_contributions(env, quest_id);
+    let mut total = amount;
+    let mut position = None;
+    for (i, entry) in contributions.iter().enumerate() {
+        if entry.sponsor == *sponsor {
+            total += entry.amount;
+            position = Some(i as u32);
+        }
+    }
+    match position {
+        Some(i) => {
+            contributions.remove(i);
+        }
+        None if contributions.len() >= crate::validation::MAX_SPONSORS => {
+            return Err(Error::ArrayTooLong);
+        }
+        None => {}
+    }
+    contributions.push_back(EscrowContribution {
+        sponsor: sponsor.clone(),
+        amount: total,
+    });
+
+    let key = DataKey::EscrowContributions(quest_id.clone());
+    env.storage().persistent().set(&key, &contributions);
+    extend_persistent_ttl(env, &key);
+    Ok(())
+}
+
+/// Sponsorship rules for a quest. Defaults to open sponsorship with pro-rata refunds.
+pub fn get_sponsor_policy(env: &Env, quest_id: &Symbol) -> SponsorPolicy {
+    let key = DataKey::SponsorPolicy(quest_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(policy) => {
+            extend_persistent_ttl(env, &key);
+            policy
+        }
+        None => SponsorPolicy {
+            allowlist_only: false,
+            refund_policy: RefundPolicy::ProRata,
+        },
+    }
+}
+
+/// Set the sponsorship rules for a quest
+pub fn set_sponsor_policy(env: &Env, quest_id: &Symbol, policy: &SponsorPolicy) {
+    let key = DataKey::SponsorPolicy(quest_id.clone());
+    env.storage().persistent().set(&key, policy);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Sponsors allowed to fund a quest while its allowlist is enabled
+pub fn get_sponsor_allowlist(env: &Env, quest_id: &Symbol) -> Vec<Address> {
+    let key = DataKey::SponsorAllowlist(quest_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(allowlist) => {
+            extend_persistent_ttl(env, &key);
+            allowlist
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Add or remove a sponsor from a quest's allowlist
+///
+/// # Returns
+/// * `Err(Error::ArrayTooLong)` - If adding would exceed `MAX_SPONSORS`
+pub fn set_sponsor_allowed(
+    env: &Env,
+    quest_id: &Symbol,
+    sponsor: &Address,
+    allowed: bool,
+) -> Result<(), Error> {
+    let mut allowlist = get_sponsor_allowlist(env, quest_id);
+    match (allowlist.first_index_of(sponsor), allowed) {
+        (None, true) => {
+            if allowlist.len() >= crate::validation::MAX_SPONSORS {
+                return Err(Error::ArrayTooLong);
+            }
+            allowlist.push_back(sponsor.clone());
+        }
+        (Some(i), false) => {
+            allowlist.remove(i);
+        }
+        _ => return Ok(()),
+    }
+
+    let key = DataKey::SponsorAllowlist(quest_id.clone());
+    if allowlist.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &allowlist);
+        extend_persistent_ttl(env, &key);
+    }
+    Ok(())
+}
+
+//================================================================================
+// Quest Retention
+//================================================================================
+
+/// Default retention period for terminal quests before pruning (30 days)
+pub const DEFAULT_RETENTION_SECONDS: u64 = 2_592_000;
+
+/// Ledger timestamp at which a quest entered a terminal status, if recorded.
+pub fn get_quest_closed_at(env: &Env, id: &Symbol) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QuestClosedAt(id.clone()))
+}
+
+/// Record the ledger timestamp at which a quest entered a terminal status.
+pub fn set_quest_closed_at(env: &Env, id: &Symbol, timestamp: u64) {
+    let key = DataKey::QuestClosedAt(id.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Record that a quest was pruned, so its id cannot be registered again.
+///
+/// Records keyed by values that cannot be enumerated (invite codes,
+/// attestation nonces, Merkle claimed bitmaps, participants admitted without
+/// submitting) outlive the prune until their TTL expires; blocking the id
+/// keeps them from applying to a new quest.
+pub fn set_quest_archived(env: &Env, id: &Symbol) {
+    let key = DataKey::QuestData(id.clone(), QuestKey::Archived);
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    extend_persistent_ttl(env, &key);
+}
+
+/// Whether a quest with this id was registered and later pruned.
+pub fn is_quest_archived(env: &Env, id: &Symbol) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::QuestData(id.clone(), QuestKey::Archived))
+}
+
+/// Delete a quest's closed-at timestamp.
+pub fn delete_quest_closed_at(env: &Env, id: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QuestClosedAt(id.clone()));
+}
+
+/// Seconds a terminal quest is retained before it can be pruned.
+pub fn get_retention_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RetentionPeriod)
+        .unwrap_or(DEFAULT_RETENTION_SECONDS)
+}
+
+/// Set the retention period for terminal quests.
+pub fn set_retention_period(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RetentionPeriod, &seconds);
+}
+
+//================================================================================
+// TTL Management
+//================================================================================
+
+/// Default threshold (in ledgers) below which persistent entries are extended (~7 days)
+pub const DEFAULT_TTL_THRESHOLD: u32 = 120_960;
+
+/// Default TTL (in ledgers) persistent entries are extended to (~30 days)
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 518_400;
+
+/// Get the TTL extension settings for persistent entries.
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Save the TTL extension settings for persistent entries.
+pub fn set_ttl_config(env: &Env, config: &TtlConfig) {
+    env.storage().instance().set(&DataKey::TtlConfig, config);
+}
+
+/// Extend a persistent entry's TTL if it has dropped below the configured threshold.
+/// The contract instance is extended alongside it, since entity records are
+/// unreachable once the instance itself is archived.
+///
+/// # Storage Access
+/// * Reads from: Instance storage (TTL config)
+/// * Gas Cost: Low (no-op while above threshold)
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    let config = get_ttl_config(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extend the contract instance (and code) TTL using the configured settings.
+pub fn extend_instance_ttl(env: &Env) {
+    let config = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Force a quest, its escrow record and sponsorship records to the full configured TTL.
+///
+/// # Returns
+/// * `Ok(())` - If the quest exists
+/// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
+pub fn bump_quest_ttl(env: &Env, id: &Symbol) -> Result<(), Error> {
+    if !has_quest(env, id) {
+        return Err(Error::QuestNotFound);
+    }
+
+    let extend_to = get_ttl_config(env).extend_to;
+    let persistent = env.storage().persistent();
+    persistent.extend_ttl(&DataKey::Quest(id.clone()), extend_to, extend_to);
+    for key in [
+        DataKey::Escrow(id.clone()),
+        DataKey::EscrowContributions(id.clone()),
+        DataKey::SponsorPolicy(id.clone()),
+        DataKey::SponsorAllowlist(id.clone()),
+        DataKey::QuestCampaign(id.clone()),
+        DataKey::QuestData(id.clone(), QuestKey::Attestor),
+        DataKey::QuestData(id.clone(), QuestKey::Answer),
+        DataKey::QuestData(id.clone(), QuestKey::ProofPolicy),
+        DataKey::QuestData(id.clone(), QuestKey::Prerequisites),
+        DataKey::QuestData(id.clone(), QuestKey::AccessMode),
+        DataKey::QuestData(id.clone(), QuestKey::BondConfig),
+        DataKey::QuestData(id.clone(), QuestKey::CreationDeposit),
+        DataKey::QuestData(id.clone(), QuestKey::Lottery),
+        DataKey::QuestData(id.clone(), QuestKey::LotteryDraw),
+        DataKey::QuestData(id.clone(), QuestKey::LotteryEntrants),
+    ] {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, extend_to, extend_to);
+        }
+    }
+    Ok(())
+}
+
+//================================================================================
+// Instance -> Persistent Migration
+//================================================================================
+
+/// Move one entry written by an older contract version from instance storage
+/// into persistent storage, decoding it with its legacy layout `L` and
+/// converting it with `upgrade`. Returns the migrated value, if any.
+fn migrate_instance_entry<L, V>(env: &Env, key: &DataKey, upgrade: impl FnOnce(L) -> V) -> Option<V>
+where
+    L: TryFromVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    let legacy: L = env.storage().instance().get(key)?;
+    let value = upgrade(legacy);
+    env.storage().persistent().set(key, &value);
+    env.storage().instance().remove(key);
+    extend_persistent_ttl(env, key);
+    Some(value)
+}
+
+/// Migrate a quest and its escrow record. Returns the number of entries moved.
+/// Legacy quests predate the quest indices, so they are indexed as they move.
+/// The `Quest` layout is unchanged since instance-storage versions; the
+/// escrow record is decoded as `LegacyEscrowInfo`.
+pub fn migrate_quest(env: &Env, id: &Symbol) -> u32 {
+    let quest = migrate_instance_entry(env, &DataKey::Quest(id.clone()), |q: Quest| q);
+    if let Some(quest) = &quest {
+        index_quest(env, quest);
+    }
+    let escrow = migrate_instance_entry(
+        env,
+        &DataKey::Escrow(id.clone()),
+        |legacy: LegacyEscrowInfo| EscrowInfo {
+            quest_id: legacy.quest_id,
+            depositor: legacy.depositor,
+            token: legacy.token,
+            total_deposited: legacy.total_deposited,
+            total_paid_out: legacy.total_paid_out,
+            total_refunded: legacy.total_refunded,
+            total_fees: 0,
+            fee_mode: FeeMode::OnPayout,
+            is_active: legacy.is_active,
+        },
+    );
+    if let Some(info) = &escrow {
+        // Legacy escrow predates the liability ledger
+        adjust_escrow_liability(env, &info.token, crate::escrow::available(info));
+    }
+    quest.is_some() as u32 + escrow.is_some() as u32
+}
+
+/// Migrate a submission. Returns the number of entries moved.
+/// Legacy submissions predate the submission indices, so they are indexed as they move.
+pub fn migrate_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> u32 {
+    let moved = migrate_instance_entry(
+        env,
+        &DataKey::Submission(quest_id.clone(), submitter.clone()),
+        |s: Submission| s,
+    )
+    .is_some();
+    if moved {
+        index_submission(env, quest_id, submitter);
+        add_to_quest_index(env, QuestIndex::Submitter(submitter.clone()), quest_id);
+        if let (Ok(quest), Ok(submission)) = (
+            get_quest(env, quest_id),
+            get_submission(env, quest_id, submitter),
+        ) {
+            match submission.status {
+                SubmissionStatus::Pending => {
+                    add_verifier_pending(env, &quest.verifier, quest_id, submitter)
+                }
+                SubmissionStatus::Paid => set_completed(env, quest_id, submitter),
+                _ => {}
+            }
+        }
+    }
+    moved as u32
+}
+
+/// Migrate a user's stats. Returns the number of entries moved.
+pub fn migrate_user_stats(env: &Env, user: &Address) -> u32 {
+    migrate_instance_entry(env, &DataKey::UserStats(user.clone()), |s: UserStats| s).is_some()
+        as u32
+}
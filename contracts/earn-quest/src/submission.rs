@@ -1,10 +1,10 @@
 use crate::errors::Error;
 use crate::events;
-use crate::storage;
-use crate::types::{BatchApprovalInput, Submission, SubmissionStatus};
+use crate::stats;
+use crate::storage::{self, QuestIndex};
+use crate::types::{BatchApprovalInput, Quest, Submission, SubmissionStatus};
 use crate::validation;
 use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
-use crate::storage;  // already imported
 
 /// Submit proof for a quest with full input validation.
 ///
@@ -12,12 +12,16 @@ use crate::storage;  // already imported
 /// - Quest exists
 /// - Quest is currently Active
 /// - Quest has not expired (deadline not passed)
+/// - Any earlier submission is still Pending or was Rejected; approved or
+///   paid submissions are final
 pub fn submit_proof(
     env: &Env,
     quest_id: &Symbol,
     submitter: &Address,
     proof_hash: &BytesN<32>,
 ) -> Result<(), Error> {
+    crate::fraud::check(env, submitter)?;
+
     // Verify quest exists and get its data
     let quest = storage::get_quest(env, quest_id)?;
 
@@ -27,6 +31,25 @@ pub fn submit_proof(
     // Validate quest has not expired
     validation::validate_quest_not_expired(env, quest.deadline)?;
 
+    let previous = storage::get_submission(env, quest_id, submitter).ok();
+    match previous.as_ref().map(|p| &p.status) {
+        Some(SubmissionStatus::Paid) => return Err(Error::AlreadyClaimed),
+        Some(SubmissionStatus::Approved) => return Err(Error::InvalidStatusTransition),
+        _ => {}
+    }
+
+    // Answer-hash quests only take commitments before their reveal time
+    crate::answer::validate_commit_window(env, quest_id)?;
+
+    crate::access::check(env, quest_id, submitter)?;
+    crate::prerequisites::check(env, quest_id, submitter)?;
+
+    // Reject or flag proof hashes already used by someone else
+    crate::uniqueness::register(env, quest_id, submitter, proof_hash)?;
+
+    // Hold the quest's anti-spam bond until the submission is reviewed
+    crate::bond::post(env, quest_id, submitter)?;
+
     let submission = Submission {
         quest_id: quest_id.clone(),
         submitter: submitter.clone(),
@@ -37,12 +60,78 @@ pub fn submit_proof(
 
     storage::set_submission(env, quest_id, submitter, &submission);
 
+    // Index first-time submissions and queue anything newly pending for review
+    match previous {
+        None => {
+            index_new_submission(env, &quest, submitter);
+            storage::add_verifier_pending(env, &quest.verifier, quest_id, submitter);
+        }
+        Some(previous) if previous.status != SubmissionStatus::Pending => {
+            storage::add_verifier_pending(env, &quest.verifier, quest_id, submitter);
+        }
+        Some(_) => {}
+    }
+
     // EMIT EVENT: ProofSubmitted
     events::proof_submitted(env, quest_id.clone(), submitter.clone(), proof_hash.clone());
 
     Ok(())
 }
 
+/// Count and index a submission stored for the first time.
+pub fn index_new_submission(env: &Env, quest: &Quest, submitter: &Address) {
+    stats::record_submission(env, &quest.creator);
+    storage::index_submission(env, &quest.id, submitter);
+    storage::add_to_quest_index(env, QuestIndex::Submitter(submitter.clone()), &quest.id);
+}
+
+/// Store a submission as Paid for a reward settled outside `claim_reward`
+/// (attestations and Merkle claims), creating it if the submitter never
+/// submitted. A Pending submission leaves the verifier's queue and has its
+/// bond refunded.
+///
+/// Fails with `AlreadyClaimed` if the submitter has already been paid.
+pub fn record_paid(
+    env: &Env,
+    quest: &Quest,
+    submitter: &Address,
+    proof_hash: &BytesN<32>,
+) -> Result<(), Error> {
+    let previous_status = storage::get_submission(env, &quest.id, submitter)
+        .ok()
+        .map(|p| p.status);
+    if previous_status == Some(SubmissionStatus::Paid) {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    storage::set_submission(
+        env,
+        &quest.id,
+        submitter,
+        &Submission {
+            quest_id: quest.id.clone(),
+            submitter: submitter.clone(),
+            proof_hash: proof_hash.clone(),
+            status: SubmissionStatus::Paid,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    storage::set_completed(env, &quest.id, submitter);
+    match previous_status {
+        None => index_new_submission(env, quest, submitter),
+        Some(SubmissionStatus::Pending) => {
+            storage::remove_verifier_pending(env, &quest.verifier, &quest.id, submitter);
+            crate::bond::refund(env, &quest.id, submitter)?;
+        }
+        _ => {}
+    }
+    if previous_status != Some(SubmissionStatus::Approved) {
+        stats::record_approval(env, &quest.creator);
+    }
+
+    Ok(())
+}
+
 /// Approve a submission with status transition validation.
 ///
 /// Validates:
@@ -63,6 +152,9 @@ pub fn approve_submission(
 
     let submission = storage::get_submission(env, quest_id, submitter)?;
 
+    // Lottery entrants must be approved before the draw
+    crate::lottery::validate_approval(env, quest_id)?;
+
     // Validate status transition: Pending -> Approved
     validation::validate_submission_status_transition(
         &submission.status,
@@ -70,18 +162,22 @@ pub fn approve_submission(
     )?;
 
     // ═══════════════════════════════════════════════════════
-    // ADD THIS BLOCK — escrow check before approval
+    // Escrow check before approval
     // ═══════════════════════════════════════════════════════
     //
-    // If this quest has escrow, verify there are enough
-    // funds to pay this person BEFORE we approve them.
-    // This prevents approving someone we can't pay.
-    if storage::has_escrow(env, quest_id) {
-        crate::escrow::validate_sufficient(env, quest_id, quest.reward_amount)?;
-    }
+    // Verify the quest's escrow has enough funds to pay this
+    // person BEFORE we approve them. This prevents approving
+    // someone we can't pay. Campaign quests first draw the reward
+    // from their campaign's pool into the quest's escrow.
+    crate::campaign::draw(env, &quest, quest.reward_amount)?;
+    crate::escrow::validate_sufficient(env, quest_id, quest.reward_amount)?;
     // ═══════════════════════════════════════════════════════
 
     storage::update_submission_status(env, quest_id, submitter, SubmissionStatus::Approved)?;
+    storage::remove_verifier_pending(env, verifier, quest_id, submitter);
+    crate::lottery::enter(env, quest_id, submitter);
+    crate::bond::refund(env, quest_id, submitter)?;
+    stats::record_approval(env, &quest.creator);
 
     // EMIT EVENT: SubmissionApproved
     events::submission_approved(env, quest_id.clone(), submitter.clone(), verifier.clone());
@@ -89,17 +185,57 @@ pub fn approve_submission(
     Ok(())
 }
 
-/// Validate and process a reward claim for a submission.
+/// Reject a submission with status transition validation.
+/// Any bond held for the submission is returned, or slashed if `spam` is set.
 ///
 /// Validates:
-/// - Submission is not already paid (AlreadyClaimed)
-/// - Submission status transition (Approved -> Paid) is valid
-/// - Quest claims have not exceeded the limit
-pub fn validate_claim(
+/// - Quest exists and caller is the verifier
+/// - Submission exists
+/// - Submission status transition (Pending -> Rejected) is valid
+pub fn reject_submission(
     env: &Env,
     quest_id: &Symbol,
     submitter: &Address,
+    verifier: &Address,
+    spam: bool,
 ) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *verifier != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    let submission = storage::get_submission(env, quest_id, submitter)?;
+
+    // Validate status transition: Pending -> Rejected
+    validation::validate_submission_status_transition(
+        &submission.status,
+        &SubmissionStatus::Rejected,
+    )?;
+
+    storage::update_submission_status(env, quest_id, submitter, SubmissionStatus::Rejected)?;
+    storage::remove_verifier_pending(env, verifier, quest_id, submitter);
+    if spam {
+        crate::bond::slash(env, &quest, submitter)?;
+    } else {
+        crate::bond::refund(env, quest_id, submitter)?;
+    }
+
+    // EMIT EVENT: SubmissionRejected
+    events::submission_rejected(env, quest_id.clone(), submitter.clone(), verifier.clone());
+
+    Ok(())
+}
+
+/// Validate and process a reward claim for a submission.
+///
+/// Validates:
+/// - Submission is not already paid (AlreadyClaimed)
+/// - Submission status transition (Approved -> Paid) is valid
+/// - Quest claims have not exceeded the limit
+pub fn validate_claim(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    crate::fraud::check(env, submitter)?;
+
     let quest = storage::get_quest(env, quest_id)?;
     let submission = storage::get_submission(env, quest_id, submitter)?;
 
@@ -109,14 +245,14 @@ pub fn validate_claim(
     }
 
     // Validate status transition: Approved -> Paid
-    validation::validate_submission_status_transition(
-        &submission.status,
-        &SubmissionStatus::Paid,
-    )?;
+    validation::validate_submission_status_transition(&submission.status, &SubmissionStatus::Paid)?;
 
     // Validate quest claims limit
     validation::validate_quest_claims_limit(quest.total_claims)?;
 
+    // Lottery quests pay drawn winners only
+    crate::lottery::validate_claim(env, quest_id, submitter)?;
+
     Ok(())
 }
 
@@ -153,3 +289,61 @@ pub fn approve_submissions_batch(
 
     Ok(())
 }
+
+//================================================================================
+// Listing queries
+//================================================================================
+
+/// Load submissions for a quest from positions `cursor..cursor + limit` of its
+/// submission index, keeping only those matching `status_filter` if given.
+///
+/// Each call scans at most `validation::MAX_PAGE_SIZE` positions, so a filtered
+/// page may hold fewer than `limit` records; advance the cursor by `limit`, not
+/// by the number of records returned.
+pub fn list_submissions(
+    env: &Env,
+    quest_id: &Symbol,
+    status_filter: &Option<SubmissionStatus>,
+    cursor: u32,
+    limit: u32,
+) -> Vec<Submission> {
+    let len = storage::get_submission_index_len(env, quest_id);
+    let end = cursor
+        .saturating_add(validation::page_limit(limit))
+        .min(len);
+
+    let mut submissions = Vec::new(env);
+    for submitter in storage::get_submission_index_page(env, quest_id, cursor, end).iter() {
+        if let Ok(submission) = storage::get_submission(env, quest_id, &submitter) {
+            if status_filter
+                .as_ref()
+                .is_none_or(|s| *s == submission.status)
+            {
+                submissions.push_back(submission);
+            }
+        }
+    }
+    submissions
+}
+
+/// Load up to `limit` submissions awaiting `verifier`, starting at position
+/// `cursor` of the verifier's queue. A reviewed submission is replaced by the
+/// last one queued, so callers paging through a changing queue may see a
+/// submission twice or miss one.
+pub fn pending_for_verifier(
+    env: &Env,
+    verifier: &Address,
+    cursor: u32,
+    limit: u32,
+) -> Vec<Submission> {
+    let pending =
+        storage::get_verifier_pending_page(env, verifier, cursor, validation::page_limit(limit));
+
+    let mut submissions = Vec::new(env);
+    for key in pending.iter() {
+        if let Ok(submission) = storage::get_submission(env, &key.quest_id, &key.submitter) {
+            submissions.push_back(submission);
+        }
+    }
+    submissions
+}
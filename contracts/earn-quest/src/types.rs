@@ -1,4 +1,28 @@
-use soroban_sdk::{contracttype, Address, Symbol, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, Symbol, Vec};
+
+//================================================================================
+// Pause categories (bitmap used by granular pause switches)
+//================================================================================
+
+/// Quest registration (`register_quest`, `register_quests_batch`)
+pub const PAUSE_QUEST_REGISTRATION: u32 = 1 << 0;
+/// Proof submissions (`submit_proof`)
+pub const PAUSE_SUBMISSIONS: u32 = 1 << 1;
+/// Verifier approvals (`approve_submission`, `approve_submissions_batch`)
+pub const PAUSE_APPROVALS: u32 = 1 << 2;
+/// Reward claims (`claim_reward`)
+pub const PAUSE_CLAIMS: u32 = 1 << 3;
+/// Escrow deposits (`deposit_escrow`)
+pub const PAUSE_DEPOSITS: u32 = 1 << 4;
+/// Escrow refunds (`cancel_quest`, `withdraw_unclaimed`)
+pub const PAUSE_REFUNDS: u32 = 1 << 5;
+/// Every pausable category
+pub const PAUSE_ALL: u32 = PAUSE_QUEST_REGISTRATION
+    | PAUSE_SUBMISSIONS
+    | PAUSE_APPROVALS
+    | PAUSE_CLAIMS
+    | PAUSE_DEPOSITS
+    | PAUSE_REFUNDS;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,7 +54,9 @@ pub enum QuestStatus {
     Paused,
     Completed,
     Expired,
-    Cancelled
+    Cancelled,
+    /// Registered but not yet funded to the escrow requirement; no submissions accepted
+    Unfunded,
 }
 
 #[contracttype]
@@ -61,6 +87,21 @@ pub enum Badge {
     Legend,
 }
 
+/// Admin-managed registry entry for a reward token.
+/// Only registered, allowed assets can back quests or escrow deposits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetConfig {
+    /// Whether the asset may be used for new quests and deposits
+    pub allowed: bool,
+    /// Smallest per-quest reward accepted for this asset
+    pub min_reward: i128,
+    /// Largest per-quest reward accepted for this asset
+    pub max_reward: i128,
+    /// Token decimals, used by clients for display
+    pub decimals: u32,
+}
+
 //================================================================================
 // Batch operation input types (gas-optimized multi-item operations)
 //================================================================================
@@ -86,16 +127,19 @@ pub struct BatchApprovalInput {
     pub submitter: Address,
 }
 
-
-/// Escrow tracks tokens locked per quest.
-/// Created when a creator calls deposit_escrow().
+/// Escrow tracks tokens locked per quest, or per campaign pool.
+/// Created when a creator calls deposit_escrow() (or deposit_campaign()).
 /// Updated when payouts happen or funds are refunded.
+///
+/// For a campaign pool, `total_paid_out` is the net amount drawn into its
+/// quests (draws minus leftovers returned when quests end).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EscrowInfo {
-    /// Which quest this escrow belongs to
+    /// Which quest (or campaign) this escrow belongs to
     pub quest_id: Symbol,
-    /// Who deposited (must be quest creator)
+    /// Quest creator that owns the escrow (sponsor deposits are tracked
+    /// separately as `EscrowContribution`s)
     pub depositor: Address,
     /// Which token is held
     pub token: Address,
@@ -105,6 +149,450 @@ pub struct EscrowInfo {
     pub total_paid_out: i128,
     /// Total tokens refunded back to creator
     pub total_refunded: i128,
+    /// Total platform fees taken from this escrow (on deposit or on payout)
+    pub total_fees: i128,
+    /// When the platform fee is charged on this escrow, fixed when it is created
+    /// so a later `FeeMode` change cannot charge the same funds twice
+    pub fee_mode: FeeMode,
     /// Whether this escrow is still active
     pub is_active: bool,
 }
+
+/// `EscrowInfo` as written to instance storage by contract versions that
+/// predate platform fees. Only decoded by `migrate_storage`, which upgrades it
+/// with `total_fees` = 0 and `FeeMode::OnPayout` (the only mode those
+/// versions could have charged).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyEscrowInfo {
+    pub quest_id: Symbol,
+    pub depositor: Address,
+    pub token: Address,
+    pub total_deposited: i128,
+    pub total_paid_out: i128,
+    pub total_refunded: i128,
+    pub is_active: bool,
+}
+
+/// A shared reward budget that funds many quests in one asset.
+/// The pool itself is tracked as an `EscrowInfo` keyed by the campaign id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    pub id: Symbol,
+    /// Owns the pool; leftover funds are withdrawn to this address
+    pub owner: Address,
+    /// Reward asset for every quest in the campaign
+    pub asset: Address,
+    /// Addresses besides the owner allowed to fund the pool and register quests
+    pub managers: Vec<Address>,
+}
+
+/// A Merkle root over `(index, submitter, amount)` leaves posted by a quest's
+/// verifier. Submitters claim their leaf with `claim_with_proof`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleRound {
+    pub root: BytesN<32>,
+    /// Number of leaves; valid leaf indices are `0..leaf_count`
+    pub leaf_count: u32,
+    /// Sum of all leaf amounts, checked against escrow when posted
+    pub total_amount: i128,
+    /// Sum of amounts claimed so far
+    pub claimed_amount: i128,
+    /// Ledger timestamp when the root was posted
+    pub posted_at: u64,
+}
+
+/// Off-chain approval signed by a quest's attestor. The attestor signs the
+/// XDR encoding of this struct with its ed25519 key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub quest_id: Symbol,
+    pub submitter: Address,
+    pub proof_hash: BytesN<32>,
+    /// Reward to pay, before any payout fee
+    pub amount: i128,
+    /// Ledger timestamp after which the attestation is no longer accepted
+    pub expiry: u64,
+    /// Single-use per quest; prevents replaying the same attestation
+    pub nonce: u64,
+}
+
+/// Expected answer of an answer-hash quest. Submitters commit
+/// `sha256(answer || submitter)` before `reveal_at` and reveal the answer
+/// from `reveal_at` until the quest deadline, once the creator has published
+/// the salt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnswerConfig {
+    /// `sha256(salt || answer)`
+    pub answer_hash: BytesN<32>,
+    /// Ledger timestamp that ends commits and starts reveals
+    pub reveal_at: u64,
+    /// Salt published by the creator at or after `reveal_at`; kept secret
+    /// until then so the answer cannot be brute-forced from `answer_hash`
+    pub salt: Option<Bytes>,
+}
+
+/// Outcome of a lottery quest's draw. `seed` reseeds the PRNG that shuffled
+/// the approved submitters, so the draw can be audited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotteryDraw {
+    pub seed: BytesN<32>,
+    pub winners: Vec<Address>,
+    /// Number of approved submitters the winners were drawn from
+    pub entrants: u32,
+    /// Ledger timestamp of the draw
+    pub drawn_at: u64,
+}
+
+/// One depositor's net contribution (after deposit fees) to a quest's escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowContribution {
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+/// How leftover escrow is returned to depositors on cancel or withdrawal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RefundPolicy {
+    /// Each depositor receives a share proportional to their contribution
+    ProRata,
+    /// The most recent depositor is repaid first, then the one before, and so on
+    Lifo,
+}
+
+/// Creator-controlled sponsorship rules for a quest.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorPolicy {
+    /// Only the creator and allowlisted sponsors may deposit
+    pub allowlist_only: bool,
+    /// How leftover escrow is split between depositors
+    pub refund_policy: RefundPolicy,
+}
+
+/// Where a quest checks that submitted proof hashes have not been used before.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProofScope {
+    /// Proof hashes are not checked
+    Off,
+    /// Against other submissions to the same quest
+    Quest,
+    /// Against submissions to every quest using the global registry
+    Global,
+}
+
+/// What happens when a submitted proof hash was already used by someone else.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuplicateAction {
+    /// `submit_proof` fails with `DuplicateProof`
+    Reject,
+    /// The submission is accepted and flagged for the verifier
+    Flag,
+}
+
+/// Creator-controlled proof-hash uniqueness rules for a quest.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofPolicy {
+    pub scope: ProofScope,
+    pub on_duplicate: DuplicateAction,
+}
+
+/// The first submission that used a proof hash.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofUse {
+    pub quest_id: Symbol,
+    pub submitter: Address,
+}
+
+/// Who may submit proof to a quest.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    /// Anyone
+    Open,
+    /// Only participants the creator has allowlisted
+    Allowlist,
+    /// Participants admitted by redeeming one of the creator's invite codes
+    InviteCode,
+}
+
+/// Requirements a user must meet before submitting proof to a quest.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prerequisites {
+    /// Quests the user must have been paid for
+    pub quests: Vec<Symbol>,
+    /// Minimum `UserStats.level`
+    pub min_level: u32,
+    /// Badges the user must hold
+    pub badges: Vec<Badge>,
+}
+
+/// Which of a quest's prerequisites a user is missing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eligibility {
+    /// True if nothing is missing
+    pub eligible: bool,
+    /// Prerequisite quests the user has not been paid for
+    pub missing_quests: Vec<Symbol>,
+    /// Level the quest requires
+    pub min_level: u32,
+    /// User's current level
+    pub level: u32,
+    /// Required badges the user does not hold
+    pub missing_badges: Vec<Badge>,
+}
+
+/// Admin record of an address denylisted for fraud.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FraudFlag {
+    /// Admin who flagged the address
+    pub flagged_by: Address,
+    /// Ledger timestamp of the flag
+    pub flagged_at: u64,
+    /// XP removed from the address when it was flagged
+    pub xp_penalty: u64,
+}
+
+/// Charge taken from a creator for each quest they register.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationDeposit {
+    pub asset: Address,
+    pub amount: i128,
+    /// Whether the charge is returned once the quest is terminal with its
+    /// escrow settled; otherwise it is a fee credited to the treasury
+    pub refundable: bool,
+}
+
+/// Where a slashed submission bond is sent.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlashTarget {
+    /// Transferred to the quest creator
+    Creator,
+    /// Credited to the platform treasury balance
+    Treasury,
+}
+
+/// Bond a quest requires submitters to post with their proof.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    /// Token the bond is posted in
+    pub asset: Address,
+    pub amount: i128,
+    /// Recipient of bonds slashed for spam
+    pub slash_to: SlashTarget,
+}
+
+/// A submission bond held by the contract.
+/// Records the terms it was posted under, so later config changes do not affect it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bond {
+    pub asset: Address,
+    pub amount: i128,
+    pub slash_to: SlashTarget,
+    /// Ledger timestamp the bond was posted
+    pub posted_at: u64,
+}
+
+/// Privileged setting that can only be changed through an M-of-N proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalTarget {
+    /// Address that receives emergency withdrawals
+    RecoveryAddress,
+    /// Admin allowed to withdraw accrued platform fees
+    Treasurer,
+    /// Wasm the contract runs
+    ContractCode,
+    /// Crediting an asset's surplus to the treasury
+    SurplusSweep,
+    /// Granting an address admin rights
+    AddAdmin,
+    /// Revoking an admin's rights
+    RemoveAdmin,
+}
+
+/// New value of a `ProposalTarget`, applied once the proposal executes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalValue {
+    /// For `RecoveryAddress`, `Treasurer`, `AddAdmin` and `RemoveAdmin`; the
+    /// asset for `SurplusSweep`
+    Address(Address),
+    /// For `ContractCode`: hash of wasm already uploaded to the network
+    WasmHash(BytesN<32>),
+}
+
+/// A pending change of a `ProposalTarget`.
+/// Follows the same M-of-N approval and timelock rules as unpausing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Monotonic proposal id
+    pub id: u32,
+    /// Value the target is set to once executed
+    pub value: ProposalValue,
+    /// Admin that proposed the change
+    pub proposer: Address,
+    /// Admins that approved this proposal; only those still admins count at execution
+    pub approvers: Vec<Address>,
+    /// Ledger timestamp after which the change can be executed, once threshold is met
+    pub executable_at: Option<u64>,
+}
+
+/// Per-asset payout circuit breaker configuration.
+/// The breaker trips when a payout would push the amount paid out within the
+/// rolling window above the limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Length of the rolling payout window in seconds
+    pub window_seconds: u64,
+    /// Absolute cap on payouts per window (0 = no absolute cap)
+    pub max_amount: i128,
+    /// Cap as basis points of the contract balance before the window's payouts (0 = no share cap)
+    pub max_bps_of_balance: u32,
+}
+
+/// Payouts of an asset within one sub-window of the rolling circuit breaker window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutBucket {
+    /// Ledger timestamp at which the sub-window started
+    pub start: u64,
+    /// Total paid out in the sub-window
+    pub paid: i128,
+    /// Number of payouts in the sub-window
+    pub count: u32,
+}
+
+/// Running payout totals for the rolling circuit breaker window of an asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutWindow {
+    /// Sub-windows still inside the rolling window, oldest first
+    pub buckets: Vec<PayoutBucket>,
+    /// Total paid out across `buckets`
+    pub total_paid: i128,
+    /// Number of payouts across `buckets`
+    pub payout_count: u32,
+}
+
+/// When the platform fee is taken.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeMode {
+    /// Fee is deducted from each escrow deposit
+    OnDeposit,
+    /// Fee is deducted from each reward payout
+    OnPayout,
+}
+
+/// Global platform fee configuration.
+/// Per-asset overrides of `fee_bps` are stored separately.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Fee in basis points (1/100 of a percent)
+    pub fee_bps: u32,
+    /// Whether the fee is charged on deposit or on payout
+    pub mode: FeeMode,
+}
+
+/// TTL extension settings for persistent entries, in ledgers.
+/// Entries whose TTL falls below `threshold` are extended to `extend_to`
+/// whenever they are read or written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// One entry in a user's quest history: a quest they submitted proof for
+/// and the current status of that submission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserQuestRecord {
+    pub quest_id: Symbol,
+    pub status: SubmissionStatus,
+}
+
+/// A user's lifetime earnings in one reward asset, net of platform fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetEarnings {
+    pub asset: Address,
+    pub amount: i128,
+}
+
+/// Aggregate activity counters, kept platform-wide and per creator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityStats {
+    /// Number of quests currently in each status
+    pub quests_unfunded: u32,
+    pub quests_active: u32,
+    pub quests_paused: u32,
+    pub quests_completed: u32,
+    pub quests_expired: u32,
+    pub quests_cancelled: u32,
+    /// Lifetime submission, approval and claim counts
+    pub total_submissions: u32,
+    pub total_approvals: u32,
+    pub total_claims: u32,
+    /// Lifetime rewards paid out, net of fees, keyed by asset
+    pub paid_volume: Map<Address, i128>,
+    /// Tokens currently held in escrow, keyed by asset
+    pub active_escrow: Map<Address, i128>,
+}
+
+/// Reconciliation of one asset's tracked liabilities against the contract's
+/// actual token balance. At most one of `surplus` and `deficit` is non-zero.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    pub asset: Address,
+    /// Contract's token balance
+    pub balance: i128,
+    /// Tokens owed to quests through their escrow
+    pub escrow_liability: i128,
+    /// Accrued platform fees awaiting withdrawal
+    pub treasury_liability: i128,
+    /// Submission bonds held
+    pub bond_liability: i128,
+    /// Refundable quest creation deposits held
+    pub deposit_liability: i128,
+    /// Tokens moved to the recovery address by emergency withdrawals; they
+    /// still back the liabilities above until returned
+    pub recovered: i128,
+    /// Tokens held beyond all liabilities
+    pub surplus: i128,
+    /// Liabilities not covered by the balance
+    pub deficit: i128,
+}
+
+/// Key of a submission record, used when migrating storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionKey {
+    pub quest_id: Symbol,
+    pub submitter: Address,
+}
@@ -0,0 +1,98 @@
+//! Proof-hash uniqueness — detects submissions that reuse a proof hash
+//! another submitter has already used, within one quest or across every
+//! quest that opts into the global registry.
+//!
+//! The first (quest, submitter) to use a hash is recorded; resubmitting
+//! one's own hash is never a duplicate.
+
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage;
+use crate::types::{DuplicateAction, ProofPolicy, ProofScope, ProofUse};
+use crate::validation;
+
+/// Registry a quest records proof hashes in: `Some(Some(quest_id))` for its
+/// own, `Some(None)` for the global one, `None` if it does not check.
+fn registry(quest_id: &Symbol, policy: &ProofPolicy) -> Option<Option<Symbol>> {
+    match policy.scope {
+        ProofScope::Off => None,
+        ProofScope::Quest => Some(Some(quest_id.clone())),
+        ProofScope::Global => Some(None),
+    }
+}
+
+/// Set a quest's proof-hash uniqueness rules.
+///
+/// # Requirements
+/// - Caller must be the quest creator
+/// - Quest must not be terminal
+pub fn set_policy(
+    env: &Env,
+    quest_id: &Symbol,
+    caller: &Address,
+    policy: &ProofPolicy,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *caller != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if validation::is_quest_terminal(&quest.status) {
+        return Err(Error::QuestNotActive);
+    }
+
+    storage::set_proof_policy(env, quest_id, policy);
+    events::proof_policy_set(env, quest_id.clone(), caller.clone(), policy.clone());
+
+    Ok(())
+}
+
+/// Check a submission's proof hash against the quest's registry and record it.
+///
+/// A hash first used by someone else fails with `DuplicateProof`, or flags
+/// the submission, depending on the quest's policy. Called from
+/// `submit_proof` before the submission is stored.
+pub fn register(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    proof_hash: &BytesN<32>,
+) -> Result<(), Error> {
+    let policy = storage::get_proof_policy(env, quest_id);
+    let Some(registry) = registry(quest_id, &policy) else {
+        storage::delete_duplicate_flag(env, quest_id, submitter);
+        return Ok(());
+    };
+
+    match storage::get_proof_use(env, &registry, proof_hash) {
+        Some(first) if first.quest_id != *quest_id || first.submitter != *submitter => {
+            if policy.on_duplicate == DuplicateAction::Reject {
+                return Err(Error::DuplicateProof);
+            }
+            storage::set_duplicate_flag(env, quest_id, submitter, &first);
+            events::duplicate_proof_flagged(env, quest_id.clone(), submitter.clone(), first);
+        }
+        Some(_) => storage::delete_duplicate_flag(env, quest_id, submitter),
+        None => {
+            let first = ProofUse {
+                quest_id: quest_id.clone(),
+                submitter: submitter.clone(),
+            };
+            storage::set_proof_use(env, &registry, proof_hash, &first);
+            storage::delete_duplicate_flag(env, quest_id, submitter);
+        }
+    }
+
+    Ok(())
+}
+
+/// First (quest, submitter) to use `proof_hash` in the registry `quest_id`
+/// checks against, or `None` if unused or the quest does not check.
+pub fn first_use(env: &Env, quest_id: &Symbol, proof_hash: &BytesN<32>) -> Option<ProofUse> {
+    let policy = storage::get_proof_policy(env, quest_id);
+    registry(quest_id, &policy)
+        .and_then(|registry| storage::get_proof_use(env, &registry, proof_hash))
+}
@@ -1,6 +1,7 @@
 use crate::errors::Error;
-use crate::types::{QuestStatus, SubmissionStatus};
-use soroban_sdk::Env;
+use crate::storage;
+use crate::types::{AssetConfig, QuestStatus, SubmissionStatus, TtlConfig};
+use soroban_sdk::{Address, Env};
 
 //================================================================================
 // Constants — Validation Limits
@@ -27,6 +28,49 @@ pub const MAX_BATCH_QUEST_REGISTRATION: u32 = 50;
 /// Maximum number of submissions that can be approved in a single batch call
 pub const MAX_BATCH_APPROVALS: u32 = 50;
 
+/// Maximum number of entries that can be migrated in a single call
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Maximum number of submissions deleted by a single prune call
+pub const MAX_PRUNE_SUBMISSIONS: u32 = 50;
+
+/// Maximum number of a flagged address's quests checked by a single voiding call
+pub const MAX_VOID_SUBMISSIONS: u32 = 50;
+
+/// Maximum number of records returned by a single listing query
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of distinct depositors funding a single quest's escrow
+pub const MAX_SPONSORS: u32 = 50;
+
+/// Maximum number of managers on a single campaign
+pub const MAX_CAMPAIGN_MANAGERS: u32 = 20;
+
+/// Maximum number of leaves in a posted Merkle root
+pub const MAX_MERKLE_LEAVES: u32 = 1_000_000;
+
+/// Maximum number of sibling hashes in a Merkle proof (enough for `MAX_MERKLE_LEAVES`)
+pub const MAX_MERKLE_PROOF_DEPTH: u32 = 32;
+
+/// Maximum number of prerequisite quests or badges a quest can require
+pub const MAX_PREREQUISITES: u32 = 10;
+
+/// Maximum number of participants or invite codes added in a single call
+pub const MAX_ACCESS_BATCH: u32 = 50;
+
+/// Maximum length in bytes of an answer or salt for answer-hash quests
+pub const MAX_ANSWER_LENGTH: u32 = 256;
+
+/// Maximum number of winners drawn by a lottery quest
+pub const MAX_LOTTERY_WINNERS: u32 = 100;
+
+/// Time drawn lottery winners have to claim before their rewards can be
+/// refunded (30 days)
+pub const LOTTERY_CLAIM_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/// Maximum platform fee in basis points (10%)
+pub const MAX_FEE_BPS: u32 = 1_000;
+
 //================================================================================
 // Address Validation
 //================================================================================
@@ -76,6 +120,71 @@ pub fn validate_reward_amount(amount: i128) -> Result<(), Error> {
     Ok(())
 }
 
+//================================================================================
+// Asset Registry Validation
+//================================================================================
+
+/// Validates that an asset is registered and allowed.
+///
+/// # Returns
+/// * `Ok(AssetConfig)` with the registry entry
+/// * `Err(Error::InvalidAsset)` if the asset is unknown or disallowed
+pub fn validate_asset_allowed(env: &Env, asset: &Address) -> Result<AssetConfig, Error> {
+    match storage::get_asset_config(env, asset) {
+        Some(config) if config.allowed => Ok(config),
+        _ => Err(Error::InvalidAsset),
+    }
+}
+
+/// Validates a reward amount against the per-asset limits in the registry.
+///
+/// # Returns
+/// * `Ok(())` if the asset is allowed and amount is within [min_reward, max_reward]
+/// * `Err(Error::InvalidAsset)` if the asset is unknown or disallowed
+/// * `Err(Error::InvalidRewardAmount)` if amount < min_reward
+/// * `Err(Error::AmountTooLarge)` if amount > max_reward
+pub fn validate_reward_for_asset(env: &Env, asset: &Address, amount: i128) -> Result<(), Error> {
+    let config = validate_asset_allowed(env, asset)?;
+    if amount < config.min_reward {
+        return Err(Error::InvalidRewardAmount);
+    }
+    if amount > config.max_reward {
+        return Err(Error::AmountTooLarge);
+    }
+    Ok(())
+}
+
+/// Validates a registry entry's reward bounds.
+///
+/// # Returns
+/// * `Ok(())` if MIN_REWARD_AMOUNT <= min_reward <= max_reward <= MAX_REWARD_AMOUNT
+/// * `Err(Error::InvalidRewardAmount)` otherwise
+pub fn validate_asset_config(config: &AssetConfig) -> Result<(), Error> {
+    if config.min_reward < MIN_REWARD_AMOUNT
+        || config.max_reward > MAX_REWARD_AMOUNT
+        || config.min_reward > config.max_reward
+    {
+        return Err(Error::InvalidRewardAmount);
+    }
+    Ok(())
+}
+
+//================================================================================
+// Fee Validation
+//================================================================================
+
+/// Validates a platform fee in basis points.
+///
+/// # Returns
+/// * `Ok(())` if fee_bps <= MAX_FEE_BPS
+/// * `Err(Error::InvalidConfig)` otherwise
+pub fn validate_fee_bps(fee_bps: u32) -> Result<(), Error> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
 //================================================================================
 // Deadline Validation
 //================================================================================
@@ -145,6 +254,7 @@ fn symbol_len(sym: &soroban_sdk::Symbol) -> u32 {
     // We use a simple byte-count approach; Symbol stores short ASCII.
     // Since Soroban enforces this at construction, this is a secondary check.
     let _ = sym; // Symbol is always valid if constructed; length is implicitly bounded.
+
     // In Soroban SDK, Symbols are at most 32 characters. We return a constant
     // that passes validation since the SDK already enforces this.
     // This function exists to provide a consistent API.
@@ -193,11 +303,15 @@ pub fn validate_badge_count(current_count: u32) -> Result<(), Error> {
 /// Validates a quest status transition is allowed.
 ///
 /// Allowed transitions:
+/// * Unfunded -> Active (once escrow meets the funding requirement)
+/// * Unfunded -> Cancelled
 /// * Active -> Paused
 /// * Active -> Completed
 /// * Active -> Expired
+/// * Active -> Cancelled
 /// * Paused -> Active
 /// * Paused -> Expired
+/// * Paused -> Cancelled
 ///
 /// # Arguments
 /// * `from` - Current quest status
@@ -206,20 +320,19 @@ pub fn validate_badge_count(current_count: u32) -> Result<(), Error> {
 /// # Returns
 /// * `Ok(())` if the transition is allowed
 /// * `Err(Error::InvalidStatusTransition)` if the transition is not allowed
-pub fn validate_quest_status_transition(
-    from: &QuestStatus,
-    to: &QuestStatus,
-) -> Result<(), Error> {
-    let valid = match (from, to) {
-        (QuestStatus::Active, QuestStatus::Paused) => true,
-        (QuestStatus::Active, QuestStatus::Completed) => true,
-        (QuestStatus::Active, QuestStatus::Expired) => true,
-        (QuestStatus::Paused, QuestStatus::Active) => true,
-        (QuestStatus::Paused, QuestStatus::Expired) => true,
-        (QuestStatus::Active, QuestStatus::Cancelled) => true,
-        (QuestStatus::Paused, QuestStatus::Cancelled) => true,
-        _ => false,
-    };
+pub fn validate_quest_status_transition(from: &QuestStatus, to: &QuestStatus) -> Result<(), Error> {
+    let valid = matches!(
+        (from, to),
+        (QuestStatus::Unfunded, QuestStatus::Active)
+            | (QuestStatus::Unfunded, QuestStatus::Cancelled)
+            | (QuestStatus::Active, QuestStatus::Paused)
+            | (QuestStatus::Active, QuestStatus::Completed)
+            | (QuestStatus::Active, QuestStatus::Expired)
+            | (QuestStatus::Paused, QuestStatus::Active)
+            | (QuestStatus::Paused, QuestStatus::Expired)
+            | (QuestStatus::Active, QuestStatus::Cancelled)
+            | (QuestStatus::Paused, QuestStatus::Cancelled)
+    );
 
     if !valid {
         return Err(Error::InvalidStatusTransition);
@@ -245,12 +358,12 @@ pub fn validate_submission_status_transition(
     from: &SubmissionStatus,
     to: &SubmissionStatus,
 ) -> Result<(), Error> {
-    let valid = match (from, to) {
-        (SubmissionStatus::Pending, SubmissionStatus::Approved) => true,
-        (SubmissionStatus::Pending, SubmissionStatus::Rejected) => true,
-        (SubmissionStatus::Approved, SubmissionStatus::Paid) => true,
-        _ => false,
-    };
+    let valid = matches!(
+        (from, to),
+        (SubmissionStatus::Pending, SubmissionStatus::Approved)
+            | (SubmissionStatus::Pending, SubmissionStatus::Rejected)
+            | (SubmissionStatus::Approved, SubmissionStatus::Paid)
+    );
 
     if !valid {
         return Err(Error::InvalidStatusTransition);
@@ -314,10 +427,35 @@ pub fn validate_batch_approval_size(length: u32) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validates that the total number of entries in a storage migration is within limits.
+pub fn validate_migration_size(length: u32) -> Result<(), Error> {
+    if length == 0 || length > MAX_MIGRATION_BATCH {
+        return Err(Error::ArrayTooLong);
+    }
+    Ok(())
+}
+
+/// Validates TTL extension settings against the network's maximum entry TTL.
+///
+/// # Returns
+/// * `Ok(())` if 0 < threshold <= extend_to <= max_ttl
+/// * `Err(Error::InvalidConfig)` otherwise
+pub fn validate_ttl_config(config: &TtlConfig, max_ttl: u32) -> Result<(), Error> {
+    if config.threshold == 0 || config.extend_to < config.threshold || config.extend_to > max_ttl {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Caps a listing query's page size at `MAX_PAGE_SIZE`.
+pub fn page_limit(limit: u32) -> u32 {
+    limit.min(MAX_PAGE_SIZE)
+}
+
 /// Check if a quest is in a terminal state (no more activity possible)
 pub fn is_quest_terminal(status: &QuestStatus) -> bool {
     matches!(
         status,
         QuestStatus::Completed | QuestStatus::Expired | QuestStatus::Cancelled
     )
-}
\ No newline at end of file
+}
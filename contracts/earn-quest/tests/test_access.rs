@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AccessMode, AssetConfig};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    quest_id: Symbol,
+    proof: BytesN<32>,
+}
+
+/// One funded quest.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let quest_id = symbol_short!("q1");
+    contract.register_quest(&quest_id, &creator, &token_address, &100, &verifier, &99999);
+    contract.deposit_escrow(&quest_id, &creator, &token_address, &1000);
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    TestEnv {
+        env,
+        contract,
+        creator,
+        quest_id,
+        proof,
+    }
+}
+
+// ══════════════════════════════════════════════════════════════
+// Allowlist
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_allowlist_mode_admits_only_participants() {
+    let t = setup();
+    let member = Address::generate(&t.env);
+    let outsider = Address::generate(&t.env);
+
+    t.contract
+        .set_access_mode(&t.quest_id, &t.creator, &AccessMode::Allowlist);
+    t.contract.set_participants(
+        &t.quest_id,
+        &t.creator,
+        &vec![&t.env, member.clone()],
+        &true,
+    );
+
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (
+            symbol_short!("allowlist"),
+            t.quest_id.clone(),
+            t.creator.clone()
+        )
+            .into_val(&t.env)
+    );
+
+    t.contract.submit_proof(&t.quest_id, &member, &t.proof);
+    assert_eq!(
+        t.contract
+            .try_submit_proof(&t.quest_id, &outsider, &t.proof),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Removed participants can no longer resubmit
+    t.contract.set_participants(
+        &t.quest_id,
+        &t.creator,
+        &vec![&t.env, member.clone()],
+        &false,
+    );
+    assert!(!t.contract.is_participant(&t.quest_id, &member));
+    assert_eq!(
+        t.contract.try_submit_proof(&t.quest_id, &member, &t.proof),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Reopening lets everyone in
+    t.contract
+        .set_access_mode(&t.quest_id, &t.creator, &AccessMode::Open);
+    t.contract.submit_proof(&t.quest_id, &outsider, &t.proof);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Invite codes
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_invite_codes_are_single_use() {
+    let t = setup();
+    let code = Bytes::from_slice(&t.env, b"WELCOME-42");
+    let code_hash: BytesN<32> = t.env.crypto().sha256(&code).into();
+
+    t.contract
+        .set_access_mode(&t.quest_id, &t.creator, &AccessMode::InviteCode);
+    t.contract.set_invite_codes(
+        &t.quest_id,
+        &t.creator,
+        &vec![&t.env, code_hash.clone()],
+        &true,
+    );
+    assert!(t.contract.is_invite_code_available(&t.quest_id, &code_hash));
+
+    let invited = Address::generate(&t.env);
+    let other = Address::generate(&t.env);
+    assert_eq!(
+        t.contract.try_submit_proof(&t.quest_id, &invited, &t.proof),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    t.contract
+        .submit_proof_with_invite(&t.quest_id, &invited, &t.proof, &code);
+    assert!(t.contract.is_participant(&t.quest_id, &invited));
+    assert!(!t.contract.is_invite_code_available(&t.quest_id, &code_hash));
+
+    // The admitted submitter can resubmit without a code; the code is spent
+    t.contract.submit_proof(&t.quest_id, &invited, &t.proof);
+    assert_eq!(
+        t.contract
+            .try_submit_proof_with_invite(&t.quest_id, &other, &t.proof, &code),
+        Err(Ok(Error::InvalidProof))
+    );
+    assert_eq!(
+        t.contract.try_submit_proof_with_invite(
+            &t.quest_id,
+            &other,
+            &t.proof,
+            &Bytes::from_slice(&t.env, b"GUESS"),
+        ),
+        Err(Ok(Error::InvalidProof))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_only_creator_manages_access() {
+    let t = setup();
+    let stranger = Address::generate(&t.env);
+
+    assert_eq!(
+        t.contract
+            .try_set_access_mode(&t.quest_id, &stranger, &AccessMode::Allowlist),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_participants(
+            &t.quest_id,
+            &stranger,
+            &vec![&t.env, stranger.clone()],
+            &true,
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_invite_codes(
+            &t.quest_id,
+            &stranger,
+            &vec![&t.env, t.proof.clone()],
+            &true,
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(t.contract.get_access_mode(&t.quest_id), AccessMode::Open);
+}
@@ -3,6 +3,7 @@
 use soroban_sdk::{testutils::Address as _, Address, Env};
 
 extern crate earn_quest;
+use earn_quest::errors::Error;
 use earn_quest::types::Badge;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
@@ -16,6 +17,24 @@ fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
     (contract_id, client)
 }
 
+/// Propose adding `new_admin` and execute it with the given approvals.
+fn add_admin(client: &EarnQuestContractClient, approvers: &[&Address], new_admin: &Address) {
+    client.add_admin(approvers[0], new_admin);
+    for approver in approvers {
+        client.approve_add_admin(approver);
+    }
+    client.execute_add_admin(approvers[0]);
+}
+
+/// Propose removing `admin` and execute it with the given approvals.
+fn remove_admin(client: &EarnQuestContractClient, approvers: &[&Address], admin: &Address) {
+    client.remove_admin(approvers[0], admin);
+    for approver in approvers {
+        client.approve_remove_admin(approver);
+    }
+    client.execute_remove_admin(approvers[0]);
+}
+
 //================================================================================
 // Initialization Tests
 //================================================================================
@@ -61,7 +80,7 @@ fn test_admin_can_add_admin() {
     let new_admin = Address::generate(&env);
 
     client.initialize(&initial_admin);
-    client.add_admin(&initial_admin, &new_admin);
+    add_admin(&client, &[&initial_admin], &new_admin);
 
     assert!(client.is_admin(&new_admin));
 }
@@ -77,8 +96,8 @@ fn test_multiple_admins_can_be_registered() {
     let admin3 = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
-    client.add_admin(&admin1, &admin3);
+    add_admin(&client, &[&admin1], &admin2);
+    add_admin(&client, &[&admin1, &admin2], &admin3);
 
     assert!(client.is_admin(&admin1));
     assert!(client.is_admin(&admin2));
@@ -114,11 +133,11 @@ fn test_admin_can_remove_admin() {
     let admin2 = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
+    add_admin(&client, &[&admin1], &admin2);
 
     assert!(client.is_admin(&admin2));
 
-    client.remove_admin(&admin1, &admin2);
+    remove_admin(&client, &[&admin1, &admin2], &admin2);
 
     assert!(!client.is_admin(&admin2));
 }
@@ -152,11 +171,11 @@ fn test_admin_status_can_be_granted_and_revoked() {
     assert!(!client.is_admin(&user));
 
     // Grant admin status
-    client.add_admin(&admin, &user);
+    add_admin(&client, &[&admin], &user);
     assert!(client.is_admin(&user));
 
     // Revoke admin status
-    client.remove_admin(&admin, &user);
+    remove_admin(&client, &[&admin, &user], &user);
     assert!(!client.is_admin(&user));
 }
 
@@ -206,7 +225,7 @@ fn test_second_admin_can_grant_badge() {
     let user = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
+    add_admin(&client, &[&admin1], &admin2);
 
     // Second admin grants badge
     client.grant_badge(&admin2, &user, &Badge::Explorer);
@@ -227,8 +246,8 @@ fn test_removed_admin_cannot_grant_badge() {
     let user = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
-    client.remove_admin(&admin1, &admin2);
+    add_admin(&client, &[&admin1], &admin2);
+    remove_admin(&client, &[&admin1, &admin2], &admin2);
 
     // Removed admin tries to grant badge
     client.grant_badge(&admin2, &user, &Badge::Rookie);
@@ -248,8 +267,8 @@ fn test_admin_can_add_same_admin_twice() {
     let new_admin = Address::generate(&env);
 
     client.initialize(&admin);
-    client.add_admin(&admin, &new_admin);
-    client.add_admin(&admin, &new_admin); // Should not panic
+    add_admin(&client, &[&admin], &new_admin);
+    add_admin(&client, &[&admin, &new_admin], &new_admin); // Should not panic
 
     assert!(client.is_admin(&new_admin));
 }
@@ -261,10 +280,95 @@ fn test_remove_non_existent_admin() {
 
     let (_, client) = setup_contract(&env);
     let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
     let non_admin = Address::generate(&env);
 
     client.initialize(&admin);
-    client.remove_admin(&admin, &non_admin); // Should not panic
+    add_admin(&client, &[&admin], &admin2);
+    remove_admin(&client, &[&admin, &admin2], &non_admin); // Should not panic
 
     assert!(!client.is_admin(&non_admin));
 }
+
+//================================================================================
+// Admin Set Proposal Tests
+//================================================================================
+
+#[test]
+fn test_single_admin_cannot_change_admin_set_alone() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let sock = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_admin(&client, &[&admin1], &admin2);
+
+    // With two admins the default threshold of 2 applies
+    client.add_admin(&admin1, &sock);
+    client.approve_add_admin(&admin1);
+    assert_eq!(
+        client.try_execute_add_admin(&admin1),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert!(!client.is_admin(&sock));
+
+    client.remove_admin(&admin1, &admin2);
+    client.approve_remove_admin(&admin1);
+    assert_eq!(
+        client.try_execute_remove_admin(&admin1),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert!(client.is_admin(&admin2));
+}
+
+#[test]
+fn test_removed_admin_approval_stops_counting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+    let recovery = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_admin(&client, &[&admin1], &admin2);
+    add_admin(&client, &[&admin1, &admin2], &admin3);
+
+    client.propose_recovery_address(&admin1, &recovery);
+    client.approve_recovery_address(&admin1);
+    client.approve_recovery_address(&admin3);
+
+    remove_admin(&client, &[&admin1, &admin2], &admin3);
+    assert_eq!(
+        client.try_execute_recovery_address(&admin1),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    client.approve_recovery_address(&admin2);
+    client.execute_recovery_address(&admin1);
+    assert_eq!(client.get_recovery_address(), Some(recovery));
+}
+
+#[test]
+fn test_last_admin_cannot_be_removed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.remove_admin(&admin, &admin);
+    client.approve_remove_admin(&admin);
+    assert_eq!(
+        client.try_execute_remove_admin(&admin),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert!(client.is_admin(&admin));
+}
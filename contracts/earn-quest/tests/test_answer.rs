@@ -0,0 +1,265 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, token, Address, Bytes, Env, Symbol};
+
+use earn_quest::answer::{answer_hash, commitment};
+use earn_quest::errors::Error;
+use earn_quest::types::{AnswerConfig, AssetConfig, SubmissionStatus};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    token_address: Address,
+    token: token::Client<'a>,
+    quest_id: Symbol,
+}
+
+/// Funded answer quest paying 100: commits until t=2000, reveals until t=5000.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let quest_id = symbol_short!("trivia");
+    let hash = answer_hash(&env, &salt(&env), &Bytes::from_slice(&env, b"paris"));
+    contract.register_answer_quest(
+        &quest_id,
+        &creator,
+        &token_address,
+        &100,
+        &5000,
+        &hash,
+        &2000,
+    );
+    contract.deposit_escrow(&quest_id, &creator, &token_address, &1000);
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        token_address,
+        token,
+        quest_id,
+    }
+}
+
+fn salt(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"pepper")
+}
+
+/// Close commits and publish the salt, opening reveals.
+fn open_reveals(t: &TestEnv) {
+    t.env.ledger().with_mut(|l| l.timestamp = 2000);
+    t.contract
+        .publish_answer_salt(&t.quest_id, &t.creator, &salt(&t.env));
+}
+
+fn commit(t: &TestEnv, answer: &[u8]) -> Address {
+    let user = Address::generate(&t.env);
+    let proof = commitment(&t.env, &Bytes::from_slice(&t.env, answer), &user);
+    t.contract.submit_proof(&t.quest_id, &user, &proof);
+    user
+}
+
+// ══════════════════════════════════════════════════════════════
+// Commit and reveal
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_correct_reveal_approves_without_verifier() {
+    let t = setup();
+    let right = commit(&t, b"paris");
+    let wrong = commit(&t, b"lyon");
+
+    open_reveals(&t);
+    assert!(t
+        .contract
+        .reveal_answer(&t.quest_id, &right, &Bytes::from_slice(&t.env, b"paris")));
+    assert!(!t
+        .contract
+        .reveal_answer(&t.quest_id, &wrong, &Bytes::from_slice(&t.env, b"lyon")));
+
+    assert_eq!(
+        t.contract.get_submission(&t.quest_id, &right).status,
+        SubmissionStatus::Approved
+    );
+    assert_eq!(
+        t.contract.get_submission(&t.quest_id, &wrong).status,
+        SubmissionStatus::Rejected
+    );
+    assert_eq!(
+        t.contract
+            .pending_for_verifier(&t.contract.address, &0, &10)
+            .len(),
+        0
+    );
+
+    t.contract.claim_reward(&t.quest_id, &right);
+    assert_eq!(t.token.balance(&right), 100);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let t = setup();
+    let user = commit(&t, b"lyon");
+    open_reveals(&t);
+
+    // Changing the answer after seeing someone else's reveal does not help
+    assert_eq!(
+        t.contract
+            .try_reveal_answer(&t.quest_id, &user, &Bytes::from_slice(&t.env, b"paris")),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    // Nor does copying another participant's commitment
+    let copier = Address::generate(&t.env);
+    let copied = t.contract.get_submission(&t.quest_id, &user).proof_hash;
+    t.env.ledger().with_mut(|l| l.timestamp = 1500);
+    t.contract.submit_proof(&t.quest_id, &copier, &copied);
+    t.env.ledger().with_mut(|l| l.timestamp = 2000);
+    assert_eq!(
+        t.contract
+            .try_reveal_answer(&t.quest_id, &copier, &Bytes::from_slice(&t.env, b"lyon")),
+        Err(Ok(Error::InvalidProof))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Windows & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_commit_and_reveal_windows() {
+    let t = setup();
+    let user = commit(&t, b"paris");
+    let paris = Bytes::from_slice(&t.env, b"paris");
+
+    assert_eq!(
+        t.contract.try_reveal_answer(&t.quest_id, &user, &paris),
+        Err(Ok(Error::TimelockNotExpired))
+    );
+
+    // The salt cannot be published early, and no one else can publish it
+    assert_eq!(
+        t.contract
+            .try_publish_answer_salt(&t.quest_id, &t.creator, &salt(&t.env)),
+        Err(Ok(Error::TimelockNotExpired))
+    );
+    assert!(t
+        .contract
+        .get_quest_answer(&t.quest_id)
+        .unwrap()
+        .salt
+        .is_none());
+
+    t.env.ledger().with_mut(|l| l.timestamp = 2000);
+    let late = Address::generate(&t.env);
+    assert_eq!(
+        t.contract
+            .try_submit_proof(&t.quest_id, &late, &commitment(&t.env, &paris, &late)),
+        Err(Ok(Error::QuestExpired))
+    );
+
+    // Reveals wait for the salt
+    assert_eq!(
+        t.contract.try_reveal_answer(&t.quest_id, &user, &paris),
+        Err(Ok(Error::TimelockNotExpired))
+    );
+    assert_eq!(
+        t.contract
+            .try_publish_answer_salt(&t.quest_id, &late, &salt(&t.env)),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.contract
+        .publish_answer_salt(&t.quest_id, &t.creator, &salt(&t.env));
+    assert_eq!(
+        t.contract.get_quest_answer(&t.quest_id).unwrap().salt,
+        Some(salt(&t.env))
+    );
+    assert_eq!(
+        t.contract
+            .try_publish_answer_salt(&t.quest_id, &t.creator, &salt(&t.env)),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    t.env.ledger().with_mut(|l| l.timestamp = 5000);
+    assert_eq!(
+        t.contract.try_reveal_answer(&t.quest_id, &user, &paris),
+        Err(Ok(Error::QuestExpired))
+    );
+}
+
+#[test]
+fn test_register_answer_quest_validation() {
+    let t = setup();
+    let hash = answer_hash(&t.env, &salt(&t.env), &Bytes::from_slice(&t.env, b"42"));
+
+    for reveal_at in [1000u64, 5000] {
+        assert_eq!(
+            t.contract.try_register_answer_quest(
+                &symbol_short!("q2"),
+                &t.creator,
+                &t.token_address,
+                &100,
+                &5000,
+                &hash,
+                &reveal_at,
+            ),
+            Err(Ok(Error::DeadlineInPast))
+        );
+    }
+
+    let quest_id = symbol_short!("q2");
+    t.contract.register_answer_quest(
+        &quest_id,
+        &t.creator,
+        &t.token_address,
+        &100,
+        &5000,
+        &hash,
+        &3000,
+    );
+    assert_eq!(t.contract.get_quest(&quest_id).verifier, t.contract.address);
+
+    // Only the hash is stored until the creator publishes the salt
+    assert_eq!(
+        t.contract.get_quest_answer(&quest_id),
+        Some(AnswerConfig {
+            answer_hash: hash,
+            reveal_at: 3000,
+            salt: None,
+        })
+    );
+}
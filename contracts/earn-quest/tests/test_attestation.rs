@@ -0,0 +1,320 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{
+    AccessMode, AssetConfig, Attestation, DuplicateAction, Prerequisites, ProofPolicy, ProofScope,
+    SubmissionStatus,
+};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    verifier: Address,
+    token: token::Client<'a>,
+    quest_id: Symbol,
+    attestor: SigningKey,
+}
+
+/// Quest with 1000 in escrow and a registered attestor key.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let quest_id = symbol_short!("q1");
+    contract.register_quest(&quest_id, &creator, &token_address, &100, &verifier, &99999);
+    contract.deposit_escrow(&quest_id, &creator, &token_address, &1000);
+
+    let attestor = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, attestor.verifying_key().as_bytes());
+    contract.set_quest_attestor(&quest_id, &creator, &Some(public_key));
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        verifier,
+        token,
+        quest_id,
+        attestor,
+    }
+}
+
+fn payload(t: &TestEnv, submitter: &Address, amount: i128, nonce: u64) -> Attestation {
+    Attestation {
+        quest_id: t.quest_id.clone(),
+        submitter: submitter.clone(),
+        proof_hash: BytesN::from_array(&t.env, &[1u8; 32]),
+        amount,
+        expiry: 2000,
+        nonce,
+    }
+}
+
+fn sign(t: &TestEnv, key: &SigningKey, payload: &Attestation) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = payload.clone().to_xdr(&t.env).iter().collect();
+    BytesN::from_array(&t.env, &key.sign(&message).to_bytes())
+}
+
+// ══════════════════════════════════════════════════════════════
+// Claims
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_attestation_approves_and_pays() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let p = payload(&t, &user, 250, 1);
+
+    t.contract
+        .claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p));
+
+    assert_eq!(t.token.balance(&user), 250);
+    assert_eq!(t.contract.get_user_stats(&user).xp, 100);
+    assert_eq!(t.contract.get_quest(&t.quest_id).total_claims, 1);
+    assert_eq!(t.contract.get_escrow_balance(&t.quest_id), 750);
+    assert!(t.contract.is_attestation_nonce_used(&t.quest_id, &1));
+
+    let submission = t.contract.get_submission(&t.quest_id, &user);
+    assert_eq!(submission.status, SubmissionStatus::Paid);
+    assert_eq!(submission.proof_hash, p.proof_hash);
+
+    // A paid submitter cannot be paid again, even under a fresh nonce
+    let p2 = payload(&t, &user, 100, 2);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p2, &sign(&t, &t.attestor, &p2)),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert_eq!(t.token.balance(&user), 250);
+}
+
+#[test]
+fn test_attestation_settles_pending_submission() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(&t.quest_id, &user, &proof);
+
+    let p = payload(&t, &user, 100, 1);
+    t.contract
+        .claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p));
+
+    assert_eq!(
+        t.contract.get_submission(&t.quest_id, &user).status,
+        SubmissionStatus::Paid
+    );
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+    assert_eq!(
+        t.contract.try_claim_reward(&t.quest_id, &user),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert_eq!(t.token.balance(&user), 100);
+}
+
+#[test]
+fn test_rejected_submission_cannot_be_attested() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(&t.quest_id, &user, &proof);
+    t.contract
+        .reject_submission(&t.quest_id, &user, &t.verifier);
+
+    let p = payload(&t, &user, 100, 1);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(
+        t.contract.get_submission(&t.quest_id, &user).status,
+        SubmissionStatus::Rejected
+    );
+    assert_eq!(t.token.balance(&user), 0);
+}
+
+#[test]
+fn test_attestation_applies_submission_gating() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let p = payload(&t, &user, 100, 1);
+
+    // Access rules
+    t.contract
+        .set_access_mode(&t.quest_id, &t.creator, &AccessMode::Allowlist);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.contract
+        .set_access_mode(&t.quest_id, &t.creator, &AccessMode::Open);
+
+    // Prerequisites
+    t.contract.set_prerequisites(
+        &t.quest_id,
+        &t.creator,
+        &Some(Prerequisites {
+            quests: Vec::new(&t.env),
+            min_level: 5,
+            badges: Vec::new(&t.env),
+        }),
+    );
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::PrerequisiteNotMet))
+    );
+    t.contract.set_prerequisites(&t.quest_id, &t.creator, &None);
+
+    // Proof-hash uniqueness
+    t.contract.set_proof_policy(
+        &t.quest_id,
+        &t.creator,
+        &ProofPolicy {
+            scope: ProofScope::Quest,
+            on_duplicate: DuplicateAction::Reject,
+        },
+    );
+    let other = Address::generate(&t.env);
+    t.contract.submit_proof(&t.quest_id, &other, &p.proof_hash);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::DuplicateProof))
+    );
+    assert_eq!(t.token.balance(&user), 0);
+}
+
+#[test]
+fn test_replayed_and_expired_attestations_are_rejected() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let p = payload(&t, &user, 100, 1);
+    let sig = sign(&t, &t.attestor, &p);
+    t.contract.claim_with_attestation(&t.quest_id, &p, &sig);
+
+    assert_eq!(
+        t.contract.try_claim_with_attestation(&t.quest_id, &p, &sig),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+
+    let p2 = payload(&t, &user, 100, 2);
+    let sig2 = sign(&t, &t.attestor, &p2);
+    t.env.ledger().with_mut(|l| l.timestamp = 2001);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p2, &sig2),
+        Err(Ok(Error::InvalidProof))
+    );
+    assert_eq!(t.token.balance(&user), 100);
+}
+
+#[test]
+fn test_forged_or_misdirected_signatures_are_rejected() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let p = payload(&t, &user, 100, 1);
+
+    // Signed by a key that is not the quest's attestor
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+    assert!(t
+        .contract
+        .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &forger, &p))
+        .is_err());
+
+    // Valid signature over different terms
+    let mut inflated = p.clone();
+    inflated.amount = 1000;
+    assert!(t
+        .contract
+        .try_claim_with_attestation(&t.quest_id, &inflated, &sign(&t, &t.attestor, &p))
+        .is_err());
+
+    // Signed for another quest
+    let mut other = p.clone();
+    other.quest_id = symbol_short!("q2");
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &other, &sign(&t, &t.attestor, &other)),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    assert_eq!(t.token.balance(&user), 0);
+    assert!(!t.contract.is_attestation_nonce_used(&t.quest_id, &1));
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_attestor_registration_and_escrow_limit() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+
+    assert_eq!(
+        t.contract
+            .try_set_quest_attestor(&t.quest_id, &Address::generate(&t.env), &None),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let p = payload(&t, &user, 1001, 1);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+
+    // Without an attestor, attestations are not accepted
+    t.contract
+        .set_quest_attestor(&t.quest_id, &t.creator, &None);
+    assert_eq!(t.contract.get_quest_attestor(&t.quest_id), None);
+    let p = payload(&t, &user, 100, 1);
+    assert_eq!(
+        t.contract
+            .try_claim_with_attestation(&t.quest_id, &p, &sign(&t, &t.attestor, &p)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
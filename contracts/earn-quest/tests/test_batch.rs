@@ -1,10 +1,15 @@
 #![cfg(test)]
 
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
 
 extern crate earn_quest;
-use earn_quest::types::{BatchApprovalInput, BatchQuestInput};
+use earn_quest::types::{AssetConfig, BatchApprovalInput, BatchQuestInput};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 //================================================================================
@@ -30,11 +35,36 @@ fn setup_contract_and_token(
 
     token_admin_client.mint(&contract_id, &100_000);
 
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        &token_contract,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
     (contract_id, client, token_contract, token_client)
 }
 
-fn make_quest_input(
+/// Mint a quest's reward to its creator and lock it in escrow so the quest opens
+fn fund_quest(
     env: &Env,
+    client: &EarnQuestContractClient,
+    token: &Address,
+    quest_id: &Symbol,
+    creator: &Address,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, token).mint(creator, &amount);
+    client.deposit_escrow(quest_id, creator, token, &amount);
+}
+
+fn make_quest_input(
+    _env: &Env,
     id: &Symbol,
     reward_asset: &Address,
     reward_amount: i128,
@@ -99,18 +129,7 @@ fn test_register_quests_batch_success() {
 
     client.register_quests_batch(&creator, &quests);
 
-    // All three quests should exist (read via single register would fail if duplicate)
-    client.register_quest(
-        &symbol_short!("BQ1"),
-        &creator,
-        &token_contract,
-        &100,
-        &verifier,
-        &deadline,
-    );
-    // If we get here without panic, BQ1 was already registered - so we actually
-    // need to just verify we can't register again. So instead: register_quest
-    // for a new id would succeed; for BQ1 we expect QuestAlreadyExists.
+    // All three quests should exist: re-registering BQ1 must fail with QuestAlreadyExists.
     let res = client.try_register_quest(
         &symbol_short!("BQ1"),
         &creator,
@@ -153,19 +172,17 @@ fn test_register_quests_batch_emits_events() {
     client.register_quests_batch(&creator, &quests);
 
     let events = env.events().all();
-    let reg_events: Vec<_> = events
+    let reg_events = events
         .iter()
-        .filter(|e| {
-            let (topics, _): (soroban_sdk::Vec<soroban_sdk::RawVal>, _) =
-                (e.0.clone(), e.1.clone());
+        .filter(|(_, topics, _)| {
             let t0: Symbol = topics.get(0).unwrap().into_val(&env);
             t0 == symbol_short!("quest_reg")
         })
-        .collect();
+        .count();
     assert!(
-        reg_events.len() >= 2,
+        reg_events >= 2,
         "expected at least 2 quest_reg events, got {}",
-        reg_events.len()
+        reg_events
     );
 }
 
@@ -243,8 +260,12 @@ fn test_register_quests_batch_duplicate_in_batch_reverts() {
     assert!(res.is_err(), "duplicate id in batch should revert");
 
     // First quest should not be stored (entire batch reverted)
-    let res2 = client.try_register_quest(&id, &creator, &token_contract, &100, &verifier, &deadline);
-    assert!(res2.is_ok(), "quest DUP should not exist after reverted batch");
+    let res2 =
+        client.try_register_quest(&id, &creator, &token_contract, &100, &verifier, &deadline);
+    assert!(
+        res2.is_ok(),
+        "quest DUP should not exist after reverted batch"
+    );
 }
 
 //================================================================================
@@ -273,6 +294,14 @@ fn test_approve_submissions_batch_success() {
         &verifier,
         &deadline,
     );
+    fund_quest(
+        &env,
+        &client,
+        &token_contract,
+        &symbol_short!("AQ1"),
+        &creator,
+        100,
+    );
     client.register_quest(
         &symbol_short!("AQ2"),
         &creator,
@@ -281,6 +310,14 @@ fn test_approve_submissions_batch_success() {
         &verifier,
         &deadline,
     );
+    fund_quest(
+        &env,
+        &client,
+        &token_contract,
+        &symbol_short!("AQ2"),
+        &creator,
+        200,
+    );
 
     // Submit proof for both
     client.submit_proof(&symbol_short!("AQ1"), &submitter1, &proof);
@@ -320,6 +357,14 @@ fn test_approve_submissions_batch_emits_events() {
         &verifier,
         &deadline,
     );
+    fund_quest(
+        &env,
+        &client,
+        &token_contract,
+        &symbol_short!("AE1"),
+        &creator,
+        50,
+    );
     client.submit_proof(&symbol_short!("AE1"), &submitter, &proof);
 
     let mut submissions = Vec::new(&env);
@@ -327,17 +372,15 @@ fn test_approve_submissions_batch_emits_events() {
     client.approve_submissions_batch(&verifier, &submissions);
 
     let events = env.events().all();
-    let appr_events: Vec<_> = events
+    let appr_events = events
         .iter()
-        .filter(|e| {
-            let (topics, _): (soroban_sdk::Vec<soroban_sdk::RawVal>, _) =
-                (e.0.clone(), e.1.clone());
+        .filter(|(_, topics, _)| {
             let t0: Symbol = topics.get(0).unwrap().into_val(&env);
             t0 == symbol_short!("sub_appr")
         })
-        .collect();
+        .count();
     assert!(
-        appr_events.len() >= 1,
+        appr_events >= 1,
         "expected at least 1 submission_approved event"
     );
 }
@@ -346,6 +389,8 @@ fn test_approve_submissions_batch_emits_events() {
 fn test_approve_submissions_batch_size_limit_enforced() {
     let env = Env::default();
     env.mock_all_auths();
+    // Setup registers 51 quests; only the batch call itself is under test.
+    env.budget().reset_unlimited();
 
     let (_, client, token_contract, _) = setup_contract_and_token(&env);
     let creator = Address::generate(&env);
@@ -357,14 +402,8 @@ fn test_approve_submissions_batch_size_limit_enforced() {
     let mut submitters = Vec::new(&env);
     for i in 0u32..51 {
         let sym = Symbol::new(&env, &format!("LQ{:02}", i));
-        client.register_quest(
-            &sym,
-            &creator,
-            &token_contract,
-            &1,
-            &verifier,
-            &deadline,
-        );
+        client.register_quest(&sym, &creator, &token_contract, &1, &verifier, &deadline);
+        fund_quest(&env, &client, &token_contract, &sym, &creator, 1);
         let sub = Address::generate(&env);
         submitters.push_back(sub.clone());
         client.submit_proof(&sym, &sub, &proof);
@@ -373,10 +412,7 @@ fn test_approve_submissions_batch_size_limit_enforced() {
     let mut submissions = Vec::new(&env);
     for i in 0u32..51 {
         let sym = Symbol::new(&env, &format!("LQ{:02}", i));
-        submissions.push_back(make_approval_input(
-            &sym,
-            &submitters.get(i).unwrap(),
-        ));
+        submissions.push_back(make_approval_input(&sym, &submitters.get(i).unwrap()));
     }
 
     let res = client.try_approve_submissions_batch(&verifier, &submissions);
@@ -417,6 +453,14 @@ fn test_approve_submissions_batch_unauthorized_reverts() {
         &verifier,
         &deadline,
     );
+    fund_quest(
+        &env,
+        &client,
+        &token_contract,
+        &symbol_short!("UQ1"),
+        &creator,
+        50,
+    );
     client.submit_proof(&symbol_short!("UQ1"), &submitter, &proof);
 
     let mut submissions = Vec::new(&env);
@@ -477,5 +521,8 @@ fn test_batch_registration_same_state_as_single_calls() {
         &verifier,
         &deadline,
     );
-    assert!(res1.is_err() && res2.is_err(), "both quests should already exist");
+    assert!(
+        res1.is_err() && res2.is_err(),
+        "both quests should already exist"
+    );
 }
@@ -0,0 +1,285 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, BondConfig, SlashTarget};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    verifier: Address,
+    bond_token: token::Client<'a>,
+    bond_token_admin: token::StellarAssetClient<'a>,
+    quest_id: Symbol,
+    proof: BytesN<32>,
+}
+
+/// One funded quest requiring a bond of 50 in a separate token.
+fn setup(slash_to: SlashTarget) -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+    let bond_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    contract.initialize(&admin);
+    for asset in [&token_address, &bond_address] {
+        contract.set_asset_config(
+            &admin,
+            asset,
+            &AssetConfig {
+                allowed: true,
+                min_reward: 1,
+                max_reward: MAX_REWARD_AMOUNT,
+                decimals: 7,
+            },
+        );
+    }
+
+    let quest_id = symbol_short!("q1");
+    contract.register_quest(&quest_id, &creator, &token_address, &100, &verifier, &99999);
+    contract.deposit_escrow(&quest_id, &creator, &token_address, &1000);
+    contract.set_submission_bond(
+        &quest_id,
+        &creator,
+        &Some(BondConfig {
+            asset: bond_address.clone(),
+            amount: 50,
+            slash_to,
+        }),
+    );
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    TestEnv {
+        env: env.clone(),
+        contract,
+        creator,
+        verifier,
+        bond_token: token::Client::new(&env, &bond_address),
+        bond_token_admin: token::StellarAssetClient::new(&env, &bond_address),
+        quest_id,
+        proof,
+    }
+}
+
+/// A submitter holding 100 bond tokens who has submitted proof.
+fn bonded_submitter(t: &TestEnv) -> Address {
+    let submitter = Address::generate(&t.env);
+    t.bond_token_admin.mint(&submitter, &100);
+    t.contract.submit_proof(&t.quest_id, &submitter, &t.proof);
+    submitter
+}
+
+// ══════════════════════════════════════════════════════════════
+// Posting & refunds
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_bond_posted_on_submit_and_refunded_on_approval() {
+    let t = setup(SlashTarget::Creator);
+    let submitter = bonded_submitter(&t);
+
+    let topics = (
+        symbol_short!("bond_post"),
+        t.quest_id.clone(),
+        submitter.clone(),
+    )
+        .into_val(&t.env);
+    assert!(t.env.events().all().iter().any(|e| e.1 == topics));
+    assert_eq!(t.bond_token.balance(&submitter), 50);
+    assert_eq!(
+        t.contract.get_bond(&t.quest_id, &submitter).unwrap().amount,
+        50
+    );
+
+    // Resubmitting while pending does not post a second bond
+    t.contract.submit_proof(&t.quest_id, &submitter, &t.proof);
+    assert_eq!(t.bond_token.balance(&submitter), 50);
+
+    // Bonds are tracked separately from escrow
+    let report = t.contract.audit_solvency(&t.bond_token.address);
+    assert_eq!(report.bond_liability, 50);
+    assert_eq!(report.escrow_liability, 0);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(t.contract.get_escrow_balance(&t.quest_id), 1000);
+
+    t.contract
+        .approve_submission(&t.quest_id, &submitter, &t.verifier);
+    assert_eq!(t.bond_token.balance(&submitter), 100);
+    assert_eq!(t.contract.get_bond(&t.quest_id, &submitter), None);
+    assert_eq!(
+        t.contract
+            .audit_solvency(&t.bond_token.address)
+            .bond_liability,
+        0
+    );
+}
+
+#[test]
+fn test_clean_rejection_refunds_bond() {
+    let t = setup(SlashTarget::Creator);
+    let submitter = bonded_submitter(&t);
+
+    t.contract
+        .reject_submission(&t.quest_id, &submitter, &t.verifier);
+    assert_eq!(t.bond_token.balance(&submitter), 100);
+
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (
+            symbol_short!("sub_rej"),
+            t.quest_id.clone(),
+            submitter.clone()
+        )
+            .into_val(&t.env)
+    );
+
+    // Resubmitting after a rejection posts a fresh bond
+    t.contract.submit_proof(&t.quest_id, &submitter, &t.proof);
+    assert_eq!(t.bond_token.balance(&submitter), 50);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Slashing
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_spam_rejection_slashes_bond_to_creator() {
+    let t = setup(SlashTarget::Creator);
+    let submitter = bonded_submitter(&t);
+
+    t.contract
+        .reject_submission_as_spam(&t.quest_id, &submitter, &t.verifier);
+
+    let topics = (
+        symbol_short!("bond_slsh"),
+        t.quest_id.clone(),
+        submitter.clone(),
+    )
+        .into_val(&t.env);
+    assert!(t.env.events().all().iter().any(|e| e.1 == topics));
+    assert_eq!(t.bond_token.balance(&submitter), 50);
+    assert_eq!(t.bond_token.balance(&t.creator), 50);
+    assert_eq!(t.contract.get_bond(&t.quest_id, &submitter), None);
+}
+
+#[test]
+fn test_spam_rejection_slashes_bond_to_treasury() {
+    let t = setup(SlashTarget::Treasury);
+    let submitter = bonded_submitter(&t);
+
+    t.contract
+        .reject_submission_as_spam(&t.quest_id, &submitter, &t.verifier);
+
+    let report = t.contract.audit_solvency(&t.bond_token.address);
+    assert_eq!(report.bond_liability, 0);
+    assert_eq!(report.treasury_liability, 50);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(t.bond_token.balance(&submitter), 50);
+
+    // Clearing the requirement lets later submitters in without a bond
+    t.contract
+        .set_submission_bond(&t.quest_id, &t.creator, &None);
+    let other = Address::generate(&t.env);
+    t.contract.submit_proof(&t.quest_id, &other, &t.proof);
+    assert_eq!(t.contract.get_bond(&t.quest_id, &other), None);
+}
+
+#[test]
+fn test_unreviewed_bond_reclaimed_after_cancel() {
+    let t = setup(SlashTarget::Creator);
+    let submitter = bonded_submitter(&t);
+
+    assert_eq!(
+        t.contract.try_reclaim_bond(&t.quest_id, &submitter),
+        Err(Ok(Error::QuestNotTerminal))
+    );
+
+    t.contract.cancel_quest(&t.quest_id, &t.creator);
+    t.contract.reclaim_bond(&t.quest_id, &submitter);
+    assert_eq!(t.bond_token.balance(&submitter), 100);
+    assert_eq!(
+        t.contract.try_reclaim_bond(&t.quest_id, &submitter),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_only_creator_sets_positive_bond() {
+    let t = setup(SlashTarget::Creator);
+    let stranger = Address::generate(&t.env);
+    let config = BondConfig {
+        asset: t.bond_token.address.clone(),
+        amount: 10,
+        slash_to: SlashTarget::Treasury,
+    };
+
+    assert_eq!(
+        t.contract
+            .try_set_submission_bond(&t.quest_id, &stranger, &Some(config.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_submission_bond(
+            &t.quest_id,
+            &t.creator,
+            &Some(BondConfig {
+                amount: 0,
+                ..config.clone()
+            }),
+        ),
+        Err(Ok(Error::InvalidRewardAmount))
+    );
+    let unlisted = t
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&t.env))
+        .address();
+    assert_eq!(
+        t.contract.try_set_submission_bond(
+            &t.quest_id,
+            &t.creator,
+            &Some(BondConfig {
+                asset: unlisted,
+                ..config
+            }),
+        ),
+        Err(Ok(Error::InvalidAsset))
+    );
+    assert_eq!(
+        t.contract.get_submission_bond(&t.quest_id).unwrap().amount,
+        50
+    );
+
+    // Submitters without the bond cannot submit
+    let broke = Address::generate(&t.env);
+    assert_eq!(
+        t.contract.try_submit_proof(&t.quest_id, &broke, &t.proof),
+        Err(Ok(Error::TransferFailed))
+    );
+}
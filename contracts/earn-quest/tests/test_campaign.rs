@@ -0,0 +1,253 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, QuestStatus};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    owner: Address,
+    verifier: Address,
+    token_address: Address,
+    token: token::Client<'a>,
+    campaign_id: Symbol,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&owner, &100_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let campaign_id = symbol_short!("camp1");
+    contract.create_campaign(&campaign_id, &owner, &token_address);
+
+    TestEnv {
+        env,
+        contract,
+        owner,
+        verifier,
+        token_address,
+        token,
+        campaign_id,
+    }
+}
+
+fn register(t: &TestEnv, id: &Symbol) {
+    t.contract
+        .register_campaign_quest(&t.campaign_id, &t.owner, id, &1000, &t.verifier, &99999);
+}
+
+fn approve(t: &TestEnv, id: &Symbol) -> Address {
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(id, &user, &proof);
+    t.contract.approve_submission(id, &user, &t.verifier);
+    user
+}
+
+// ══════════════════════════════════════════════════════════════
+// Pool funding and draws
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_campaign_quests_draw_rewards_from_pool() {
+    let t = setup();
+    t.contract.deposit_campaign(&t.campaign_id, &t.owner, &5000);
+
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    register(&t, &q1);
+    register(&t, &q2);
+    assert_eq!(t.contract.get_quest(&q1).status, QuestStatus::Active);
+    assert_eq!(t.contract.get_quest(&q1).reward_asset, t.token_address);
+
+    let listed = t.contract.list_campaign_quests(&t.campaign_id, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(1).unwrap().id, q2);
+
+    // Approval moves one reward from the pool into the quest's escrow
+    let a = approve(&t, &q1);
+    approve(&t, &q2);
+    assert_eq!(t.contract.get_campaign_balance(&t.campaign_id), 3000);
+    assert_eq!(t.contract.get_escrow_balance(&q1), 1000);
+
+    t.contract.claim_reward(&q1, &a);
+    assert_eq!(t.token.balance(&a), 1000);
+    assert_eq!(t.contract.get_escrow_balance(&q1), 0);
+
+    let pool = t.contract.get_campaign_escrow(&t.campaign_id);
+    assert_eq!(pool.total_deposited, 5000);
+    assert_eq!(pool.total_paid_out, 2000);
+
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.escrow_liability, 4000);
+    assert_eq!(report.deficit, 0);
+    assert_eq!(report.surplus, 0);
+}
+
+#[test]
+fn test_pool_exhaustion_blocks_approval() {
+    let t = setup();
+    t.contract.deposit_campaign(&t.campaign_id, &t.owner, &1000);
+    let qid = symbol_short!("q1");
+    register(&t, &qid);
+    approve(&t, &qid);
+
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(&qid, &user, &proof);
+    assert_eq!(
+        t.contract.try_approve_submission(&qid, &user, &t.verifier),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Leftovers and withdrawal
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_leftovers_return_to_pool_and_owner_withdraws() {
+    let t = setup();
+    t.contract.deposit_campaign(&t.campaign_id, &t.owner, &5000);
+    let qid = symbol_short!("q1");
+    register(&t, &qid);
+    approve(&t, &qid);
+    approve(&t, &qid);
+    assert_eq!(t.contract.get_campaign_balance(&t.campaign_id), 3000);
+
+    // Cancelling returns the unclaimed rewards to the pool, not the creator
+    assert_eq!(t.contract.cancel_quest(&qid, &t.owner), 2000);
+    assert_eq!(t.contract.get_campaign_balance(&t.campaign_id), 5000);
+    assert_eq!(t.token.balance(&t.owner), 95_000);
+
+    assert_eq!(
+        t.contract
+            .withdraw_campaign_unclaimed(&t.campaign_id, &t.owner),
+        5000
+    );
+    assert_eq!(t.token.balance(&t.owner), 100_000);
+    assert!(!t.contract.get_campaign_escrow(&t.campaign_id).is_active);
+
+    // A closed campaign takes no new funds or quests
+    assert_eq!(
+        t.contract
+            .try_deposit_campaign(&t.campaign_id, &t.owner, &100),
+        Err(Ok(Error::EscrowInactive))
+    );
+    assert_eq!(
+        t.contract.try_register_campaign_quest(
+            &t.campaign_id,
+            &t.owner,
+            &symbol_short!("q2"),
+            &1000,
+            &t.verifier,
+            &99999,
+        ),
+        Err(Ok(Error::EscrowInactive))
+    );
+    assert_eq!(
+        t.contract
+            .try_withdraw_campaign_unclaimed(&t.campaign_id, &t.owner),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+    assert_eq!(
+        t.contract.audit_solvency(&t.token_address).escrow_liability,
+        0
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_campaign_managers_and_validation() {
+    let t = setup();
+    let manager = Address::generate(&t.env);
+    let qid = symbol_short!("q1");
+
+    assert_eq!(
+        t.contract
+            .try_create_campaign(&t.campaign_id, &manager, &t.token_address),
+        Err(Ok(Error::CampaignAlreadyExists))
+    );
+
+    // An empty pool cannot open quests
+    assert_eq!(
+        t.contract.try_register_campaign_quest(
+            &t.campaign_id,
+            &t.owner,
+            &qid,
+            &1000,
+            &t.verifier,
+            &99999,
+        ),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+    t.contract.deposit_campaign(&t.campaign_id, &t.owner, &2000);
+
+    assert_eq!(
+        t.contract.try_register_campaign_quest(
+            &t.campaign_id,
+            &manager,
+            &qid,
+            &1000,
+            &t.verifier,
+            &99999,
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.contract
+        .set_campaign_manager(&t.campaign_id, &t.owner, &manager, &true);
+    assert_eq!(t.contract.get_campaign(&t.campaign_id).managers.len(), 1);
+    t.contract
+        .register_campaign_quest(&t.campaign_id, &manager, &qid, &1000, &t.verifier, &99999);
+    assert_eq!(t.contract.get_quest(&qid).creator, manager);
+
+    // Campaign quests are funded only through their pool
+    assert_eq!(
+        t.contract
+            .try_deposit_escrow(&qid, &t.owner, &t.token_address, &1000),
+        Err(Ok(Error::FundedByCampaign))
+    );
+
+    assert_eq!(
+        t.contract.try_get_campaign(&symbol_short!("none")),
+        Err(Ok(Error::CampaignNotFound))
+    );
+}
@@ -0,0 +1,258 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, vec, Address, Env, IntoVal, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, BatchQuestInput, CreationDeposit};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    verifier: Address,
+    token: token::Client<'a>,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    TestEnv {
+        env: env.clone(),
+        contract,
+        admin,
+        creator,
+        verifier,
+        token: token::Client::new(&env, &token_address),
+    }
+}
+
+fn register(t: &TestEnv, id: &Symbol) {
+    t.contract
+        .register_quest(id, &t.creator, &t.token.address, &100, &t.verifier, &99999);
+}
+
+fn set_deposit(t: &TestEnv, amount: i128, refundable: bool) {
+    t.contract.set_creation_deposit(
+        &t.admin,
+        &Some(CreationDeposit {
+            asset: t.token.address.clone(),
+            amount,
+            refundable,
+        }),
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Creation deposits
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_refundable_deposit_returned_when_quest_settles() {
+    let t = setup();
+    set_deposit(&t, 25, true);
+
+    let id = symbol_short!("q1");
+    register(&t, &id);
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("crt_paid"), id.clone(), t.creator.clone()).into_val(&t.env)
+    );
+    assert_eq!(t.token.balance(&t.creator), 975);
+    assert_eq!(
+        t.contract.get_quest_creation_deposit(&id).unwrap().amount,
+        25
+    );
+
+    // Held deposits are a liability, not surplus
+    let report = t.contract.audit_solvency(&t.token.address);
+    assert_eq!(report.deposit_liability, 25);
+    assert_eq!(report.bond_liability, 0);
+    assert_eq!(report.surplus, 0);
+
+    t.contract
+        .deposit_escrow(&id, &t.creator, &t.token.address, &500);
+    t.contract.cancel_quest(&id, &t.creator);
+    assert_eq!(t.token.balance(&t.creator), 1000);
+    assert_eq!(t.contract.get_quest_creation_deposit(&id), None);
+    assert_eq!(
+        t.contract
+            .audit_solvency(&t.token.address)
+            .deposit_liability,
+        0
+    );
+}
+
+#[test]
+fn test_registration_fee_credited_to_treasury() {
+    let t = setup();
+    set_deposit(&t, 25, false);
+
+    let id = symbol_short!("q1");
+    register(&t, &id);
+    assert_eq!(t.contract.get_quest_creation_deposit(&id), None);
+    assert_eq!(
+        t.contract
+            .audit_solvency(&t.token.address)
+            .treasury_liability,
+        25
+    );
+
+    // Fees are not returned on cancel
+    t.contract.cancel_quest(&id, &t.creator);
+    assert_eq!(t.token.balance(&t.creator), 975);
+}
+
+#[test]
+fn test_unpaid_deposit_blocks_registration() {
+    let t = setup();
+    set_deposit(&t, 25, true);
+
+    let broke = Address::generate(&t.env);
+    assert_eq!(
+        t.contract.try_register_quest(
+            &symbol_short!("q1"),
+            &broke,
+            &t.token.address,
+            &100,
+            &t.verifier,
+            &99999,
+        ),
+        Err(Ok(Error::CreationDepositUnpaid))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Quotas
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_active_quest_cap_per_creator() {
+    let t = setup();
+    t.contract.set_max_active_quests(&t.admin, &2);
+    assert_eq!(t.contract.get_max_active_quests(), 2);
+
+    register(&t, &symbol_short!("q1"));
+    let batch = vec![
+        &t.env,
+        BatchQuestInput {
+            id: symbol_short!("q2"),
+            reward_asset: t.token.address.clone(),
+            reward_amount: 100,
+            verifier: t.verifier.clone(),
+            deadline: 99999,
+        },
+        BatchQuestInput {
+            id: symbol_short!("q3"),
+            reward_asset: t.token.address.clone(),
+            reward_amount: 100,
+            verifier: t.verifier.clone(),
+            deadline: 99999,
+        },
+    ];
+    assert_eq!(
+        t.contract.try_register_quests_batch(&t.creator, &batch),
+        Err(Ok(Error::ActiveQuestLimitReached))
+    );
+    register(&t, &symbol_short!("q2"));
+
+    // Other creators have their own quota
+    let other = Address::generate(&t.env);
+    t.contract.register_quest(
+        &symbol_short!("q4"),
+        &other,
+        &t.token.address,
+        &100,
+        &t.verifier,
+        &99999,
+    );
+
+    // Terminal quests free a slot
+    t.contract.cancel_quest(&symbol_short!("q1"), &t.creator);
+    register(&t, &symbol_short!("q3"));
+
+    t.contract.set_max_active_quests(&t.admin, &0);
+    register(&t, &symbol_short!("q5"));
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_only_admins_configure_creation_rules() {
+    let t = setup();
+    let deposit = CreationDeposit {
+        asset: t.token.address.clone(),
+        amount: 25,
+        refundable: true,
+    };
+
+    assert_eq!(
+        t.contract
+            .try_set_creation_deposit(&t.creator, &Some(deposit.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_max_active_quests(&t.creator, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_creation_deposit(
+            &t.admin,
+            &Some(CreationDeposit {
+                amount: 0,
+                ..deposit.clone()
+            })
+        ),
+        Err(Ok(Error::InvalidRewardAmount))
+    );
+    let unlisted = t
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&t.env))
+        .address();
+    assert_eq!(
+        t.contract.try_set_creation_deposit(
+            &t.admin,
+            &Some(CreationDeposit {
+                asset: unlisted,
+                ..deposit
+            })
+        ),
+        Err(Ok(Error::InvalidAsset))
+    );
+    assert_eq!(t.contract.get_creation_deposit(), None);
+}
@@ -1,8 +1,13 @@
 #![cfg(test)]
 
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
-
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{
+    AssetConfig, EscrowContribution, QuestStatus, RefundPolicy, SponsorPolicy,
+};
+use earn_quest::validation::{MAX_PRUNE_SUBMISSIONS, MAX_REWARD_AMOUNT};
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 // ──────────────────────────────────────────────
@@ -39,7 +44,9 @@ fn setup() -> TestEnv<'static> {
 
     // Create a test token
     let token_admin = Address::generate(&env);
-    let token_address = env.register_stellar_asset_contract(token_admin.clone());
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
     let token = token::Client::new(&env, &token_address);
     let token_admin_client = token::StellarAssetClient::new(&env, &token_address);
 
@@ -48,6 +55,16 @@ fn setup() -> TestEnv<'static> {
 
     // Initialize the contract
     contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
 
     TestEnv {
         env,
@@ -69,9 +86,9 @@ fn register_quest(t: &TestEnv, quest_id: &Symbol) {
         quest_id,
         &t.creator,
         &t.token_address,
-        &1000_i128,  // reward: 1000 per completion
+        &1000_i128, // reward: 1000 per completion
         &t.verifier,
-        &99999_u64,  // far-future deadline
+        &99999_u64, // far-future deadline
     );
 }
 
@@ -95,7 +112,8 @@ fn test_deposit_escrow() {
     assert_eq!(t.token.balance(&t.creator), 100_000);
 
     // Deposit 5000 into escrow
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // Creator should have 100_000 - 5_000 = 95_000
     assert_eq!(t.token.balance(&t.creator), 95_000);
@@ -112,7 +130,7 @@ fn test_deposit_escrow() {
     assert_eq!(info.total_deposited, 5_000);
     assert_eq!(info.total_paid_out, 0);
     assert_eq!(info.total_refunded, 0);
-    assert_eq!(info.is_active, true);
+    assert!(info.is_active);
 }
 
 // ══════════════════════════════════════════════════════════════
@@ -125,11 +143,13 @@ fn test_topup_escrow() {
     let qid = symbol_short!("q2");
     register_quest(&t, &qid);
 
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &3000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &3000);
     assert_eq!(t.contract.get_escrow_balance(&qid), 3_000);
 
     // Top up with 2000 more
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &2000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &2000);
     assert_eq!(t.contract.get_escrow_balance(&qid), 5_000);
 
     let info = t.contract.get_escrow_info(&qid);
@@ -147,7 +167,8 @@ fn test_payout_deducts_escrow() {
     register_quest(&t, &qid);
 
     // Deposit enough for at least 1 payout (reward is 1000)
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // User submits, verifier approves, user claims
     submit_proof(&t, &qid, &t.user_a);
@@ -173,7 +194,8 @@ fn test_multiple_payouts() {
     let t = setup();
     let qid = symbol_short!("q4");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // User A
     submit_proof(&t, &qid, &t.user_a);
@@ -203,14 +225,20 @@ fn test_insufficient_escrow_blocks_approval() {
     let qid = symbol_short!("q5");
     register_quest(&t, &qid);
 
-    // Deposit only 500, but reward is 1000 per completion
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &500);
+    // Fund exactly one completion
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
 
     submit_proof(&t, &qid, &t.user_a);
+    submit_proof(&t, &qid, &t.user_b);
+    t.contract.approve_submission(&qid, &t.user_a, &t.verifier);
+    t.contract.claim_reward(&qid, &t.user_a);
 
-    // Try to approve — should fail because escrow (500) < reward (1000)
-    let result = t.contract.try_approve_submission(&qid, &t.user_a, &t.verifier);
-    assert!(result.is_err());
+    // Try to approve — should fail because escrow (0) < reward (1000)
+    let result = t
+        .contract
+        .try_approve_submission(&qid, &t.user_b, &t.verifier);
+    assert_eq!(result, Err(Ok(Error::InsufficientEscrow)));
 }
 
 // ══════════════════════════════════════════════════════════════
@@ -222,7 +250,8 @@ fn test_cancel_quest_refunds_escrow() {
     let t = setup();
     let qid = symbol_short!("q6");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // One payout first
     submit_proof(&t, &qid, &t.user_a);
@@ -243,7 +272,7 @@ fn test_cancel_quest_refunds_escrow() {
     assert_eq!(t.contract.get_escrow_balance(&qid), 0);
 
     let info = t.contract.get_escrow_info(&qid);
-    assert_eq!(info.is_active, false);
+    assert!(!info.is_active);
     assert_eq!(info.total_refunded, 4_000);
 }
 
@@ -258,7 +287,9 @@ fn test_stranger_cannot_deposit() {
     register_quest(&t, &qid);
 
     // User A tries to deposit — not the creator
-    let result = t.contract.try_deposit_escrow(&qid, &t.user_a, &t.token_address, &1000);
+    let result = t
+        .contract
+        .try_deposit_escrow(&qid, &t.user_a, &t.token_address, &1000);
     assert!(result.is_err());
 }
 
@@ -271,7 +302,8 @@ fn test_stranger_cannot_cancel() {
     let t = setup();
     let qid = symbol_short!("q8");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     let result = t.contract.try_cancel_quest(&qid, &t.user_a);
     assert!(result.is_err());
@@ -293,10 +325,11 @@ fn test_withdraw_unclaimed_after_expiry() {
         &t.token_address,
         &1000_i128,
         &t.verifier,
-        &100_u64,  // expires at timestamp 100
+        &100_u64, // expires at timestamp 100
     );
 
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // We need to set quest status to Expired for withdraw to work.
     // In a real scenario, an admin or cron job would update this.
@@ -315,12 +348,15 @@ fn test_cannot_deposit_to_cancelled_quest() {
     let t = setup();
     let qid = symbol_short!("q10");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     t.contract.cancel_quest(&qid, &t.creator);
 
     // Try to deposit after cancel — should fail
-    let result = t.contract.try_deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+    let result = t
+        .contract
+        .try_deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
     assert!(result.is_err());
 }
 
@@ -333,7 +369,8 @@ fn test_cannot_withdraw_from_active_quest() {
     let t = setup();
     let qid = symbol_short!("q11");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // Quest is Active — withdraw_unclaimed should fail
     let result = t.contract.try_withdraw_unclaimed(&qid, &t.creator);
@@ -349,7 +386,8 @@ fn test_double_withdrawal_prevented() {
     let t = setup();
     let qid = symbol_short!("q12");
     register_quest(&t, &qid);
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
 
     // Cancel → refunds everything
     t.contract.cancel_quest(&qid, &t.creator);
@@ -360,25 +398,43 @@ fn test_double_withdrawal_prevented() {
 }
 
 // ══════════════════════════════════════════════════════════════
-// TEST 13: Quest without escrow works (backward compatibility)
+// TEST 13: Quests stay closed until their escrow is funded
 // ══════════════════════════════════════════════════════════════
 
 #[test]
-fn test_quest_without_escrow_backward_compat() {
+fn test_quest_opens_only_when_funded() {
     let t = setup();
     let qid = symbol_short!("q13");
     register_quest(&t, &qid);
+    assert_eq!(t.contract.get_quest(&qid).status, QuestStatus::Unfunded);
 
-    // DON'T deposit escrow — old-style quest
-    // Manually fund the contract so transfer_reward succeeds
+    // Loose contract balance no longer backs unescrowed quests
     t.token_admin_client.mint(&t.contract.address, &10_000);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    assert_eq!(
+        t.contract.try_submit_proof(&qid, &t.user_a, &proof),
+        Err(Ok(Error::QuestNotActive))
+    );
+
+    // Require two completions' worth before opening
+    t.contract.set_funding_requirement(&t.admin, &2);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1500);
+    assert_eq!(t.contract.get_quest(&qid).status, QuestStatus::Unfunded);
+
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &500);
+    assert_eq!(t.contract.get_quest(&qid).status, QuestStatus::Active);
 
-    // Should work without escrow
     submit_proof(&t, &qid, &t.user_a);
     t.contract.approve_submission(&qid, &t.user_a, &t.verifier);
     t.contract.claim_reward(&qid, &t.user_a);
-
     assert_eq!(t.token.balance(&t.user_a), 1_000);
+
+    assert_eq!(
+        t.contract.try_set_funding_requirement(&t.admin, &0),
+        Err(Ok(Error::InvalidFundingRequirement))
+    );
 }
 
 // ══════════════════════════════════════════════════════════════
@@ -392,7 +448,8 @@ fn test_full_lifecycle() {
     register_quest(&t, &qid);
 
     // 1. Deposit escrow for 3 completions (3 × 1000 = 3000)
-    t.contract.deposit_escrow(&qid, &t.creator, &t.token_address, &3000);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &3000);
     assert_eq!(t.contract.get_escrow_balance(&qid), 3_000);
     assert_eq!(t.token.balance(&t.creator), 97_000);
 
@@ -420,7 +477,7 @@ fn test_full_lifecycle() {
     assert_eq!(info.total_deposited, 3_000);
     assert_eq!(info.total_paid_out, 2_000);
     assert_eq!(info.total_refunded, 1_000);
-    assert_eq!(info.is_active, false);
+    assert!(!info.is_active);
     assert_eq!(t.contract.get_escrow_balance(&qid), 0);
 }
 
@@ -434,7 +491,9 @@ fn test_zero_deposit_rejected() {
     let qid = symbol_short!("q15");
     register_quest(&t, &qid);
 
-    let result = t.contract.try_deposit_escrow(&qid, &t.creator, &t.token_address, &0);
+    let result = t
+        .contract
+        .try_deposit_escrow(&qid, &t.creator, &t.token_address, &0);
     assert!(result.is_err());
 }
 
@@ -450,11 +509,370 @@ fn test_wrong_token_rejected() {
 
     // Create a different token
     let other_admin = Address::generate(&t.env);
-    let other_token = t.env.register_stellar_asset_contract(other_admin.clone());
+    let other_token = t
+        .env
+        .register_stellar_asset_contract_v2(other_admin.clone())
+        .address();
     let other_admin_client = token::StellarAssetClient::new(&t.env, &other_token);
     other_admin_client.mint(&t.creator, &10_000);
 
     // Try to deposit the wrong token
-    let result = t.contract.try_deposit_escrow(&qid, &t.creator, &other_token, &1000);
+    let result = t
+        .contract
+        .try_deposit_escrow(&qid, &t.creator, &other_token, &1000);
     assert!(result.is_err());
-}
\ No newline at end of file
+}
+// ══════════════════════════════════════════════════════════════
+// TEST 17: Deposit blocked once the asset is disallowed
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_deposit_rejected_for_disallowed_asset() {
+    let t = setup();
+    let qid = symbol_short!("q17");
+    register_quest(&t, &qid);
+
+    t.contract.set_asset_config(
+        &t.admin,
+        &t.token_address,
+        &AssetConfig {
+            allowed: false,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let result = t
+        .contract
+        .try_deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+    assert_eq!(t.token.balance(&t.creator), 100_000);
+}
+
+// ══════════════════════════════════════════════════════════════
+// TEST 18: Prune a cancelled quest after the retention period
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_prune_cancelled_quest_after_retention() {
+    let t = setup();
+    let qid = symbol_short!("q18");
+    register_quest(&t, &qid);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+
+    submit_proof(&t, &qid, &t.user_a);
+    t.contract.approve_submission(&qid, &t.user_a, &t.verifier);
+    t.contract.claim_reward(&qid, &t.user_a);
+    submit_proof(&t, &qid, &t.user_b);
+
+    // Active quests cannot be pruned
+    assert_eq!(
+        t.contract.try_prune_quest(&qid),
+        Err(Ok(Error::QuestNotTerminal))
+    );
+
+    t.contract.cancel_quest(&qid, &t.creator);
+    assert_eq!(
+        t.contract.try_prune_quest(&qid),
+        Err(Ok(Error::RetentionPeriodActive))
+    );
+
+    let retention = t.contract.get_retention_period();
+    t.env.ledger().with_mut(|l| l.timestamp += retention);
+
+    assert!(t.contract.prune_quest(&qid));
+
+    let (_, topics, data) = t.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+    assert_eq!(name, symbol_short!("q_archive"));
+    let summary: (u32, i128, i128) = data.into_val(&t.env);
+    assert_eq!(summary, (1, 1000, 4000));
+
+    assert_eq!(
+        t.contract.try_get_quest(&qid),
+        Err(Ok(Error::QuestNotFound))
+    );
+    assert_eq!(
+        t.contract.try_get_submission(&qid, &t.user_a),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+    assert_eq!(
+        t.contract.try_get_escrow_info(&qid),
+        Err(Ok(Error::EscrowNotFound))
+    );
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+    assert_eq!(
+        t.contract
+            .list_quests_by_status(&earn_quest::types::QuestStatus::Cancelled, &0, &10)
+            .len(),
+        0
+    );
+    assert_eq!(t.contract.list_quests(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_pruned_quest_id_cannot_be_registered_again() {
+    let t = setup();
+    let qid = symbol_short!("q18");
+    register_quest(&t, &qid);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+    submit_proof(&t, &qid, &t.user_a);
+
+    t.contract.cancel_quest(&qid, &t.creator);
+    t.contract.set_retention_period(&t.admin, &0);
+    assert!(t.contract.prune_quest(&qid));
+
+    // Records keyed by the old id (participants, nonces, proof uses) must not
+    // carry over to a new quest
+    assert_eq!(
+        t.contract.try_register_quest(
+            &qid,
+            &t.creator,
+            &t.token_address,
+            &1000,
+            &t.verifier,
+            &99999
+        ),
+        Err(Ok(Error::QuestAlreadyExists))
+    );
+    assert_eq!(
+        t.contract.try_get_quest(&qid),
+        Err(Ok(Error::QuestNotFound))
+    );
+}
+
+#[test]
+fn test_pruned_quest_is_dropped_from_listings() {
+    let t = setup();
+    let pruned = symbol_short!("q19");
+    let kept = symbol_short!("q20");
+    register_quest(&t, &pruned);
+    register_quest(&t, &kept);
+    for qid in [&pruned, &kept] {
+        t.contract
+            .deposit_escrow(qid, &t.creator, &t.token_address, &1000);
+    }
+    submit_proof(&t, &pruned, &t.user_a);
+    t.contract
+        .submit_proof(&kept, &t.user_a, &BytesN::from_array(&t.env, &[2u8; 32]));
+
+    t.contract.cancel_quest(&pruned, &t.creator);
+    t.contract.set_retention_period(&t.admin, &0);
+    assert!(t.contract.prune_quest(&pruned));
+
+    let by_creator = t.contract.list_quests_by_creator(&t.creator, &0, &10);
+    assert_eq!(by_creator.len(), 1);
+    assert_eq!(by_creator.get(0).unwrap().id, kept);
+
+    let by_verifier = t.contract.list_quests_by_verifier(&t.verifier, &0, &10);
+    assert_eq!(by_verifier.len(), 1);
+    assert_eq!(t.contract.list_quests(&0, &10).len(), 1);
+    assert_eq!(
+        t.contract
+            .list_quests_by_status(&QuestStatus::Cancelled, &0, &10)
+            .len(),
+        0
+    );
+
+    let history = t.contract.get_user_history(&t.user_a, &0, &10);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().quest_id, kept);
+}
+
+// ══════════════════════════════════════════════════════════════
+// TEST 19: Large quests are pruned across several calls
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_prune_quest_in_chunks() {
+    let t = setup();
+    t.env.budget().reset_unlimited();
+    let qid = symbol_short!("q19");
+    register_quest(&t, &qid);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+
+    for _ in 0..(MAX_PRUNE_SUBMISSIONS + 1) {
+        submit_proof(&t, &qid, &Address::generate(&t.env));
+    }
+
+    t.contract.cancel_quest(&qid, &t.creator);
+    t.contract.set_retention_period(&t.admin, &0);
+
+    assert!(!t.contract.prune_quest(&qid));
+    assert_eq!(t.contract.list_submissions(&qid, &None, &0, &10).len(), 1);
+    assert!(t.contract.prune_quest(&qid));
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// TEST 20: Sponsors co-fund and are refunded pro rata
+// ══════════════════════════════════════════════════════════════
+
+/// Helper: mint tokens to a new sponsor and deposit them into a quest
+fn sponsor(t: &TestEnv, quest_id: &Symbol, amount: i128) -> Address {
+    let sponsor = Address::generate(&t.env);
+    t.token_admin_client.mint(&sponsor, &amount);
+    t.contract
+        .deposit_escrow(quest_id, &sponsor, &t.token_address, &amount);
+    sponsor
+}
+
+#[test]
+fn test_sponsor_deposits_refunded_pro_rata() {
+    let t = setup();
+    let qid = symbol_short!("q20");
+    register_quest(&t, &qid);
+
+    // A sponsor alone can open the quest
+    let dao = sponsor(&t, &qid, 2000);
+    assert_eq!(t.contract.get_quest(&qid).status, QuestStatus::Active);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+
+    let contributions = t.contract.get_escrow_contributions(&qid);
+    assert_eq!(
+        contributions,
+        soroban_sdk::vec![
+            &t.env,
+            EscrowContribution {
+                sponsor: dao.clone(),
+                amount: 2000
+            },
+            EscrowContribution {
+                sponsor: t.creator.clone(),
+                amount: 1000
+            },
+        ]
+    );
+    assert_eq!(t.contract.get_escrow_info(&qid).depositor, t.creator);
+
+    submit_proof(&t, &qid, &t.user_a);
+    t.contract.approve_submission(&qid, &t.user_a, &t.verifier);
+    t.contract.claim_reward(&qid, &t.user_a);
+
+    // 2000 left: dao 1333 plus 1 rounding dust as the earliest depositor, creator 666
+    assert_eq!(t.contract.cancel_quest(&qid, &t.creator), 2000);
+    assert_eq!(t.token.balance(&dao), 1334);
+    assert_eq!(t.token.balance(&t.creator), 100_000 - 1000 + 666);
+
+    let refunds = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().into_val(&t.env);
+            name == symbol_short!("esc_ref")
+        })
+        .count();
+    assert_eq!(refunds, 2);
+}
+
+// ══════════════════════════════════════════════════════════════
+// TEST 21: Last-in-first-out refunds repay the latest depositor first
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_lifo_refund_repays_latest_depositor_first() {
+    let t = setup();
+    let qid = symbol_short!("q21");
+    register_quest(&t, &qid);
+    t.contract.set_sponsor_policy(
+        &qid,
+        &t.creator,
+        &SponsorPolicy {
+            allowlist_only: false,
+            refund_policy: RefundPolicy::Lifo,
+        },
+    );
+
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+    let early = sponsor(&t, &qid, 1000);
+    let late = sponsor(&t, &qid, 1000);
+
+    submit_proof(&t, &qid, &t.user_a);
+    t.contract.approve_submission(&qid, &t.user_a, &t.verifier);
+    t.contract.claim_reward(&qid, &t.user_a);
+    t.contract.cancel_quest(&qid, &t.creator);
+
+    // 2000 left: late and early are repaid in full, the creator absorbs the payout
+    assert_eq!(t.token.balance(&late), 1000);
+    assert_eq!(t.token.balance(&early), 1000);
+    assert_eq!(t.token.balance(&t.creator), 100_000 - 1000);
+}
+
+// ══════════════════════════════════════════════════════════════
+// TEST 22: Sponsor allowlist and refund policy lock
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_sponsor_allowlist_and_policy_lock() {
+    let t = setup();
+    let qid = symbol_short!("q22");
+    register_quest(&t, &qid);
+
+    let restricted = SponsorPolicy {
+        allowlist_only: true,
+        refund_policy: RefundPolicy::ProRata,
+    };
+    assert_eq!(
+        t.contract
+            .try_set_sponsor_policy(&qid, &t.user_a, &restricted),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.contract.set_sponsor_policy(&qid, &t.creator, &restricted);
+    assert_eq!(t.contract.get_sponsor_policy(&qid), restricted);
+
+    let outsider = Address::generate(&t.env);
+    t.token_admin_client.mint(&outsider, &1000);
+    assert_eq!(
+        t.contract
+            .try_deposit_escrow(&qid, &outsider, &t.token_address, &1000),
+        Err(Ok(Error::SponsorNotAllowed))
+    );
+
+    t.contract
+        .set_sponsor_allowed(&qid, &t.creator, &outsider, &true);
+    assert_eq!(
+        t.contract.get_sponsor_allowlist(&qid),
+        soroban_sdk::vec![&t.env, outsider.clone()]
+    );
+    t.contract
+        .deposit_escrow(&qid, &outsider, &t.token_address, &1000);
+
+    // Sponsors keep the refund terms they funded under
+    assert_eq!(
+        t.contract.try_set_sponsor_policy(
+            &qid,
+            &t.creator,
+            &SponsorPolicy {
+                allowlist_only: true,
+                refund_policy: RefundPolicy::Lifo,
+            },
+        ),
+        Err(Ok(Error::RefundPolicyLocked))
+    );
+
+    // Opening sponsorship back up is still allowed
+    t.contract.set_sponsor_policy(
+        &qid,
+        &t.creator,
+        &SponsorPolicy {
+            allowlist_only: false,
+            refund_policy: RefundPolicy::ProRata,
+        },
+    );
+    sponsor(&t, &qid, 500);
+    assert_eq!(t.contract.get_escrow_contributions(&qid).len(), 2);
+}
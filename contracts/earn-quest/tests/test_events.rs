@@ -1,10 +1,30 @@
 #![cfg(test)]
 
 extern crate earn_quest;
+use earn_quest::types::AssetConfig;
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
-use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, symbol_short, testutils::{Address as _, Events}};
 use soroban_sdk::token::StellarAssetClient;
-
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Env, IntoVal, Symbol,
+};
+
+fn allow_asset(env: &Env, client: &EarnQuestContractClient, asset: &Address) {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        asset,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+}
 #[test]
 fn test_full_quest_lifecycle_events() {
     let env = Env::default();
@@ -18,11 +38,8 @@ fn test_full_quest_lifecycle_events() {
     let admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_address = token_contract_obj.address();
+    allow_asset(&env, &client, &token_address);
     let token_admin_client = StellarAssetClient::new(&env, &token_address);
-    
-    // Fund the contract so it can pay out rewards later
-    let fund_amount = 1000i128;
-    token_admin_client.mint(&contract_id, &fund_amount);
 
     // 3. Test Data
     let creator = Address::generate(&env);
@@ -45,7 +62,7 @@ fn test_full_quest_lifecycle_events() {
     // Verify Register Event
     let events = env.events().all();
     let (contract, topics, data) = events.last().unwrap();
-    
+
     assert_eq!(contract, contract_id);
 
     // Topics: [EventName, QuestID, Creator]
@@ -56,14 +73,34 @@ fn test_full_quest_lifecycle_events() {
     assert_eq!(t_name, symbol_short!("quest_reg"));
     assert_eq!(t_id, quest_id);
     assert_eq!(t_creator, creator);
-    
+
     // Verify Data: (reward_asset, reward_amount, verifier, deadline)
-    let (asset_data, amount_data, verifier_data, deadline_data): (Address, i128, Address, u64) = data.into_val(&env);
+    let (asset_data, amount_data, verifier_data, deadline_data): (Address, i128, Address, u64) =
+        data.into_val(&env);
     assert_eq!(asset_data, token_address);
     assert_eq!(amount_data, reward_amount);
     assert_eq!(verifier_data, verifier);
     assert_eq!(deadline_data, deadline);
 
+    // --- STEP 1b: FUND ESCROW (opens the quest) ---
+    let fund_amount = 1000i128;
+    token_admin_client.mint(&creator, &fund_amount);
+    client.deposit_escrow(&quest_id, &creator, &token_address, &fund_amount);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+
+    // Topics: [EventName, QuestID]
+    let t_name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let t_id: Symbol = topics.get(1).unwrap().into_val(&env);
+
+    assert_eq!(t_name, symbol_short!("q_funded"));
+    assert_eq!(t_id, quest_id);
+
+    // Verify Data: (escrow_balance,)
+    let (escrow_balance,): (i128,) = data.into_val(&env);
+    assert_eq!(escrow_balance, fund_amount);
+
     // --- STEP 2: SUBMIT PROOF ---
     let proof_hash = BytesN::from_array(&env, &[0u8; 32]);
     client.submit_proof(&quest_id, &user, &proof_hash);
@@ -79,7 +116,7 @@ fn test_full_quest_lifecycle_events() {
     assert_eq!(t_name, symbol_short!("proof_sub"));
     assert_eq!(t_id, quest_id);
     assert_eq!(t_sub, user);
-    
+
     // --- STEP 3: APPROVE SUBMISSION ---
     client.approve_submission(&quest_id, &user, &verifier);
 
@@ -99,7 +136,7 @@ fn test_full_quest_lifecycle_events() {
     client.claim_reward(&quest_id, &user);
 
     let events = env.events().all();
-    
+
     // After claim_reward, we expect 2 events: reward_claimed and xp_awarded
     // Get the second-to-last event (reward_claimed)
     let event_count = events.len();
@@ -118,18 +155,18 @@ fn test_full_quest_lifecycle_events() {
     let (claimed_asset, claimed_amount): (Address, i128) = data.into_val(&env);
     assert_eq!(claimed_asset, token_address);
     assert_eq!(claimed_amount, reward_amount);
-    
+
     // Verify the XP awarded event (last event)
     let (_, topics, data) = events.last().unwrap();
     let t_name: Symbol = topics.get(0).unwrap().into_val(&env);
     let t_user: Address = topics.get(1).unwrap().into_val(&env);
-    
+
     assert_eq!(t_name, symbol_short!("xp_award"));
     assert_eq!(t_user, user);
-    
+
     // Verify XP data: (xp_amount, total_xp, level)
     let (xp_amount, total_xp, level): (u64, u64, u32) = data.into_val(&env);
     assert_eq!(xp_amount, 100);
     assert_eq!(total_xp, 100);
     assert_eq!(level, 1);
-}
\ No newline at end of file
+}
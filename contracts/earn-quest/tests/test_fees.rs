@@ -0,0 +1,273 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, Symbol, TryFromVal};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, FeeMode};
+use earn_quest::validation::{MAX_FEE_BPS, MAX_REWARD_AMOUNT};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    verifier: Address,
+    user: Address,
+    token_address: Address,
+    token: token::Client<'a>,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &100_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    TestEnv {
+        env,
+        contract,
+        admin,
+        creator,
+        verifier,
+        user,
+        token_address,
+        token,
+    }
+}
+
+/// Helper: register a quest paying 1000 and fund it with `deposit`
+fn funded_quest(t: &TestEnv, quest_id: &Symbol, deposit: i128) {
+    t.contract.register_quest(
+        quest_id,
+        &t.creator,
+        &t.token_address,
+        &1000_i128,
+        &t.verifier,
+        &99999_u64,
+    );
+    t.contract
+        .deposit_escrow(quest_id, &t.creator, &t.token_address, &deposit);
+}
+
+/// Helper: take a user from submission to claimed reward
+fn complete(t: &TestEnv, quest_id: &Symbol, user: &Address) {
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(quest_id, user, &proof);
+    t.contract.approve_submission(quest_id, user, &t.verifier);
+    t.contract.claim_reward(quest_id, user);
+}
+
+#[test]
+fn test_no_fee_by_default() {
+    let t = setup();
+    let qid = symbol_short!("f1");
+    funded_quest(&t, &qid, 5000);
+    complete(&t, &qid, &t.user);
+
+    assert_eq!(t.token.balance(&t.user), 1000);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 0);
+}
+
+#[test]
+fn test_fee_on_payout() {
+    let t = setup();
+    let qid = symbol_short!("f2");
+    t.contract
+        .set_fee_config(&t.admin, &500u32, &FeeMode::OnPayout);
+    funded_quest(&t, &qid, 5000);
+    complete(&t, &qid, &t.user);
+
+    // 5% of 1000 withheld
+    assert_eq!(t.token.balance(&t.user), 950);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 50);
+
+    let info = t.contract.get_escrow_info(&qid);
+    assert_eq!(info.total_paid_out, 950);
+    assert_eq!(info.total_fees, 50);
+    assert_eq!(t.contract.get_escrow_balance(&qid), 4000);
+}
+
+#[test]
+fn test_fee_on_deposit_with_asset_override() {
+    let t = setup();
+    let qid = symbol_short!("f3");
+    t.contract
+        .set_fee_config(&t.admin, &500u32, &FeeMode::OnDeposit);
+    t.contract
+        .set_asset_fee(&t.admin, &t.token_address, &Some(200u32));
+    assert_eq!(t.contract.get_asset_fee(&t.token_address), 200);
+
+    funded_quest(&t, &qid, 10_000);
+
+    // 2% override applies instead of the 5% global rate
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 200);
+    assert_eq!(t.contract.get_escrow_balance(&qid), 9800);
+
+    // No second fee at payout time
+    complete(&t, &qid, &t.user);
+    assert_eq!(t.token.balance(&t.user), 1000);
+    assert_eq!(t.contract.get_escrow_balance(&qid), 8800);
+
+    // Fees are not refunded to the creator
+    t.contract.cancel_quest(&qid, &t.creator);
+    assert_eq!(t.token.balance(&t.creator), 100_000 - 10_000 + 8800);
+}
+
+#[test]
+fn test_fee_mode_fixed_per_escrow() {
+    let t = setup();
+    let qid = symbol_short!("f5");
+    t.contract
+        .set_fee_config(&t.admin, &1000u32, &FeeMode::OnDeposit);
+    let topics = t.env.events().all().last().unwrap().1;
+    assert_eq!(
+        topics,
+        (symbol_short!("fee_cfg"), t.admin.clone()).into_val(&t.env)
+    );
+    let data = t.env.events().all().last().unwrap().2;
+    assert_eq!(
+        <(Option<Address>, Option<u32>, Option<FeeMode>)>::try_from_val(&t.env, &data).unwrap(),
+        (None, Some(1000), Some(FeeMode::OnDeposit))
+    );
+    funded_quest(&t, &qid, 5000);
+
+    // Switching the global mode does not charge the existing escrow again
+    t.contract
+        .set_fee_config(&t.admin, &1000u32, &FeeMode::OnPayout);
+    complete(&t, &qid, &t.user);
+    assert_eq!(t.token.balance(&t.user), 1000);
+    assert_eq!(
+        t.contract.get_escrow_info(&qid).fee_mode,
+        FeeMode::OnDeposit
+    );
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 500);
+
+    // Top-ups keep the escrow's mode as well
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &1000);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 600);
+}
+
+#[test]
+fn test_withdraw_fees_treasurer_only() {
+    let t = setup();
+    let qid = symbol_short!("f4");
+    let other_admin = Address::generate(&t.env);
+    let treasurer = Address::generate(&t.env);
+    let dest = Address::generate(&t.env);
+    t.contract.add_admin(&t.admin, &other_admin);
+    t.contract.approve_add_admin(&t.admin);
+    t.contract.execute_add_admin(&t.admin);
+
+    t.contract
+        .set_fee_config(&t.admin, &1000u32, &FeeMode::OnDeposit);
+    funded_quest(&t, &qid, 5000);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 500);
+
+    // Treasurer must be an admin
+    let res = t.contract.try_propose_treasurer(&t.admin, &treasurer);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    // Appointing a Treasurer needs the unpause threshold of admin approvals
+    t.contract.add_admin(&t.admin, &treasurer);
+    t.contract.approve_add_admin(&t.admin);
+    t.contract.approve_add_admin(&other_admin);
+    t.contract.execute_add_admin(&t.admin);
+    t.contract.propose_treasurer(&t.admin, &treasurer);
+    t.contract.approve_treasurer(&treasurer);
+    let res = t.contract.try_execute_treasurer(&treasurer);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+    t.contract.approve_treasurer(&other_admin);
+    t.contract.execute_treasurer(&treasurer);
+    assert_eq!(t.contract.get_treasurer(), Some(treasurer.clone()));
+    assert_eq!(t.contract.get_pending_treasurer(), None);
+
+    // Other admins cannot withdraw
+    let res = t
+        .contract
+        .try_withdraw_fees(&other_admin, &t.token_address, &dest, &100);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    // Cannot withdraw more than accrued
+    let res = t
+        .contract
+        .try_withdraw_fees(&treasurer, &t.token_address, &dest, &501);
+    assert_eq!(res, Err(Ok(Error::InsufficientBalance)));
+
+    t.contract
+        .withdraw_fees(&treasurer, &t.token_address, &dest, &300);
+    assert_eq!(t.token.balance(&dest), 300);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 200);
+
+    // A removed admin loses the Treasurer role
+    t.contract.remove_admin(&t.admin, &treasurer);
+    t.contract.approve_remove_admin(&t.admin);
+    t.contract.approve_remove_admin(&other_admin);
+    t.contract.execute_remove_admin(&t.admin);
+    let res = t
+        .contract
+        .try_withdraw_fees(&treasurer, &t.token_address, &dest, &100);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_fee_config_validation() {
+    let t = setup();
+    let stranger = Address::generate(&t.env);
+
+    let res = t
+        .contract
+        .try_set_fee_config(&t.admin, &(MAX_FEE_BPS + 1), &FeeMode::OnPayout);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    let res = t
+        .contract
+        .try_set_asset_fee(&t.admin, &t.token_address, &Some(MAX_FEE_BPS + 1));
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    let res = t
+        .contract
+        .try_set_fee_config(&stranger, &100u32, &FeeMode::OnPayout);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    t.contract
+        .set_fee_config(&t.admin, &100u32, &FeeMode::OnPayout);
+    t.contract
+        .set_asset_fee(&t.admin, &t.token_address, &Some(0u32));
+    assert_eq!(t.contract.get_asset_fee(&t.token_address), 0);
+
+    // Removing the override falls back to the global rate
+    t.contract.set_asset_fee(&t.admin, &t.token_address, &None);
+    assert_eq!(t.contract.get_asset_fee(&t.token_address), 100);
+}
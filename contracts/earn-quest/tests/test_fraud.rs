@@ -0,0 +1,281 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, SubmissionStatus};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    verifier: Address,
+    token_address: Address,
+    q1: Symbol,
+    q2: Symbol,
+    proof: BytesN<32>,
+}
+
+/// Two funded quests paying 100 each.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &10_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    for id in [&q1, &q2] {
+        contract.register_quest(id, &creator, &token_address, &100, &verifier, &99999);
+        contract.deposit_escrow(id, &creator, &token_address, &1000);
+    }
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    TestEnv {
+        env,
+        contract,
+        admin,
+        creator,
+        verifier,
+        token_address,
+        q1,
+        q2,
+        proof,
+    }
+}
+
+// ══════════════════════════════════════════════════════════════
+// Flagging
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_flag_voids_submissions_and_penalizes_xp() {
+    let t = setup();
+    let farmer = Address::generate(&t.env);
+
+    // Earn 100 XP, then leave one approval and one pending submission open
+    t.contract.submit_proof(&t.q1, &farmer, &t.proof);
+    t.contract.approve_submission(&t.q1, &farmer, &t.verifier);
+    t.contract.claim_reward(&t.q1, &farmer);
+    let q3 = symbol_short!("q3");
+    t.contract
+        .register_quest(&q3, &t.creator, &t.token_address, &100, &t.verifier, &99999);
+    t.contract
+        .deposit_escrow(&q3, &t.creator, &t.token_address, &1000);
+    t.contract.submit_proof(&q3, &farmer, &t.proof);
+    t.contract.approve_submission(&q3, &farmer, &t.verifier);
+    t.contract.submit_proof(&t.q2, &farmer, &t.proof);
+
+    t.contract.set_fraud_xp_penalty(&t.admin, &150);
+    assert_eq!(t.contract.flag_address(&t.admin, &farmer, &true), 2);
+
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("flagged"), t.admin.clone(), farmer.clone()).into_val(&t.env)
+    );
+
+    for id in [&t.q2, &q3] {
+        assert_eq!(
+            t.contract.get_submission(id, &farmer).status,
+            SubmissionStatus::Rejected
+        );
+    }
+    assert_eq!(
+        t.contract.get_submission(&t.q1, &farmer).status,
+        SubmissionStatus::Paid
+    );
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+
+    let stats = t.contract.get_user_stats(&farmer);
+    assert_eq!(stats.xp, 0);
+    let flag = t.contract.get_fraud_flag(&farmer).unwrap();
+    assert_eq!(flag.flagged_by, t.admin);
+    assert_eq!(flag.xp_penalty, 100);
+
+    // Flagging again does not penalize twice
+    assert_eq!(t.contract.flag_address(&t.admin, &farmer, &true), 0);
+    assert_eq!(t.contract.get_fraud_flag(&farmer).unwrap().xp_penalty, 100);
+}
+
+#[test]
+fn test_void_flagged_submissions_pages_by_cursor() {
+    let t = setup();
+    let farmer = Address::generate(&t.env);
+    t.contract.submit_proof(&t.q1, &farmer, &t.proof);
+    t.contract.submit_proof(&t.q2, &farmer, &t.proof);
+
+    let stranger = Address::generate(&t.env);
+    assert_eq!(
+        t.contract
+            .try_void_flagged_submissions(&t.admin, &farmer, &0),
+        Err(Ok(Error::NotFlagged))
+    );
+
+    t.contract.flag_address(&t.admin, &farmer, &false);
+    assert_eq!(
+        t.contract
+            .try_void_flagged_submissions(&stranger, &farmer, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Only quests from the cursor on are checked
+    assert_eq!(
+        t.contract.void_flagged_submissions(&t.admin, &farmer, &1),
+        None
+    );
+    assert_eq!(
+        t.contract.get_submission(&t.q1, &farmer).status,
+        SubmissionStatus::Pending
+    );
+    assert_eq!(
+        t.contract.get_submission(&t.q2, &farmer).status,
+        SubmissionStatus::Rejected
+    );
+
+    assert_eq!(
+        t.contract.void_flagged_submissions(&t.admin, &farmer, &0),
+        None
+    );
+    assert_eq!(
+        t.contract.get_submission(&t.q1, &farmer).status,
+        SubmissionStatus::Rejected
+    );
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+}
+
+#[test]
+fn test_denylisted_address_cannot_submit_claim_or_deposit() {
+    let t = setup();
+    let farmer = Address::generate(&t.env);
+    t.contract.submit_proof(&t.q1, &farmer, &t.proof);
+    t.contract.approve_submission(&t.q1, &farmer, &t.verifier);
+
+    // Without voiding, the approval stays but cannot be claimed
+    t.contract.flag_address(&t.admin, &farmer, &false);
+    assert_eq!(
+        t.contract.try_claim_reward(&t.q1, &farmer),
+        Err(Ok(Error::AddressDenylisted))
+    );
+    assert_eq!(
+        t.contract.try_submit_proof(&t.q2, &farmer, &t.proof),
+        Err(Ok(Error::AddressDenylisted))
+    );
+    token::StellarAssetClient::new(&t.env, &t.token_address).mint(&farmer, &100);
+    assert_eq!(
+        t.contract
+            .try_deposit_escrow(&t.q2, &farmer, &t.token_address, &100),
+        Err(Ok(Error::AddressDenylisted))
+    );
+
+    // Un-flagging is audited and restores access
+    t.contract.unflag_address(&t.admin, &farmer);
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("unflagged"), t.admin.clone(), farmer.clone()).into_val(&t.env)
+    );
+    assert_eq!(t.contract.get_fraud_flag(&farmer), None);
+    t.contract.claim_reward(&t.q1, &farmer);
+}
+
+#[test]
+fn test_voided_campaign_approval_returns_draw_to_pool() {
+    let t = setup();
+    let owner = Address::generate(&t.env);
+    token::StellarAssetClient::new(&t.env, &t.token_address).mint(&owner, &5000);
+    let campaign_id = symbol_short!("camp");
+    t.contract
+        .create_campaign(&campaign_id, &owner, &t.token_address);
+    t.contract.deposit_campaign(&campaign_id, &owner, &5000);
+    let qid = symbol_short!("cq");
+    t.contract
+        .register_campaign_quest(&campaign_id, &owner, &qid, &1000, &t.verifier, &99999);
+
+    let farmer = Address::generate(&t.env);
+    t.contract.submit_proof(&qid, &farmer, &t.proof);
+    t.contract.approve_submission(&qid, &farmer, &t.verifier);
+    assert_eq!(t.contract.get_campaign_balance(&campaign_id), 4000);
+
+    t.contract.flag_address(&t.admin, &farmer, &true);
+    assert_eq!(t.contract.get_campaign_balance(&campaign_id), 5000);
+    assert_eq!(t.contract.get_escrow_balance(&qid), 0);
+}
+
+#[test]
+fn test_flagged_owner_cannot_fund_campaign() {
+    let t = setup();
+    let owner = Address::generate(&t.env);
+    token::StellarAssetClient::new(&t.env, &t.token_address).mint(&owner, &5000);
+    let campaign_id = symbol_short!("camp");
+    t.contract
+        .create_campaign(&campaign_id, &owner, &t.token_address);
+
+    t.contract.flag_address(&t.admin, &owner, &false);
+    assert_eq!(
+        t.contract.try_deposit_campaign(&campaign_id, &owner, &5000),
+        Err(Ok(Error::AddressDenylisted))
+    );
+    assert_eq!(t.contract.get_campaign_balance(&campaign_id), 0);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_only_admins_manage_denylist() {
+    let t = setup();
+    let user = Address::generate(&t.env);
+    let stranger = Address::generate(&t.env);
+
+    assert_eq!(
+        t.contract.try_flag_address(&stranger, &user, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_unflag_address(&stranger, &user),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_fraud_xp_penalty(&stranger, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(t.contract.get_fraud_xp_penalty(), 0);
+}
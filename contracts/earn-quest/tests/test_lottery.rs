@@ -0,0 +1,339 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Vec};
+
+use earn_quest::errors::Error;
+use earn_quest::types::AssetConfig;
+use earn_quest::validation::{LOTTERY_CLAIM_WINDOW, MAX_REWARD_AMOUNT};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+const DEADLINE: u64 = 1000;
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    verifier: Address,
+    token_address: Address,
+    quest_id: Symbol,
+}
+
+/// An unfunded quest paying 100 per reward.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let quest_id = symbol_short!("raffle");
+    contract.register_quest(
+        &quest_id,
+        &creator,
+        &token_address,
+        &100,
+        &verifier,
+        &DEADLINE,
+    );
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        verifier,
+        token_address,
+        quest_id,
+    }
+}
+
+/// Make the quest a lottery, fund it and approve `entrants` submitters.
+fn approved_entrants(t: &TestEnv, winners: u32, deposit: i128, entrants: u32) -> Vec<Address> {
+    t.contract.set_lottery(&t.quest_id, &t.creator, &winners);
+    t.contract
+        .deposit_escrow(&t.quest_id, &t.creator, &t.token_address, &deposit);
+
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    let mut submitters = Vec::new(&t.env);
+    for _ in 0..entrants {
+        let submitter = Address::generate(&t.env);
+        t.contract.submit_proof(&t.quest_id, &submitter, &proof);
+        t.contract
+            .approve_submission(&t.quest_id, &submitter, &t.verifier);
+        submitters.push_back(submitter);
+    }
+    submitters
+}
+
+fn pass_deadline(t: &TestEnv) {
+    t.env.ledger().with_mut(|l| l.timestamp = DEADLINE + 1);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Drawing
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_draw_picks_winners_who_alone_can_claim() {
+    let t = setup();
+    let entrants = approved_entrants(&t, 2, 200, 5);
+
+    // Approval alone does not pay out
+    assert_eq!(
+        t.contract
+            .try_claim_reward(&t.quest_id, &entrants.get(0).unwrap()),
+        Err(Ok(Error::QuestStillActive))
+    );
+
+    pass_deadline(&t);
+    let winners = t.contract.draw_winners(&t.quest_id, &t.verifier);
+    assert_eq!(winners.len(), 2);
+
+    let draw = t.contract.get_lottery_draw(&t.quest_id).unwrap();
+    assert_eq!(draw.winners, winners);
+    assert_eq!(draw.entrants, 5);
+    let event = t.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (
+            symbol_short!("lot_draw"),
+            t.quest_id.clone(),
+            t.verifier.clone()
+        )
+            .into_val(&t.env)
+    );
+    let data = <(BytesN<32>, Vec<Address>, u32)>::try_from_val(&t.env, &event.2).unwrap();
+    assert_eq!(data, (draw.seed.clone(), winners.clone(), 5));
+
+    let token = token::Client::new(&t.env, &t.token_address);
+    for entrant in entrants.iter() {
+        if winners.contains(&entrant) {
+            t.contract.claim_reward(&t.quest_id, &entrant);
+            assert_eq!(token.balance(&entrant), 100);
+            assert_eq!(t.contract.get_user_stats(&entrant).xp, 100);
+        } else {
+            assert_eq!(
+                t.contract.try_claim_reward(&t.quest_id, &entrant),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_participation_xp_claimed_once_by_entrants_not_drawn() {
+    let t = setup();
+    let entrants = approved_entrants(&t, 1, 100, 3);
+
+    let pending = Address::generate(&t.env);
+    t.contract.submit_proof(
+        &t.quest_id,
+        &pending,
+        &BytesN::from_array(&t.env, &[2u8; 32]),
+    );
+
+    assert_eq!(
+        t.contract
+            .try_claim_participation_xp(&t.quest_id, &entrants.get(0).unwrap()),
+        Err(Ok(Error::QuestStillActive))
+    );
+
+    pass_deadline(&t);
+    let winner = t
+        .contract
+        .draw_winners(&t.quest_id, &t.creator)
+        .get(0)
+        .unwrap();
+
+    assert_eq!(
+        t.contract.try_claim_participation_xp(&t.quest_id, &winner),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_claim_participation_xp(&t.quest_id, &pending),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    for entrant in entrants.iter().filter(|e| *e != winner) {
+        // The draw itself awards nothing
+        assert_eq!(t.contract.get_user_stats(&entrant).xp, 0);
+        t.contract.claim_participation_xp(&t.quest_id, &entrant);
+        assert_eq!(t.contract.get_user_stats(&entrant).xp, 25);
+        assert_eq!(
+            t.contract.try_claim_participation_xp(&t.quest_id, &entrant),
+            Err(Ok(Error::AlreadyClaimed))
+        );
+    }
+}
+
+#[test]
+fn test_draw_only_once_after_deadline() {
+    let t = setup();
+    let entrants = approved_entrants(&t, 3, 300, 2);
+
+    assert_eq!(
+        t.contract.try_draw_winners(&t.quest_id, &t.creator),
+        Err(Ok(Error::QuestStillActive))
+    );
+
+    pass_deadline(&t);
+    let stranger = Address::generate(&t.env);
+    assert_eq!(
+        t.contract.try_draw_winners(&t.quest_id, &stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Fewer entrants than winners: everyone approved wins
+    let winners = t.contract.draw_winners(&t.quest_id, &t.creator);
+    assert_eq!(winners.len(), 2);
+    assert!(winners.contains(entrants.get(0).unwrap()));
+    assert!(winners.contains(entrants.get(1).unwrap()));
+
+    assert_eq!(
+        t.contract.try_draw_winners(&t.quest_id, &t.creator),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    // Late approvals cannot enter a drawn lottery
+    let late = Address::generate(&t.env);
+    t.env.ledger().with_mut(|l| l.timestamp = 0);
+    t.contract
+        .submit_proof(&t.quest_id, &late, &BytesN::from_array(&t.env, &[2u8; 32]));
+    assert_eq!(
+        t.contract
+            .try_approve_submission(&t.quest_id, &late, &t.verifier),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_draw_requires_escrow_for_every_winner() {
+    let t = setup();
+    approved_entrants(&t, 3, 200, 3);
+    pass_deadline(&t);
+
+    assert_eq!(
+        t.contract.try_draw_winners(&t.quest_id, &t.creator),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+
+    t.contract
+        .deposit_escrow(&t.quest_id, &t.creator, &t.token_address, &100);
+    assert_eq!(t.contract.draw_winners(&t.quest_id, &t.creator).len(), 3);
+}
+
+#[test]
+fn test_cancel_waits_for_drawn_winners() {
+    let t = setup();
+    approved_entrants(&t, 2, 300, 3);
+    pass_deadline(&t);
+    let winners = t.contract.draw_winners(&t.quest_id, &t.creator);
+
+    assert_eq!(
+        t.contract.try_cancel_quest(&t.quest_id, &t.creator),
+        Err(Ok(Error::EscrowNotSettled))
+    );
+
+    // Winners can still claim; an unclaimed winner keeps blocking the refund
+    let token = token::Client::new(&t.env, &t.token_address);
+    let first = winners.get(0).unwrap();
+    t.contract.claim_reward(&t.quest_id, &first);
+    assert_eq!(token.balance(&first), 100);
+    assert_eq!(
+        t.contract.try_cancel_quest(&t.quest_id, &t.creator),
+        Err(Ok(Error::EscrowNotSettled))
+    );
+
+    // Once the claim window has passed the rest is refunded
+    t.env
+        .ledger()
+        .with_mut(|l| l.timestamp = DEADLINE + 1 + LOTTERY_CLAIM_WINDOW);
+    assert_eq!(t.contract.cancel_quest(&t.quest_id, &t.creator), 200);
+    assert_eq!(token.balance(&t.creator), 900);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_lottery_mode_set_by_creator_before_funding() {
+    let t = setup();
+    let stranger = Address::generate(&t.env);
+
+    assert_eq!(
+        t.contract.try_set_lottery(&t.quest_id, &stranger, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_lottery(&t.quest_id, &t.creator, &0),
+        Err(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        t.contract.try_set_lottery(&t.quest_id, &t.creator, &101),
+        Err(Ok(Error::ArrayTooLong))
+    );
+    assert_eq!(t.contract.get_lottery_winners(&t.quest_id), None);
+
+    t.contract
+        .deposit_escrow(&t.quest_id, &t.creator, &t.token_address, &100);
+    assert_eq!(
+        t.contract.try_set_lottery(&t.quest_id, &t.creator, &2),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_lottery_quests_take_no_attestations_or_merkle_roots() {
+    let t = setup();
+    let key = BytesN::from_array(&t.env, &[7u8; 32]);
+
+    t.contract
+        .set_quest_attestor(&t.quest_id, &t.creator, &Some(key.clone()));
+    assert_eq!(
+        t.contract.try_set_lottery(&t.quest_id, &t.creator, &2),
+        Err(Ok(Error::InvalidConfig))
+    );
+    t.contract
+        .set_quest_attestor(&t.quest_id, &t.creator, &None);
+
+    t.contract.set_lottery(&t.quest_id, &t.creator, &2);
+    assert_eq!(
+        t.contract
+            .try_set_quest_attestor(&t.quest_id, &t.creator, &Some(key.clone())),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    t.contract
+        .deposit_escrow(&t.quest_id, &t.creator, &t.token_address, &200);
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &t.verifier, &key, &2, &200),
+        Err(Ok(Error::InvalidConfig))
+    );
+}
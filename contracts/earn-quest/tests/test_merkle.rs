@@ -0,0 +1,310 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, vec, Address, BytesN, Env, Symbol, Vec};
+
+use earn_quest::errors::Error;
+use earn_quest::merkle::{hash_pair, leaf_hash};
+use earn_quest::types::{AssetConfig, SubmissionStatus};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    verifier: Address,
+    token: token::Client<'a>,
+    quest_id: Symbol,
+}
+
+/// Quest with 1000 in escrow, paying 100 per regular approval.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &1000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let quest_id = symbol_short!("q1");
+    contract.register_quest(&quest_id, &creator, &token_address, &100, &verifier, &99999);
+    contract.deposit_escrow(&quest_id, &creator, &token_address, &1000);
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        verifier,
+        token,
+        quest_id,
+    }
+}
+
+/// Four-leaf tree over `(i, users[i], amounts[i])`; returns the root and
+/// the proof for each leaf.
+fn build_tree(
+    env: &Env,
+    users: &[Address; 4],
+    amounts: &[i128; 4],
+) -> (BytesN<32>, [Vec<BytesN<32>>; 4]) {
+    let l: [BytesN<32>; 4] =
+        core::array::from_fn(|i| leaf_hash(env, i as u32, &users[i], amounts[i]));
+    let left = hash_pair(env, &l[0], &l[1]);
+    let right = hash_pair(env, &l[2], &l[3]);
+    let root = hash_pair(env, &left, &right);
+    let proofs = [
+        vec![env, l[1].clone(), right.clone()],
+        vec![env, l[0].clone(), right.clone()],
+        vec![env, l[3].clone(), left.clone()],
+        vec![env, l[2].clone(), left.clone()],
+    ];
+    (root, proofs)
+}
+
+// ══════════════════════════════════════════════════════════════
+// Posting and claiming
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_cohort_claims_with_proofs() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+
+    let round_id = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+    assert_eq!(round_id, 0);
+    assert_eq!(
+        t.contract.get_merkle_round(&t.quest_id, &round_id).root,
+        root
+    );
+
+    for i in 0..4 {
+        t.contract.claim_with_proof(
+            &t.quest_id,
+            &users[i],
+            &round_id,
+            &(i as u32),
+            &amounts[i],
+            &proofs[i],
+        );
+        assert_eq!(t.token.balance(&users[i]), amounts[i]);
+        assert_eq!(t.contract.get_user_stats(&users[i]).xp, 100);
+    }
+
+    let round = t.contract.get_merkle_round(&t.quest_id, &round_id);
+    assert_eq!(round.claimed_amount, 500);
+    assert!(t.contract.is_merkle_claimed(&t.quest_id, &round_id, &2));
+    assert_eq!(t.contract.get_quest(&t.quest_id).total_claims, 4);
+    assert_eq!(t.contract.get_escrow_balance(&t.quest_id), 500);
+
+    // Each leaf pays once
+    assert_eq!(
+        t.contract.try_claim_with_proof(
+            &t.quest_id,
+            &users[0],
+            &round_id,
+            &0,
+            &amounts[0],
+            &proofs[0],
+        ),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_invalid_proofs_are_rejected() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+    let round_id = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+
+    // Wrong amount, wrong claimant, wrong proof, index out of range
+    let cases = [
+        (users[1].clone(), 1u32, 300i128, proofs[1].clone()),
+        (Address::generate(&t.env), 1, 200, proofs[1].clone()),
+        (users[1].clone(), 1, 200, proofs[2].clone()),
+        (users[1].clone(), 4, 200, proofs[1].clone()),
+    ];
+    for (user, index, amount, proof) in cases.iter() {
+        assert_eq!(
+            t.contract
+                .try_claim_with_proof(&t.quest_id, user, &round_id, index, amount, proof),
+            Err(Ok(Error::InvalidProof))
+        );
+    }
+
+    assert_eq!(
+        t.contract
+            .try_claim_with_proof(&t.quest_id, &users[1], &7, &1, &200, &proofs[1]),
+        Err(Ok(Error::MerkleRoundNotFound))
+    );
+    assert_eq!(t.token.balance(&users[1]), 0);
+}
+
+#[test]
+fn test_leaves_cannot_exceed_posted_total() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+
+    // Root commits to 500 in leaves but only declares 300
+    let round_id = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &300);
+    t.contract
+        .claim_with_proof(&t.quest_id, &users[1], &round_id, &1, &200, &proofs[1]);
+    assert_eq!(
+        t.contract
+            .try_claim_with_proof(&t.quest_id, &users[2], &round_id, &2, &150, &proofs[2]),
+        Err(Ok(Error::InvalidProof))
+    );
+    t.contract
+        .claim_with_proof(&t.quest_id, &users[0], &round_id, &0, &100, &proofs[0]);
+}
+
+#[test]
+fn test_unclaimed_round_totals_are_held_back() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+    let round_id = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+
+    // 1000 escrowed, 500 owed to round 0
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &600),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+
+    // Claims release their amount along with paying it out
+    t.contract
+        .claim_with_proof(&t.quest_id, &users[1], &round_id, &1, &200, &proofs[1]);
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &600),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+    t.contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+}
+
+#[test]
+fn test_claim_records_paid_submission() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+    let first = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+    let second = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+
+    t.contract
+        .claim_with_proof(&t.quest_id, &users[0], &first, &0, &100, &proofs[0]);
+    let submission = t.contract.get_submission(&t.quest_id, &users[0]);
+    assert_eq!(submission.status, SubmissionStatus::Paid);
+    assert_eq!(t.contract.get_user_history(&users[0], &0, &10).len(), 1);
+
+    // Paid once per quest, whichever path pays
+    assert_eq!(
+        t.contract
+            .try_claim_with_proof(&t.quest_id, &users[0], &second, &0, &100, &proofs[0]),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert!(t.contract.try_claim_reward(&t.quest_id, &users[0]).is_err());
+}
+
+#[test]
+fn test_cancelled_quest_rejects_proof_claims() {
+    let t = setup();
+    let users: [Address; 4] = core::array::from_fn(|_| Address::generate(&t.env));
+    let amounts = [100, 200, 150, 50];
+    let (root, proofs) = build_tree(&t.env, &users, &amounts);
+    let round_id = t
+        .contract
+        .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &500);
+
+    t.contract.cancel_quest(&t.quest_id, &t.creator);
+    assert_eq!(
+        t.contract
+            .try_claim_with_proof(&t.quest_id, &users[0], &round_id, &0, &100, &proofs[0]),
+        Err(Ok(Error::QuestNotActive))
+    );
+    assert_eq!(t.token.balance(&users[0]), 0);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_post_root_validation() {
+    let t = setup();
+    let root = BytesN::from_array(&t.env, &[7u8; 32]);
+
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &Address::generate(&t.env), &root, &4, &500,),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &0),
+        Err(Ok(Error::InvalidRewardAmount))
+    );
+    assert_eq!(
+        t.contract
+            .try_post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &1001),
+        Err(Ok(Error::InsufficientEscrow))
+    );
+
+    // Round ids increase per quest
+    assert_eq!(
+        t.contract
+            .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &100),
+        0
+    );
+    assert_eq!(
+        t.contract
+            .post_merkle_root(&t.quest_id, &t.verifier, &root, &4, &100),
+        1
+    );
+}
@@ -1,38 +1,54 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, symbol_short, BytesN};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
 
 // Import from the library
 extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::AssetConfig;
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+fn allow_asset(env: &Env, client: &EarnQuestContractClient, asset: &Address) {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        asset,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+}
 #[test]
 fn test_payout_success() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, EarnQuestContract);
     let client = EarnQuestContractClient::new(&env, &contract_id);
-    
+
     // 1. Setup Token
     let admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&env, &client, &token_contract);
     let token_admin_client = StellarAssetClient::new(&env, &token_contract);
     let token_client = TokenClient::new(&env, &token_contract);
-    
-    // Fund the contract with enough tokens for payout
+
     let reward_amount = 100i128;
-    token_admin_client.mint(&contract_id, &1000); 
-    
+
     // 2. Setup Quest
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
-    
+
     let quest_id = symbol_short!("Q1");
-    
+
     client.register_quest(
         &quest_id,
         &creator,
@@ -41,20 +57,24 @@ fn test_payout_success() {
         &verifier,
         &10000,
     );
-    
+
+    // Fund the quest's escrow with enough tokens for payout
+    token_admin_client.mint(&creator, &1000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &1000);
+
     // 3. Submit Proof
     let proof = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_proof(&quest_id, &submitter, &proof);
-    
+
     // 4. Approve Submission
     client.approve_submission(&quest_id, &submitter, &verifier);
-    
+
     // 5. Claim Reward
     let pre_balance = token_client.balance(&submitter);
     assert_eq!(pre_balance, 0);
-    
+
     client.claim_reward(&quest_id, &submitter);
-    
+
     let post_balance = token_client.balance(&submitter);
     assert_eq!(post_balance, 100);
 }
@@ -63,20 +83,21 @@ fn test_payout_success() {
 fn test_insufficient_balance() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, EarnQuestContract);
     let client = EarnQuestContractClient::new(&env, &contract_id);
-    
+
     let admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&env, &client, &token_contract);
     // Do NOT fund contract
-    
+
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
     let quest_id = symbol_short!("Q2");
-    
+
     client.register_quest(
         &quest_id,
         &creator,
@@ -85,35 +106,38 @@ fn test_insufficient_balance() {
         &verifier,
         &10000,
     );
-    
+
+    // Without escrow the quest never opens, so nothing can be paid out
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
-    client.approve_submission(&quest_id, &submitter, &verifier);
-    
-    // Claim should fail with InsufficientBalance
+    let res = client.try_submit_proof(&quest_id, &submitter, &proof);
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+
     let res = client.try_claim_reward(&quest_id, &submitter);
-    assert!(res.is_err(), "Expected claim to fail due to insufficient balance");
+    assert!(
+        res.is_err(),
+        "Expected claim to fail without a funded escrow"
+    );
 }
 
 #[test]
 fn test_double_claim_prevention() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, EarnQuestContract);
     let client = EarnQuestContractClient::new(&env, &contract_id);
-    
+
     let admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&env, &client, &token_contract);
     let token_admin_client = StellarAssetClient::new(&env, &token_contract);
-    token_admin_client.mint(&contract_id, &1000); 
-    
+
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
     let quest_id = symbol_short!("Q3");
-    
+
     client.register_quest(
         &quest_id,
         &creator,
@@ -122,14 +146,16 @@ fn test_double_claim_prevention() {
         &verifier,
         &10000,
     );
-    
+    token_admin_client.mint(&creator, &1000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &1000);
+
     let proof = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_proof(&quest_id, &submitter, &proof);
     client.approve_submission(&quest_id, &submitter, &verifier);
-    
+
     // First claim
     client.claim_reward(&quest_id, &submitter);
-    
+
     // Second claim should fail with AlreadyClaimed
     let res = client.try_claim_reward(&quest_id, &submitter);
     assert!(res.is_err(), "Expected second claim to fail");
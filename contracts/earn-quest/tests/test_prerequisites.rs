@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, vec, Address, BytesN, Env, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, Badge, Prerequisites};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    verifier: Address,
+    intro: Symbol,
+    advanced: Symbol,
+}
+
+/// Two funded quests: `intro` and `advanced`.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &2000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let intro = symbol_short!("intro");
+    let advanced = symbol_short!("advanced");
+    for id in [&intro, &advanced] {
+        contract.register_quest(id, &creator, &token_address, &100, &verifier, &99999);
+        contract.deposit_escrow(id, &creator, &token_address, &1000);
+    }
+
+    TestEnv {
+        env,
+        contract,
+        admin,
+        creator,
+        verifier,
+        intro,
+        advanced,
+    }
+}
+
+fn complete(t: &TestEnv, quest_id: &Symbol, user: &Address) {
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(quest_id, user, &proof);
+    t.contract.approve_submission(quest_id, user, &t.verifier);
+    t.contract.claim_reward(quest_id, user);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Chained quests
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_prerequisite_quest_must_be_paid() {
+    let t = setup();
+    t.contract.set_prerequisites(
+        &t.advanced,
+        &t.creator,
+        &Some(Prerequisites {
+            quests: vec![&t.env, t.intro.clone()],
+            min_level: 0,
+            badges: vec![&t.env],
+        }),
+    );
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+
+    assert_eq!(
+        t.contract.try_submit_proof(&t.advanced, &user, &proof),
+        Err(Ok(Error::PrerequisiteNotMet))
+    );
+    let eligibility = t.contract.get_eligibility(&t.advanced, &user);
+    assert!(!eligibility.eligible);
+    assert_eq!(eligibility.missing_quests, vec![&t.env, t.intro.clone()]);
+
+    // Approved is not enough; the reward must have been claimed
+    t.contract.submit_proof(&t.intro, &user, &proof);
+    t.contract.approve_submission(&t.intro, &user, &t.verifier);
+    assert!(!t.contract.get_eligibility(&t.advanced, &user).eligible);
+
+    t.contract.claim_reward(&t.intro, &user);
+    assert!(t.contract.get_eligibility(&t.advanced, &user).eligible);
+    complete(&t, &t.advanced, &user);
+}
+
+#[test]
+fn test_completion_survives_pruning_the_prerequisite() {
+    let t = setup();
+    t.contract.set_prerequisites(
+        &t.advanced,
+        &t.creator,
+        &Some(Prerequisites {
+            quests: vec![&t.env, t.intro.clone()],
+            min_level: 0,
+            badges: vec![&t.env],
+        }),
+    );
+    let user = Address::generate(&t.env);
+    complete(&t, &t.intro, &user);
+
+    t.contract.cancel_quest(&t.intro, &t.creator);
+    t.contract.set_retention_period(&t.admin, &0);
+    assert!(t.contract.prune_quest(&t.intro));
+
+    assert!(t.contract.get_eligibility(&t.advanced, &user).eligible);
+    complete(&t, &t.advanced, &user);
+}
+
+#[test]
+fn test_level_and_badge_requirements() {
+    let t = setup();
+    t.contract.set_prerequisites(
+        &t.advanced,
+        &t.creator,
+        &Some(Prerequisites {
+            quests: vec![&t.env],
+            min_level: 2,
+            badges: vec![&t.env, Badge::Explorer],
+        }),
+    );
+    let user = Address::generate(&t.env);
+
+    let eligibility = t.contract.get_eligibility(&t.advanced, &user);
+    assert!(!eligibility.eligible);
+    assert_eq!(eligibility.min_level, 2);
+    assert_eq!(eligibility.level, 1);
+    assert_eq!(eligibility.missing_badges, vec![&t.env, Badge::Explorer]);
+
+    t.contract.grant_badge(&t.admin, &user, &Badge::Explorer);
+    let eligibility = t.contract.get_eligibility(&t.advanced, &user);
+    assert_eq!(eligibility.missing_badges.len(), 0);
+    assert!(!eligibility.eligible);
+
+    // Clearing the prerequisites opens the quest to everyone
+    t.contract.set_prerequisites(&t.advanced, &t.creator, &None);
+    assert!(t.contract.get_eligibility(&t.advanced, &user).eligible);
+    complete(&t, &t.advanced, &user);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control & validation
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_set_prerequisites_validation() {
+    let t = setup();
+    let requirement = |id: Symbol| {
+        Some(Prerequisites {
+            quests: vec![&t.env, id],
+            min_level: 0,
+            badges: vec![&t.env],
+        })
+    };
+
+    assert_eq!(
+        t.contract.try_set_prerequisites(
+            &t.advanced,
+            &Address::generate(&t.env),
+            &requirement(t.intro.clone()),
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        t.contract.try_set_prerequisites(
+            &t.advanced,
+            &t.creator,
+            &requirement(symbol_short!("none")),
+        ),
+        Err(Ok(Error::QuestNotFound))
+    );
+
+    t.contract
+        .set_prerequisites(&t.advanced, &t.creator, &requirement(t.intro.clone()));
+    assert_eq!(
+        t.contract.get_prerequisites(&t.advanced),
+        requirement(t.intro.clone())
+    );
+}
@@ -0,0 +1,425 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, BatchApprovalInput, QuestStatus, SubmissionStatus};
+use earn_quest::validation::{MAX_PAGE_SIZE, MAX_REWARD_AMOUNT};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    verifier: Address,
+    token_address: Address,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &100_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        verifier,
+        token_address,
+    }
+}
+
+fn register(t: &TestEnv, id: &Symbol, creator: &Address, verifier: &Address) {
+    t.contract
+        .register_quest(id, creator, &t.token_address, &1000, verifier, &10000);
+    token::StellarAssetClient::new(&t.env, &t.token_address).mint(creator, &1000);
+    t.contract
+        .deposit_escrow(id, creator, &t.token_address, &1000);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Single-record views
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_get_quest_and_submission() {
+    let t = setup();
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator, &t.verifier);
+
+    let quest = t.contract.get_quest(&qid);
+    assert_eq!(quest.id, qid);
+    assert_eq!(quest.creator, t.creator);
+    assert_eq!(quest.status, QuestStatus::Active);
+
+    let submitter = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[7u8; 32]);
+    t.contract.submit_proof(&qid, &submitter, &proof);
+
+    let submission = t.contract.get_submission(&qid, &submitter);
+    assert_eq!(submission.proof_hash, proof);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+
+    assert_eq!(
+        t.contract.try_get_quest(&symbol_short!("none")),
+        Err(Ok(Error::QuestNotFound))
+    );
+    assert_eq!(
+        t.contract.try_get_submission(&qid, &t.creator),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+}
+
+// ══════════════════════════════════════════════════════════════
+// Paginated listings
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_list_quests_paginates_in_registration_order() {
+    let t = setup();
+    let ids = [
+        symbol_short!("q1"),
+        symbol_short!("q2"),
+        symbol_short!("q3"),
+        symbol_short!("q4"),
+        symbol_short!("q5"),
+    ];
+    for id in ids.iter() {
+        register(&t, id, &t.creator, &t.verifier);
+    }
+
+    let first = t.contract.list_quests(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().id, ids[0]);
+    assert_eq!(first.get(1).unwrap().id, ids[1]);
+
+    let last = t.contract.list_quests(&4, &2);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().id, ids[4]);
+
+    assert_eq!(t.contract.list_quests(&5, &2).len(), 0);
+    assert_eq!(t.contract.list_quests(&u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_list_quests_caps_page_size() {
+    let t = setup();
+    // Funding more than a page of quests exceeds the default test budget
+    t.env.budget().reset_unlimited();
+    for i in 0..(MAX_PAGE_SIZE + 1) {
+        let id = Symbol::new(&t.env, &std::format!("q{}", i));
+        register(&t, &id, &t.creator, &t.verifier);
+    }
+
+    assert_eq!(t.contract.list_quests(&0, &u32::MAX).len(), MAX_PAGE_SIZE);
+    assert_eq!(t.contract.list_quests(&MAX_PAGE_SIZE, &u32::MAX).len(), 1);
+}
+
+#[test]
+fn test_list_quests_by_creator_and_verifier() {
+    let t = setup();
+    let other_creator = Address::generate(&t.env);
+    let other_verifier = Address::generate(&t.env);
+
+    register(&t, &symbol_short!("a1"), &t.creator, &t.verifier);
+    register(&t, &symbol_short!("b1"), &other_creator, &t.verifier);
+    register(&t, &symbol_short!("a2"), &t.creator, &other_verifier);
+
+    let by_creator = t.contract.list_quests_by_creator(&t.creator, &0, &10);
+    assert_eq!(by_creator.len(), 2);
+    assert_eq!(by_creator.get(0).unwrap().id, symbol_short!("a1"));
+    assert_eq!(by_creator.get(1).unwrap().id, symbol_short!("a2"));
+
+    let by_verifier = t.contract.list_quests_by_verifier(&t.verifier, &0, &10);
+    assert_eq!(by_verifier.len(), 2);
+    assert_eq!(by_verifier.get(0).unwrap().id, symbol_short!("a1"));
+    assert_eq!(by_verifier.get(1).unwrap().id, symbol_short!("b1"));
+
+    let nobody = Address::generate(&t.env);
+    assert_eq!(t.contract.list_quests_by_creator(&nobody, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_list_quests_by_status_follows_cancellation() {
+    let t = setup();
+    let keep = symbol_short!("keep");
+    let cancel = symbol_short!("cancel");
+    register(&t, &keep, &t.creator, &t.verifier);
+    register(&t, &cancel, &t.creator, &t.verifier);
+
+    assert_eq!(
+        t.contract
+            .list_quests_by_status(&QuestStatus::Active, &0, &10)
+            .len(),
+        2
+    );
+
+    t.contract.cancel_quest(&cancel, &t.creator);
+
+    let active = t
+        .contract
+        .list_quests_by_status(&QuestStatus::Active, &0, &10);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, keep);
+
+    let cancelled = t
+        .contract
+        .list_quests_by_status(&QuestStatus::Cancelled, &0, &10);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled.get(0).unwrap().id, cancel);
+    assert_eq!(cancelled.get(0).unwrap().status, QuestStatus::Cancelled);
+
+    assert_eq!(
+        t.contract
+            .list_quests_by_status(&QuestStatus::Active, &1, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_status_index_moves_last_quest_into_vacated_slot() {
+    let t = setup();
+    let a = symbol_short!("a");
+    let b = symbol_short!("b");
+    let c = symbol_short!("c");
+    for id in [&a, &b, &c] {
+        register(&t, id, &t.creator, &t.verifier);
+    }
+
+    // Leaving a status swaps the last quest into the freed position
+    t.contract.cancel_quest(&a, &t.creator);
+    let active = t
+        .contract
+        .list_quests_by_status(&QuestStatus::Active, &0, &10);
+    assert_eq!(active.len(), 2);
+    assert_eq!(active.get(0).unwrap().id, c);
+    assert_eq!(active.get(1).unwrap().id, b);
+
+    // The moved quest's position is tracked, so it can leave in turn
+    t.contract.cancel_quest(&c, &t.creator);
+    let active = t
+        .contract
+        .list_quests_by_status(&QuestStatus::Active, &0, &10);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, b);
+
+    let cancelled = t
+        .contract
+        .list_quests_by_status(&QuestStatus::Cancelled, &0, &10);
+    assert_eq!(cancelled.len(), 2);
+    assert_eq!(cancelled.get(0).unwrap().id, a);
+    assert_eq!(cancelled.get(1).unwrap().id, c);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Submission index & verifier queue
+// ══════════════════════════════════════════════════════════════
+
+fn submit(t: &TestEnv, qid: &Symbol) -> Address {
+    let submitter = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(qid, &submitter, &proof);
+    submitter
+}
+
+#[test]
+fn test_list_submissions_with_status_filter() {
+    let t = setup();
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator, &t.verifier);
+
+    let a = submit(&t, &qid);
+    let b = submit(&t, &qid);
+    let c = submit(&t, &qid);
+    t.contract.approve_submission(&qid, &a, &t.verifier);
+    t.contract.reject_submission(&qid, &b, &t.verifier);
+
+    let all = t.contract.list_submissions(&qid, &None, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().submitter, a);
+    assert_eq!(all.get(2).unwrap().submitter, c);
+
+    let pending = t
+        .contract
+        .list_submissions(&qid, &Some(SubmissionStatus::Pending), &0, &10);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().submitter, c);
+
+    let rejected = t
+        .contract
+        .list_submissions(&qid, &Some(SubmissionStatus::Rejected), &0, &10);
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected.get(0).unwrap().submitter, b);
+
+    // Filtered pages scan `limit` positions, so they may come back short
+    let page = t
+        .contract
+        .list_submissions(&qid, &Some(SubmissionStatus::Pending), &0, &2);
+    assert_eq!(page.len(), 0);
+    let page = t
+        .contract
+        .list_submissions(&qid, &Some(SubmissionStatus::Pending), &2, &2);
+    assert_eq!(page.len(), 1);
+}
+
+#[test]
+fn test_resubmission_is_not_indexed_twice() {
+    let t = setup();
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator, &t.verifier);
+
+    let submitter = submit(&t, &qid);
+    let proof = BytesN::from_array(&t.env, &[2u8; 32]);
+    t.contract.submit_proof(&qid, &submitter, &proof);
+
+    assert_eq!(t.contract.list_submissions(&qid, &None, &0, &10).len(), 1);
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        1
+    );
+}
+
+#[test]
+fn test_resubmission_rejected_once_approved_or_paid() {
+    let t = setup();
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator, &t.verifier);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &5000);
+
+    let submitter = submit(&t, &qid);
+    let proof = BytesN::from_array(&t.env, &[2u8; 32]);
+    t.contract.approve_submission(&qid, &submitter, &t.verifier);
+    assert_eq!(
+        t.contract.try_submit_proof(&qid, &submitter, &proof),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    t.contract.claim_reward(&qid, &submitter);
+    assert_eq!(
+        t.contract.try_submit_proof(&qid, &submitter, &proof),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+    assert_eq!(
+        t.contract.get_submission(&qid, &submitter).status,
+        SubmissionStatus::Paid
+    );
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+}
+
+#[test]
+fn test_pending_queue_drives_batch_approval() {
+    let t = setup();
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    register(&t, &q1, &t.creator, &t.verifier);
+    register(&t, &q2, &t.creator, &t.verifier);
+    t.contract
+        .deposit_escrow(&q1, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&q2, &t.creator, &t.token_address, &5000);
+
+    let a = submit(&t, &q1);
+    let b = submit(&t, &q2);
+    let c = submit(&t, &q1);
+
+    let queue = t.contract.pending_for_verifier(&t.verifier, &0, &10);
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.get(0).unwrap().submitter, a);
+    assert_eq!(queue.get(1).unwrap().submitter, b);
+    assert_eq!(queue.get(2).unwrap().submitter, c);
+
+    t.contract.reject_submission(&q2, &b, &t.verifier);
+
+    // The last queued submission takes the reviewed one's slot
+    let queue = t.contract.pending_for_verifier(&t.verifier, &0, &10);
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.get(0).unwrap().submitter, a);
+    assert_eq!(queue.get(1).unwrap().submitter, c);
+    assert_eq!(
+        t.contract
+            .pending_for_verifier(&t.verifier, &1, &10)
+            .get(0)
+            .unwrap()
+            .submitter,
+        c
+    );
+
+    let mut batch = soroban_sdk::Vec::new(&t.env);
+    for s in t.contract.pending_for_verifier(&t.verifier, &0, &10).iter() {
+        batch.push_back(BatchApprovalInput {
+            quest_id: s.quest_id,
+            submitter: s.submitter,
+        });
+    }
+    assert_eq!(batch.len(), 2);
+    t.contract.approve_submissions_batch(&t.verifier, &batch);
+
+    assert_eq!(
+        t.contract.pending_for_verifier(&t.verifier, &0, &10).len(),
+        0
+    );
+    assert_eq!(
+        t.contract.get_submission(&q1, &c).status,
+        SubmissionStatus::Approved
+    );
+}
+
+#[test]
+fn test_reject_submission_requires_verifier_and_pending() {
+    let t = setup();
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator, &t.verifier);
+    let submitter = submit(&t, &qid);
+
+    assert_eq!(
+        t.contract
+            .try_reject_submission(&qid, &submitter, &t.creator),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    t.contract.reject_submission(&qid, &submitter, &t.verifier);
+    assert_eq!(
+        t.contract.get_submission(&qid, &submitter).status,
+        SubmissionStatus::Rejected
+    );
+
+    assert_eq!(
+        t.contract
+            .try_approve_submission(&qid, &submitter, &t.verifier),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+}
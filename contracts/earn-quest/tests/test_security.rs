@@ -1,9 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, symbol_short, BytesN};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env,
+};
 
 extern crate earn_quest;
+use earn_quest::types::{AssetConfig, ProposalValue};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
@@ -12,6 +18,27 @@ fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
     (contract_id, client)
 }
 
+/// Add a second admin to a contract whose only admin is `admin`.
+fn add_second_admin(client: &EarnQuestContractClient, admin: &Address, admin2: &Address) {
+    client.add_admin(admin, admin2);
+    client.approve_add_admin(admin);
+    client.execute_add_admin(admin);
+}
+
+fn allow_asset(client: &EarnQuestContractClient, admin: &Address, asset: &Address) {
+    client.initialize(admin);
+    client.set_asset_config(
+        admin,
+        asset,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #50)")]
 fn test_pause_blocks_register_quest() {
@@ -25,13 +52,21 @@ fn test_pause_blocks_register_quest() {
     let token_admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&client, &admin, &token_contract);
 
     client.initialize(&admin);
     client.emergency_pause(&admin);
 
     let quest_id = symbol_short!("SQ1");
     // This should panic because contract is paused
-    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &10000,
+    );
 }
 
 #[test]
@@ -44,10 +79,21 @@ fn test_emergency_withdraw_when_paused() {
     let token_admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&client, &admin, &token_contract);
     let token_admin_client = StellarAssetClient::new(&env, &token_contract);
     let token_client = TokenClient::new(&env, &token_contract);
 
+    let recovery = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
     client.initialize(&admin);
+    add_second_admin(&client, &admin, &admin2);
+
+    // Register recovery address (default threshold is two approvals)
+    client.propose_recovery_address(&admin, &recovery);
+    client.approve_recovery_address(&admin);
+    client.approve_recovery_address(&admin2);
+    client.execute_recovery_address(&admin);
 
     // Fund contract
     token_admin_client.mint(&contract_id, &1000);
@@ -55,12 +101,254 @@ fn test_emergency_withdraw_when_paused() {
     // Pause
     client.emergency_pause(&admin);
 
-    // Emergency withdraw to admin
-    client.emergency_withdraw(&admin, &token_contract, &admin, &500);
+    // Emergency withdraw goes to the recovery address
+    client.emergency_withdraw(&admin, &token_contract, &500);
+
+    // Ensure recovery address received funds
+    assert_eq!(token_client.balance(&recovery), 500);
+    assert_eq!(token_client.balance(&admin), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #54)")]
+fn test_emergency_withdraw_requires_recovery_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token_contract = token_contract_obj.address();
+    allow_asset(&client, &admin, &token_contract);
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+
+    client.initialize(&admin);
+    token_admin_client.mint(&contract_id, &1000);
+    client.emergency_pause(&admin);
+
+    client.emergency_withdraw(&admin, &token_contract, &500);
+}
+
+#[test]
+fn test_recovery_address_change_requires_approvals_and_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let recovery = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    client.set_unpause_timelock(&admin1, &100u64);
+
+    client.propose_recovery_address(&admin1, &recovery);
+    client.approve_recovery_address(&admin1);
+
+    // One approval out of two is not enough
+    let res = client.try_execute_recovery_address(&admin1);
+    assert_eq!(
+        res,
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
+
+    // The same admin cannot approve twice
+    let res = client.try_approve_recovery_address(&admin1);
+    assert_eq!(res, Err(Ok(earn_quest::errors::Error::AlreadyApproved)));
+
+    client.approve_recovery_address(&admin2);
+
+    // Threshold met but timelock still running
+    let res = client.try_execute_recovery_address(&admin1);
+    assert_eq!(res, Err(Ok(earn_quest::errors::Error::TimelockNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.execute_recovery_address(&admin2);
+
+    assert_eq!(client.get_recovery_address(), Some(recovery));
+    assert_eq!(client.get_pending_recovery(), None);
+}
+
+#[test]
+fn test_single_admin_cannot_change_recovery_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_unpause_timelock(&admin, &100u64);
+
+    // Neither the threshold nor the timelock can be lowered
+    assert_eq!(
+        client.try_set_unpause_threshold(&admin, &1u32),
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
+    assert_eq!(
+        client.try_set_unpause_threshold(&admin, &0u32),
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
+    assert_eq!(
+        client.try_set_unpause_timelock(&admin, &0u64),
+        Err(Ok(earn_quest::errors::Error::TimelockNotExpired))
+    );
+
+    // A lone approval never schedules the change
+    client.propose_recovery_address(&admin, &Address::generate(&env));
+    client.approve_recovery_address(&admin);
+    env.ledger().with_mut(|li| li.timestamp += 1000);
+    assert_eq!(
+        client.try_execute_recovery_address(&admin),
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
+    assert_eq!(client.get_recovery_address(), None);
+}
+
+#[test]
+fn test_upgrade_requires_approvals_and_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    client.set_unpause_timelock(&admin1, &100u64);
+
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        client.try_approve_upgrade(&admin1),
+        Err(Ok(earn_quest::errors::Error::NoPendingProposal))
+    );
+    assert_eq!(
+        client.try_upgrade(&admin1),
+        Err(Ok(earn_quest::errors::Error::NoPendingProposal))
+    );
+    assert_eq!(
+        client.try_propose_upgrade(&Address::generate(&env), &wasm_hash),
+        Err(Ok(earn_quest::errors::Error::Unauthorized))
+    );
+
+    client.propose_upgrade(&admin1, &wasm_hash);
+    client.approve_upgrade(&admin1);
+    env.ledger().with_mut(|li| li.timestamp += 1000);
+    assert_eq!(
+        client.try_upgrade(&admin1),
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
+
+    client.approve_upgrade(&admin2);
+    assert_eq!(
+        client.try_upgrade(&admin1),
+        Err(Ok(earn_quest::errors::Error::TimelockNotExpired))
+    );
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.value, ProposalValue::WasmHash(wasm_hash));
+    assert_eq!(pending.approvers.len(), 2);
+}
+
+#[test]
+fn test_new_recovery_proposal_resets_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+
+    client.propose_recovery_address(&admin1, &Address::generate(&env));
+    client.approve_recovery_address(&admin1);
+
+    // A second proposal replaces the first and starts from zero approvals
+    let replacement = Address::generate(&env);
+    client.propose_recovery_address(&admin2, &replacement);
+    let pending = client.get_pending_recovery().unwrap();
+    assert_eq!(pending.value, ProposalValue::Address(replacement));
+    assert_eq!(pending.approvers.len(), 0);
+
+    client.approve_recovery_address(&admin1);
+    assert_eq!(client.get_pending_recovery().unwrap().approvers.len(), 1);
+}
+
+#[test]
+fn test_non_admin_cannot_propose_recovery_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let res = client.try_propose_recovery_address(&stranger, &stranger);
+    assert_eq!(res, Err(Ok(earn_quest::errors::Error::Unauthorized)));
+}
+
+#[test]
+fn test_unpause_approvals_reset_between_pause_cycles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+
+    for _ in 0..2 {
+        client.emergency_pause(&admin1);
+        assert_eq!(
+            client.try_emergency_unpause(&admin1),
+            Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+        );
+
+        client.emergency_approve_unpause(&admin1);
+        client.emergency_approve_unpause(&admin2);
+        client.emergency_unpause(&admin2);
+    }
+}
+
+#[test]
+fn test_unpause_approvals_only_collected_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+
+    assert_eq!(
+        client.try_emergency_approve_unpause(&admin1),
+        Err(Ok(earn_quest::errors::Error::InvalidStatusTransition))
+    );
+
+    // Pausing again discards approvals gathered so far
+    client.emergency_pause(&admin1);
+    client.emergency_approve_unpause(&admin1);
+    client.emergency_pause(&admin2);
+    client.emergency_approve_unpause(&admin2);
+    assert_eq!(
+        client.try_emergency_unpause(&admin1),
+        Err(Ok(earn_quest::errors::Error::InsufficientApprovals))
+    );
 
-    // Ensure admin received funds
-    let bal = token_client.balance(&admin);
-    assert_eq!(bal, 500);
+    client.emergency_approve_unpause(&admin1);
+    client.emergency_unpause(&admin1);
 }
 
 #[test]
@@ -73,7 +361,7 @@ fn test_multisig_approve_and_unpause_with_zero_timelock() {
     let admin2 = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
+    add_second_admin(&client, &admin1, &admin2);
 
     // Configure threshold=2 and timelock=0
     client.set_unpause_threshold(&admin1, &2u32);
@@ -95,9 +383,17 @@ fn test_multisig_approve_and_unpause_with_zero_timelock() {
     let token_admin = Address::generate(&env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_contract = token_contract_obj.address();
+    allow_asset(&client, &admin1, &token_contract);
 
     let quest_id = symbol_short!("SQ2");
-    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &10000,
+    );
 }
 
 #[test]
@@ -111,7 +407,7 @@ fn test_unpause_requires_enough_approvals() {
     let admin2 = Address::generate(&env);
 
     client.initialize(&admin1);
-    client.add_admin(&admin1, &admin2);
+    add_second_admin(&client, &admin1, &admin2);
 
     client.set_unpause_threshold(&admin1, &2u32);
     client.set_unpause_timelock(&admin1, &0u64);
@@ -124,3 +420,327 @@ fn test_unpause_requires_enough_approvals() {
     // Unpause should fail with InsufficientApprovals
     client.emergency_unpause(&admin1);
 }
+
+#[test]
+fn test_paused_approvals_leave_claims_working() {
+    use earn_quest::errors::Error;
+    use earn_quest::types::{PAUSE_APPROVALS, PAUSE_SUBMISSIONS};
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let user_a = Address::generate(&env);
+    let user_b = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    allow_asset(&client, &admin, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+
+    client.initialize(&admin);
+
+    let quest_id = symbol_short!("SQ3");
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &10000,
+    );
+    StellarAssetClient::new(&env, &token_contract).mint(&creator, &1000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &1000);
+    client.submit_proof(&quest_id, &user_a, &proof);
+    client.approve_submission(&quest_id, &user_a, &verifier);
+    client.submit_proof(&quest_id, &user_b, &proof);
+
+    // A bad verifier: stop approvals only
+    let flags = client.pause_operations(&admin, &PAUSE_APPROVALS);
+    assert_eq!(flags, PAUSE_APPROVALS);
+    assert_eq!(client.get_pause_flags(), PAUSE_APPROVALS);
+
+    let res = client.try_approve_submission(&quest_id, &user_b, &verifier);
+    assert_eq!(res, Err(Ok(Error::Paused)));
+
+    // Already-earned rewards can still be claimed
+    client.claim_reward(&quest_id, &user_a);
+    assert_eq!(token_client.balance(&user_a), 100);
+
+    // Categories accumulate and are lifted independently
+    client.pause_operations(&admin, &PAUSE_SUBMISSIONS);
+    assert_eq!(
+        client.get_pause_flags(),
+        PAUSE_APPROVALS | PAUSE_SUBMISSIONS
+    );
+    client.unpause_operations(&admin, &PAUSE_APPROVALS);
+    assert_eq!(client.get_pause_flags(), PAUSE_SUBMISSIONS);
+
+    client.approve_submission(&quest_id, &user_b, &verifier);
+    let res = client.try_submit_proof(&quest_id, &Address::generate(&env), &proof);
+    assert_eq!(res, Err(Ok(Error::Paused)));
+}
+
+#[test]
+fn test_pause_operations_validation() {
+    use earn_quest::errors::Error;
+    use earn_quest::types::{PAUSE_ALL, PAUSE_CLAIMS};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let res = client.try_pause_operations(&stranger, &PAUSE_CLAIMS);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    let res = client.try_pause_operations(&admin, &0u32);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    let res = client.try_pause_operations(&admin, &(PAUSE_ALL + 1));
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    // Lifting a category does not lift the global emergency pause
+    client.emergency_pause(&admin);
+    client.pause_operations(&admin, &PAUSE_CLAIMS);
+    client.unpause_operations(&admin, &PAUSE_CLAIMS);
+    assert_eq!(client.get_pause_flags(), 0);
+
+    let res = client.try_add_admin(&admin, &stranger);
+    assert_eq!(res, Err(Ok(Error::Paused)));
+}
+
+#[test]
+fn test_circuit_breaker_trips_on_payout_volume() {
+    use earn_quest::errors::Error;
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    allow_asset(&client, &admin1, &token_contract);
+
+    client.initialize(&admin1);
+    add_second_admin(&client, &admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    client.set_unpause_timelock(&admin1, &0u64);
+
+    // At most 250 per hour
+    client.set_circuit_breaker(&admin1, &token_contract, &3600u64, &250i128, &0u32);
+
+    let quest_id = symbol_short!("CB1");
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &10000,
+    );
+    StellarAssetClient::new(&env, &token_contract).mint(&creator, &10_000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &10_000);
+
+    let mut users = soroban_sdk::Vec::new(&env);
+    for _ in 0..4 {
+        let user = Address::generate(&env);
+        client.submit_proof(&quest_id, &user, &proof);
+        client.approve_submission(&quest_id, &user, &verifier);
+        users.push_back(user);
+    }
+
+    let token = TokenClient::new(&env, &token_contract);
+    assert!(client.claim_reward(&quest_id, &users.get(0).unwrap()));
+    assert!(client.claim_reward(&quest_id, &users.get(1).unwrap()));
+    let window = client.get_payout_window(&token_contract).unwrap();
+    assert_eq!(window.total_paid, 200);
+    assert_eq!(window.payout_count, 2);
+
+    // A third payout would push the window to 300 > 250: it is not paid and
+    // the breaker trips instead
+    let third = users.get(2).unwrap();
+    assert!(!client.claim_reward(&quest_id, &third));
+    assert_eq!(token.balance(&third), 0);
+    assert_eq!(
+        client.get_submission(&quest_id, &third).status,
+        earn_quest::types::SubmissionStatus::Approved
+    );
+    assert_eq!(
+        client
+            .get_payout_window(&token_contract)
+            .unwrap()
+            .total_paid,
+        200
+    );
+
+    let res = client.try_claim_reward(&quest_id, &users.get(3).unwrap());
+    assert_eq!(res, Err(Ok(Error::Paused)));
+
+    // Normal M-of-N unpause flow restores payouts, once the window has room
+    client.emergency_approve_unpause(&admin1);
+    client.emergency_approve_unpause(&admin2);
+    client.emergency_unpause(&admin1);
+    env.ledger().with_mut(|li| li.timestamp += 3600 + 450);
+    assert!(client.claim_reward(&quest_id, &third));
+    assert_eq!(token.balance(&third), 100);
+}
+
+#[test]
+fn test_circuit_breaker_window_rolls_instead_of_resetting() {
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token_contract = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&client, &admin, &token_contract);
+    client.initialize(&admin);
+
+    // At most 200 per 800 seconds, tracked in 100-second sub-windows
+    client.set_circuit_breaker(&admin, &token_contract, &800u64, &200i128, &0u32);
+
+    let quest_id = symbol_short!("CB3");
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &100_000,
+    );
+    StellarAssetClient::new(&env, &token_contract).mint(&creator, &1000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &1000);
+
+    let mut users = soroban_sdk::Vec::new(&env);
+    for _ in 0..3 {
+        let user = Address::generate(&env);
+        client.submit_proof(&quest_id, &user, &proof);
+        client.approve_submission(&quest_id, &user, &verifier);
+        users.push_back(user);
+    }
+
+    // Two payouts late in one fixed-length period...
+    env.ledger().with_mut(|li| li.timestamp = 750);
+    client.claim_reward(&quest_id, &users.get(0).unwrap());
+    client.claim_reward(&quest_id, &users.get(1).unwrap());
+
+    // ...still count just after it would have reset
+    env.ledger().with_mut(|li| li.timestamp = 850);
+    client.claim_reward(&quest_id, &users.get(2).unwrap());
+    let window = client.get_payout_window(&token_contract).unwrap();
+    assert_eq!(window.total_paid, 200);
+    assert_eq!(window.buckets.len(), 1);
+    let res = client.try_claim_reward(&quest_id, &users.get(2).unwrap());
+    assert_eq!(res, Err(Ok(earn_quest::errors::Error::Paused)));
+}
+
+#[test]
+fn test_circuit_breaker_balance_share_and_window_reset() {
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    allow_asset(&client, &admin, &token_contract);
+
+    client.initialize(&admin);
+
+    // 15% of the balance at window start (1000 -> 150) per 100 seconds
+    client.set_circuit_breaker(&admin, &token_contract, &100u64, &0i128, &1500u32);
+
+    let quest_id = symbol_short!("CB2");
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &token_contract,
+        &100,
+        &verifier,
+        &10000,
+    );
+    StellarAssetClient::new(&env, &token_contract).mint(&creator, &1000);
+    client.deposit_escrow(&quest_id, &creator, &token_contract, &1000);
+
+    let user_a = Address::generate(&env);
+    let user_b = Address::generate(&env);
+    for user in [&user_a, &user_b] {
+        client.submit_proof(&quest_id, user, &proof);
+        client.approve_submission(&quest_id, user, &verifier);
+    }
+
+    client.claim_reward(&quest_id, &user_a);
+    let window = client.get_payout_window(&token_contract).unwrap();
+    assert_eq!(window.total_paid, 100);
+
+    // Payouts roll out of the window once it has elapsed
+    env.ledger().with_mut(|li| li.timestamp += 200);
+    client.claim_reward(&quest_id, &user_b);
+    let window = client.get_payout_window(&token_contract).unwrap();
+    assert_eq!(window.total_paid, 100);
+    assert_eq!(window.payout_count, 1);
+}
+
+#[test]
+fn test_circuit_breaker_config_validation() {
+    use earn_quest::errors::Error;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let res = client.try_set_circuit_breaker(&stranger, &asset, &60u64, &100i128, &0u32);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    let res = client.try_set_circuit_breaker(&admin, &asset, &0u64, &100i128, &0u32);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    let res = client.try_set_circuit_breaker(&admin, &asset, &60u64, &0i128, &10_001u32);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig)));
+
+    client.set_circuit_breaker(&admin, &asset, &60u64, &100i128, &0u32);
+    assert!(client.get_circuit_breaker(&asset).is_some());
+
+    // Zero limits remove the breaker
+    client.set_circuit_breaker(&admin, &asset, &60u64, &0i128, &0u32);
+    assert_eq!(client.get_circuit_breaker(&asset), None);
+}
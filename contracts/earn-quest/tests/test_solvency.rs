@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, FeeMode};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract_id: Address,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    verifier: Address,
+    token_address: Address,
+    token_admin_client: token::StellarAssetClient<'a>,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_address);
+    token_admin_client.mint(&creator, &100_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    TestEnv {
+        env,
+        contract_id,
+        contract,
+        admin,
+        creator,
+        verifier,
+        token_address,
+        token_admin_client,
+    }
+}
+
+fn funded_quest(t: &TestEnv, id: &Symbol, deposit: i128) {
+    t.contract
+        .register_quest(id, &t.creator, &t.token_address, &1000, &t.verifier, &99999);
+    t.contract
+        .deposit_escrow(id, &t.creator, &t.token_address, &deposit);
+}
+
+fn complete(t: &TestEnv, id: &Symbol) {
+    let user = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(id, &user, &proof);
+    t.contract.approve_submission(id, &user, &t.verifier);
+    t.contract.claim_reward(id, &user);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Audit
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_ledger_balanced_through_escrow_lifecycle() {
+    let t = setup();
+    t.contract
+        .set_fee_config(&t.admin, &100, &FeeMode::OnDeposit);
+
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    funded_quest(&t, &q1, 5000);
+    funded_quest(&t, &q2, 3000);
+
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.balance, 8000);
+    assert_eq!(report.escrow_liability, 7920);
+    assert_eq!(report.treasury_liability, 80);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(report.deficit, 0);
+
+    complete(&t, &q1);
+    t.contract.cancel_quest(&q2, &t.creator);
+
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.balance, 8000 - 1000 - 2970);
+    assert_eq!(report.escrow_liability, 4950 - 1000);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(report.deficit, 0);
+}
+
+/// Register a second admin and require both to approve proposals.
+fn add_second_admin(t: &TestEnv) -> Address {
+    let admin2 = Address::generate(&t.env);
+    t.contract.add_admin(&t.admin, &admin2);
+    t.contract.approve_add_admin(&t.admin);
+    t.contract.execute_add_admin(&t.admin);
+    t.contract.set_unpause_threshold(&t.admin, &2u32);
+    admin2
+}
+
+#[test]
+fn test_emergency_withdraw_is_booked_as_recovered() {
+    let t = setup();
+    funded_quest(&t, &symbol_short!("q1"), 5000);
+
+    let recovery = Address::generate(&t.env);
+    let admin2 = add_second_admin(&t);
+    t.contract.propose_recovery_address(&t.admin, &recovery);
+    t.contract.approve_recovery_address(&t.admin);
+    t.contract.approve_recovery_address(&admin2);
+    t.contract.execute_recovery_address(&t.admin);
+    t.contract.emergency_pause(&t.admin);
+    t.contract
+        .emergency_withdraw(&t.admin, &t.token_address, &1200);
+
+    // The withdrawn tokens still back the escrow they were taken from
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.balance, 3800);
+    assert_eq!(report.escrow_liability, 5000);
+    assert_eq!(report.recovered, 1200);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(report.deficit, 0);
+
+    // Returning them restores the balance without creating a sweepable surplus
+    assert_eq!(
+        t.contract
+            .try_return_recovered(&recovery, &t.token_address, &1201),
+        Err(Ok(Error::InvalidRewardAmount))
+    );
+    t.contract
+        .return_recovered(&recovery, &t.token_address, &1200);
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.balance, 5000);
+    assert_eq!(report.recovered, 0);
+    assert_eq!(report.surplus, 0);
+    assert_eq!(report.deficit, 0);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Sweep
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_sweep_surplus_credits_treasury() {
+    let t = setup();
+    funded_quest(&t, &symbol_short!("q1"), 5000);
+    let admin2 = add_second_admin(&t);
+
+    // Tokens sent straight to the contract are not owed to anyone
+    t.token_admin_client.mint(&t.contract_id, &300);
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.surplus, 300);
+
+    let stranger = Address::generate(&t.env);
+    assert_eq!(
+        t.contract.try_propose_sweep(&stranger, &t.token_address),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // A single admin cannot sweep
+    t.contract.propose_sweep(&t.admin, &t.token_address);
+    t.contract.approve_sweep(&t.admin);
+    assert_eq!(
+        t.contract.try_sweep_surplus(&t.admin),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    t.contract.approve_sweep(&admin2);
+    assert_eq!(t.contract.sweep_surplus(&t.admin), 300);
+    assert_eq!(t.contract.get_treasury_balance(&t.token_address), 300);
+    assert_eq!(t.contract.get_pending_sweep(), None);
+
+    let report = t.contract.audit_solvency(&t.token_address);
+    assert_eq!(report.treasury_liability, 300);
+    assert_eq!(report.surplus, 0);
+
+    t.contract.propose_sweep(&t.admin, &t.token_address);
+    t.contract.approve_sweep(&t.admin);
+    t.contract.approve_sweep(&admin2);
+    assert_eq!(
+        t.contract.try_sweep_surplus(&t.admin),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+}
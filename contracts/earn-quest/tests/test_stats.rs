@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
+
+use earn_quest::types::{AssetConfig, FeeMode};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    admin: Address,
+    creator: Address,
+    other_creator: Address,
+    verifier: Address,
+    token_address: Address,
+}
+
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let other_creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_address);
+    token_admin_client.mint(&creator, &100_000);
+    token_admin_client.mint(&other_creator, &100_000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    TestEnv {
+        env,
+        contract,
+        admin,
+        creator,
+        other_creator,
+        verifier,
+        token_address,
+    }
+}
+
+fn register(t: &TestEnv, id: &Symbol, creator: &Address) {
+    t.contract
+        .register_quest(id, creator, &t.token_address, &1000, &t.verifier, &10000);
+}
+
+fn submit(t: &TestEnv, id: &Symbol) -> Address {
+    let submitter = Address::generate(&t.env);
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    t.contract.submit_proof(id, &submitter, &proof);
+    submitter
+}
+
+// ══════════════════════════════════════════════════════════════
+// Counters
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_stats_start_empty() {
+    let t = setup();
+    let stats = t.contract.get_platform_stats();
+    assert_eq!(stats.quests_active, 0);
+    assert_eq!(stats.total_submissions, 0);
+    assert_eq!(stats.paid_volume.len(), 0);
+    assert_eq!(t.contract.get_creator_stats(&t.creator), stats);
+}
+
+#[test]
+fn test_stats_follow_quest_lifecycle() {
+    let t = setup();
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    let q3 = symbol_short!("q3");
+    register(&t, &q1, &t.creator);
+    register(&t, &q2, &t.creator);
+    register(&t, &q3, &t.other_creator);
+    assert_eq!(t.contract.get_platform_stats().quests_unfunded, 3);
+
+    t.contract
+        .deposit_escrow(&q1, &t.creator, &t.token_address, &5000);
+    t.contract
+        .deposit_escrow(&q2, &t.creator, &t.token_address, &2000);
+    t.contract
+        .deposit_escrow(&q3, &t.other_creator, &t.token_address, &3000);
+
+    let a = submit(&t, &q1);
+    let b = submit(&t, &q1);
+    submit(&t, &q3);
+    t.contract.approve_submission(&q1, &a, &t.verifier);
+    t.contract.reject_submission(&q1, &b, &t.verifier);
+    t.contract.claim_reward(&q1, &a);
+
+    t.contract.cancel_quest(&q2, &t.creator);
+
+    let mine = t.contract.get_creator_stats(&t.creator);
+    assert_eq!(mine.quests_active, 1);
+    assert_eq!(mine.quests_cancelled, 1);
+    assert_eq!(mine.total_submissions, 2);
+    assert_eq!(mine.total_approvals, 1);
+    assert_eq!(mine.total_claims, 1);
+    assert_eq!(mine.paid_volume.get(t.token_address.clone()), Some(1000));
+    // q1: 5000 - 1000 paid; q2 fully refunded
+    assert_eq!(mine.active_escrow.get(t.token_address.clone()), Some(4000));
+
+    let platform = t.contract.get_platform_stats();
+    assert_eq!(platform.quests_unfunded, 0);
+    assert_eq!(platform.quests_active, 2);
+    assert_eq!(platform.quests_cancelled, 1);
+    assert_eq!(platform.total_submissions, 3);
+    assert_eq!(platform.total_approvals, 1);
+    assert_eq!(platform.total_claims, 1);
+    assert_eq!(
+        platform.paid_volume.get(t.token_address.clone()),
+        Some(1000)
+    );
+    assert_eq!(
+        platform.active_escrow.get(t.token_address.clone()),
+        Some(7000)
+    );
+}
+
+#[test]
+fn test_stats_track_escrow_net_of_fees() {
+    let t = setup();
+    t.contract
+        .set_fee_config(&t.admin, &500, &FeeMode::OnPayout);
+
+    let qid = symbol_short!("q1");
+    register(&t, &qid, &t.creator);
+    t.contract
+        .deposit_escrow(&qid, &t.creator, &t.token_address, &2000);
+
+    let user = submit(&t, &qid);
+    t.contract.approve_submission(&qid, &user, &t.verifier);
+    t.contract.claim_reward(&qid, &user);
+
+    // 5% fee withheld from the 1000 reward
+    let stats = t.contract.get_creator_stats(&t.creator);
+    assert_eq!(stats.paid_volume.get(t.token_address.clone()), Some(950));
+    assert_eq!(stats.active_escrow.get(t.token_address.clone()), Some(1000));
+
+    t.contract.cancel_quest(&qid, &t.creator);
+    let stats = t.contract.get_creator_stats(&t.creator);
+    assert_eq!(stats.active_escrow.get(t.token_address.clone()), None);
+}
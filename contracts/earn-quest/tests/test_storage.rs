@@ -1,10 +1,20 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    vec, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Vec,
+};
 
 extern crate earn_quest;
-use earn_quest::storage;
+use earn_quest::errors::Error;
+use earn_quest::storage::{self, DataKey};
 use earn_quest::types::Badge;
+use earn_quest::types::{
+    AssetConfig, FeeMode, LegacyEscrowInfo, Quest, QuestStatus, SubmissionKey, TtlConfig,
+};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 //================================================================================
@@ -17,6 +27,34 @@ fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
     (contract_id, client)
 }
 
+fn allow_asset(env: &Env, client: &EarnQuestContractClient, asset: &Address) {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        asset,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+}
+
+/// Mint a quest's reward to its creator and lock it in escrow so the quest opens
+fn fund_quest(
+    env: &Env,
+    client: &EarnQuestContractClient,
+    token: &Address,
+    quest_id: &Symbol,
+    creator: &Address,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, token).mint(creator, &amount);
+    client.deposit_escrow(quest_id, creator, token, &amount);
+}
+
 //================================================================================
 // Quest Storage Tests (Through Contract)
 //================================================================================
@@ -31,6 +69,7 @@ fn test_quest_storage_through_contract() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     // Register quest (stores it)
@@ -49,12 +88,16 @@ fn test_submission_storage_through_contract() {
 
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
     // Register quest
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 1000);
 
     // Submit proof (stores submission)
     let proof = BytesN::from_array(&env, &[1u8; 32]);
@@ -90,6 +133,7 @@ fn test_storage_isolation_between_quests() {
     let quest_id2 = symbol_short!("QUEST2");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     // Register two quests
@@ -109,13 +153,17 @@ fn test_storage_isolation_between_submissions() {
 
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let submitter1 = Address::generate(&env);
     let submitter2 = Address::generate(&env);
 
     // Register quest
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 1000);
 
     // Two different users submit
     let proof1 = BytesN::from_array(&env, &[1u8; 32]);
@@ -202,12 +250,16 @@ fn test_quest_lifecycle_with_storage_operations() {
 
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
     // 1. Register quest (set_quest)
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 1000);
 
     // 2. Submit proof (set_submission)
     let proof = BytesN::from_array(&env, &[1u8; 32]);
@@ -276,12 +328,14 @@ fn test_concurrent_operations_storage() {
     env.mock_all_auths();
 
     let (_, client) = setup_contract(&env);
+    let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
 
     // Create multiple quests with unique IDs
     client.register_quest(
         &symbol_short!("QUEST1"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &1000,
         &Address::generate(&env),
         &10000,
@@ -290,7 +344,7 @@ fn test_concurrent_operations_storage() {
     client.register_quest(
         &symbol_short!("QUEST2"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &2000,
         &Address::generate(&env),
         &10000,
@@ -299,7 +353,7 @@ fn test_concurrent_operations_storage() {
     client.register_quest(
         &symbol_short!("QUEST3"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &3000,
         &Address::generate(&env),
         &10000,
@@ -318,12 +372,16 @@ fn test_storage_key_collision_prevention() {
 
     let quest_id = symbol_short!("TEST");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
     // Register quest with "TEST" id
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 1000);
 
     // Submit proof with "TEST" quest id and specific submitter
     let proof = BytesN::from_array(&env, &[1u8; 32]);
@@ -347,6 +405,7 @@ fn test_error_on_duplicate_quest() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     // Register quest
@@ -374,3 +433,265 @@ fn test_storage_documentation_exists() {
     // This test passes if the module compiles with all documentation
     // No runtime assertion needed - compile-time check
 }
+
+//================================================================================
+// Persistent Storage & TTL Tests
+//================================================================================
+
+fn quest_ttl(env: &Env, contract_id: &Address, quest_id: &soroban_sdk::Symbol) -> u32 {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Quest(quest_id.clone()))
+    })
+}
+
+#[test]
+fn test_quest_written_to_persistent_storage_with_extended_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+
+    let quest_id = symbol_short!("QUEST1");
+    let creator = Address::generate(&env);
+    let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
+    let verifier = Address::generate(&env);
+
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+
+    env.as_contract(&contract_id, || {
+        let key = DataKey::Quest(quest_id.clone());
+        assert!(env.storage().persistent().has(&key));
+        assert!(!env.storage().instance().has(&key));
+    });
+    assert_eq!(
+        quest_ttl(&env, &contract_id, &quest_id),
+        storage::DEFAULT_TTL_EXTEND_TO
+    );
+}
+
+#[test]
+fn test_bump_ttl_restores_full_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+
+    let admin = Address::generate(&env);
+    let quest_id = symbol_short!("QUEST1");
+    let creator = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        &token,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+    let verifier = Address::generate(&env);
+
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+
+    env.ledger().with_mut(|l| l.sequence_number += 100_000);
+    assert_eq!(
+        quest_ttl(&env, &contract_id, &quest_id),
+        storage::DEFAULT_TTL_EXTEND_TO - 100_000
+    );
+
+    client.bump_ttl(&admin, &quest_id);
+    assert_eq!(
+        quest_ttl(&env, &contract_id, &quest_id),
+        storage::DEFAULT_TTL_EXTEND_TO
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_bump_ttl(&stranger, &quest_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_bump_ttl(&admin, &symbol_short!("NOPE")),
+        Err(Ok(Error::QuestNotFound))
+    );
+}
+
+#[test]
+fn test_set_ttl_config_validates_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let config = TtlConfig {
+        threshold: 1_000,
+        extend_to: 10_000,
+    };
+    client.set_ttl_config(&admin, &config);
+    assert_eq!(client.get_ttl_config(), config);
+
+    let inverted = TtlConfig {
+        threshold: 10_000,
+        extend_to: 1_000,
+    };
+    assert_eq!(
+        client.try_set_ttl_config(&admin, &inverted),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    let too_long = TtlConfig {
+        threshold: 1_000,
+        extend_to: u32::MAX,
+    };
+    assert_eq!(
+        client.try_set_ttl_config(&admin, &too_long),
+        Err(Ok(Error::InvalidConfig))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_ttl_config(&stranger, &config),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_migrate_storage_moves_instance_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let quest_id = symbol_short!("LEGACY");
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let creator = Address::generate(&env);
+    let quest = Quest {
+        id: quest_id.clone(),
+        creator: creator.clone(),
+        reward_asset: token.clone(),
+        reward_amount: 1000,
+        verifier: Address::generate(&env),
+        deadline: 10000,
+        status: QuestStatus::Active,
+        total_claims: 0,
+    };
+    let legacy_escrow = LegacyEscrowInfo {
+        quest_id: quest_id.clone(),
+        depositor: creator.clone(),
+        token: token.clone(),
+        total_deposited: 5000,
+        total_paid_out: 1000,
+        total_refunded: 0,
+        is_active: true,
+    };
+
+    // Simulate entries written by a contract version that used instance storage,
+    // before escrows recorded platform fees
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::Quest(quest_id.clone()), &quest);
+        env.storage()
+            .instance()
+            .set(&DataKey::Escrow(quest_id.clone()), &legacy_escrow);
+    });
+
+    let migrated = client.migrate_storage(
+        &admin,
+        &vec![&env, quest_id.clone()],
+        &Vec::<SubmissionKey>::new(&env),
+        &Vec::<Address>::new(&env),
+    );
+    assert_eq!(migrated, 2);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::Quest(quest_id.clone())));
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::Escrow(quest_id.clone())));
+        assert_eq!(storage::get_quest(&env, &quest_id), Ok(quest.clone()));
+    });
+
+    let escrow = client.get_escrow_info(&quest_id);
+    assert_eq!(escrow.total_deposited, 5000);
+    assert_eq!(escrow.total_paid_out, 1000);
+    assert_eq!(escrow.total_fees, 0);
+    assert_eq!(escrow.fee_mode, FeeMode::OnPayout);
+
+    // The escrow's remaining balance is booked as a liability
+    assert_eq!(client.audit_solvency(&token).escrow_liability, 4000);
+
+    // Running the migration again is a no-op
+    let migrated = client.migrate_storage(
+        &admin,
+        &vec![&env, quest_id.clone()],
+        &Vec::<SubmissionKey>::new(&env),
+        &Vec::<Address>::new(&env),
+    );
+    assert_eq!(migrated, 0);
+}
+
+#[test]
+fn test_mark_unfunded_quests_closes_legacy_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let funded = symbol_short!("FUNDED");
+    client.register_quest(&funded, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &funded, &creator, 1000);
+
+    // Simulate a quest opened by a version that did not require escrow
+    let legacy = symbol_short!("LEGACY");
+    client.register_quest(&legacy, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &legacy, &creator, 1000);
+    env.as_contract(&contract_id, || storage::delete_escrow(&env, &legacy));
+    assert_eq!(client.get_creator_stats(&creator).quests_active, 2);
+
+    let quest_ids = vec![&env, funded.clone(), legacy.clone()];
+    assert_eq!(
+        client.try_mark_unfunded_quests(&creator, &quest_ids),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.mark_unfunded_quests(&admin, &quest_ids), 1);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("unfunded"), admin.clone()).into_val(&env)
+    );
+    assert_eq!(u32::try_from_val(&env, &event.2).unwrap(), 1);
+
+    let stats = client.get_creator_stats(&creator);
+    assert_eq!((stats.quests_active, stats.quests_unfunded), (1, 1));
+
+    assert_eq!(client.get_quest(&funded).status, QuestStatus::Active);
+    assert_eq!(client.get_quest(&legacy).status, QuestStatus::Unfunded);
+
+    // Funding the legacy quest reopens it
+    fund_quest(&env, &client, &token, &legacy, &creator, 1000);
+    assert_eq!(client.get_quest(&legacy).status, QuestStatus::Active);
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Symbol};
+
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, DuplicateAction, ProofPolicy, ProofScope, ProofUse};
+use earn_quest::validation::MAX_REWARD_AMOUNT;
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+// ──────────────────────────────────────────────
+// Test setup helper
+// ──────────────────────────────────────────────
+
+struct TestEnv<'a> {
+    env: Env,
+    contract: EarnQuestContractClient<'a>,
+    creator: Address,
+    q1: Symbol,
+    q2: Symbol,
+}
+
+/// Two funded quests by the same creator.
+fn setup() -> TestEnv<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let contract = EarnQuestContractClient::new(&env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&creator, &2000);
+
+    contract.initialize(&admin);
+    contract.set_asset_config(
+        &admin,
+        &token_address,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let q1 = symbol_short!("q1");
+    let q2 = symbol_short!("q2");
+    for id in [&q1, &q2] {
+        contract.register_quest(id, &creator, &token_address, &100, &verifier, &99999);
+        contract.deposit_escrow(id, &creator, &token_address, &1000);
+    }
+
+    TestEnv {
+        env,
+        contract,
+        creator,
+        q1,
+        q2,
+    }
+}
+
+fn policy(scope: ProofScope, on_duplicate: DuplicateAction) -> ProofPolicy {
+    ProofPolicy {
+        scope,
+        on_duplicate,
+    }
+}
+
+// ══════════════════════════════════════════════════════════════
+// Scopes
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_unchecked_quests_accept_reused_hashes() {
+    let t = setup();
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    let a = Address::generate(&t.env);
+    let b = Address::generate(&t.env);
+
+    t.contract.submit_proof(&t.q1, &a, &proof);
+    t.contract.submit_proof(&t.q1, &b, &proof);
+    assert_eq!(t.contract.get_proof_first_use(&t.q1, &proof), None);
+    assert_eq!(t.contract.get_duplicate_flag(&t.q1, &b), None);
+}
+
+#[test]
+fn test_quest_scope_rejects_reuse_within_quest() {
+    let t = setup();
+    t.contract.set_proof_policy(
+        &t.q1,
+        &t.creator,
+        &policy(ProofScope::Quest, DuplicateAction::Reject),
+    );
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    let a = Address::generate(&t.env);
+    let b = Address::generate(&t.env);
+
+    t.contract.submit_proof(&t.q1, &a, &proof);
+    assert_eq!(
+        t.contract.try_submit_proof(&t.q1, &b, &proof),
+        Err(Ok(Error::DuplicateProof))
+    );
+    assert_eq!(
+        t.contract.get_proof_first_use(&t.q1, &proof),
+        Some(ProofUse {
+            quest_id: t.q1.clone(),
+            submitter: a.clone(),
+        })
+    );
+
+    // Resubmitting one's own hash is fine, and other quests are unaffected
+    t.contract.submit_proof(&t.q1, &a, &proof);
+    t.contract.submit_proof(&t.q2, &b, &proof);
+}
+
+#[test]
+fn test_global_scope_flags_reuse_across_quests() {
+    let t = setup();
+    for id in [&t.q1, &t.q2] {
+        t.contract.set_proof_policy(
+            id,
+            &t.creator,
+            &policy(ProofScope::Global, DuplicateAction::Flag),
+        );
+    }
+    let proof = BytesN::from_array(&t.env, &[1u8; 32]);
+    let a = Address::generate(&t.env);
+    let b = Address::generate(&t.env);
+
+    t.contract.submit_proof(&t.q1, &a, &proof);
+    t.contract.submit_proof(&t.q2, &b, &proof);
+
+    let first = ProofUse {
+        quest_id: t.q1.clone(),
+        submitter: a.clone(),
+    };
+    assert_eq!(
+        t.contract.get_proof_first_use(&t.q2, &proof),
+        Some(first.clone())
+    );
+    assert_eq!(t.contract.get_duplicate_flag(&t.q2, &b), Some(first));
+    assert_eq!(t.contract.get_duplicate_flag(&t.q1, &a), None);
+
+    // A fresh proof clears the flag
+    t.contract
+        .submit_proof(&t.q2, &b, &BytesN::from_array(&t.env, &[2u8; 32]));
+    assert_eq!(t.contract.get_duplicate_flag(&t.q2, &b), None);
+}
+
+// ══════════════════════════════════════════════════════════════
+// Access control
+// ══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_only_creator_sets_proof_policy() {
+    let t = setup();
+    let p = policy(ProofScope::Quest, DuplicateAction::Flag);
+    assert_eq!(
+        t.contract
+            .try_set_proof_policy(&t.q1, &Address::generate(&t.env), &p),
+        Err(Ok(Error::Unauthorized))
+    );
+    t.contract.set_proof_policy(&t.q1, &t.creator, &p);
+    assert_eq!(t.contract.get_proof_policy(&t.q1), p);
+}
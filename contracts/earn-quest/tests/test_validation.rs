@@ -1,10 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, BytesN, Env};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Ledger, token::StellarAssetClient, Address,
+    BytesN, Env, Symbol,
+};
 
 extern crate earn_quest;
-use earn_quest::types::{QuestStatus, SubmissionStatus};
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, QuestStatus, SubmissionStatus};
 use earn_quest::validation;
+use earn_quest::validation::MAX_REWARD_AMOUNT;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 //================================================================================
@@ -17,6 +22,34 @@ fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
     (contract_id, client)
 }
 
+fn allow_asset(env: &Env, client: &EarnQuestContractClient, asset: &Address) {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        asset,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+}
+
+/// Mint a quest's reward to its creator and lock it in escrow so the quest opens
+fn fund_quest(
+    env: &Env,
+    client: &EarnQuestContractClient,
+    token: &Address,
+    quest_id: &Symbol,
+    creator: &Address,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, token).mint(creator, &amount);
+    client.deposit_escrow(quest_id, creator, token, &amount);
+}
+
 //================================================================================
 // Address Validation Tests
 //================================================================================
@@ -50,6 +83,7 @@ fn test_register_quest_creator_verifier_same_address_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
 
     // Set a future deadline
     env.ledger().with_mut(|li| {
@@ -58,11 +92,7 @@ fn test_register_quest_creator_verifier_same_address_rejected() {
 
     // Creator and verifier are the same address - should fail
     let result = client.try_register_quest(
-        &quest_id,
-        &creator,
-        &token,
-        &1000,
-        &creator, // same as creator
+        &quest_id, &creator, &token, &1000, &creator, // same as creator
         &5000,
     );
 
@@ -119,6 +149,7 @@ fn test_register_quest_zero_reward_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -139,6 +170,7 @@ fn test_register_quest_negative_reward_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -159,6 +191,7 @@ fn test_register_quest_max_reward_valid() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -186,6 +219,7 @@ fn test_register_quest_exceeds_max_reward_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -245,6 +279,7 @@ fn test_register_quest_deadline_in_past_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     // Set current ledger time to 5000
@@ -267,14 +302,14 @@ fn test_register_quest_deadline_in_future_valid() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 1000;
     });
 
-    let result =
-        client.try_register_quest(&quest_id, &creator, &token, &500, &verifier, &10000);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &500, &verifier, &10000);
     assert!(result.is_ok());
 }
 
@@ -372,82 +407,80 @@ fn test_validate_badge_count_below_max_valid() {
 
 #[test]
 fn test_quest_status_active_to_paused_valid() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Active,
-        &QuestStatus::Paused,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Active, &QuestStatus::Paused);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_quest_status_active_to_completed_valid() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Active,
-        &QuestStatus::Completed,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Active, &QuestStatus::Completed);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_quest_status_active_to_expired_valid() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Active,
-        &QuestStatus::Expired,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Active, &QuestStatus::Expired);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_quest_status_paused_to_active_valid() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Paused,
-        &QuestStatus::Active,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Paused, &QuestStatus::Active);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_quest_status_paused_to_expired_valid() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Paused,
-        &QuestStatus::Expired,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Paused, &QuestStatus::Expired);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_quest_status_completed_to_active_rejected() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Completed,
-        &QuestStatus::Active,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Completed, &QuestStatus::Active);
     assert!(result.is_err());
 }
 
 #[test]
 fn test_quest_status_expired_to_active_rejected() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Expired,
-        &QuestStatus::Active,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Expired, &QuestStatus::Active);
     assert!(result.is_err());
 }
 
 #[test]
 fn test_quest_status_completed_to_paused_rejected() {
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Completed, &QuestStatus::Paused);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_quest_status_unfunded_to_active_valid() {
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Unfunded, &QuestStatus::Active);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_quest_status_unfunded_to_completed_rejected() {
     let result = validation::validate_quest_status_transition(
+        &QuestStatus::Unfunded,
         &QuestStatus::Completed,
-        &QuestStatus::Paused,
     );
     assert!(result.is_err());
 }
 
 #[test]
 fn test_quest_status_same_to_same_rejected() {
-    let result = validation::validate_quest_status_transition(
-        &QuestStatus::Active,
-        &QuestStatus::Active,
-    );
+    let result =
+        validation::validate_quest_status_transition(&QuestStatus::Active, &QuestStatus::Active);
     assert!(result.is_err());
 }
 
@@ -583,7 +616,10 @@ fn test_full_quest_lifecycle_with_validation() {
 
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
@@ -594,6 +630,7 @@ fn test_full_quest_lifecycle_with_validation() {
 
     // 1. Register quest (all validations pass)
     client.register_quest(&quest_id, &creator, &token, &500, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 500);
 
     // 2. Submit proof
     let proof = BytesN::from_array(&env, &[1u8; 32]);
@@ -613,6 +650,7 @@ fn test_duplicate_quest_registration_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -623,8 +661,7 @@ fn test_duplicate_quest_registration_rejected() {
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
 
     // Second registration with same ID should fail
-    let result =
-        client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000);
     assert!(result.is_err());
 }
 
@@ -652,7 +689,10 @@ fn test_approve_by_wrong_verifier_rejected() {
 
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
     let wrong_verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
@@ -662,6 +702,7 @@ fn test_approve_by_wrong_verifier_rejected() {
     });
 
     client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    fund_quest(&env, &client, &token, &quest_id, &creator, 1000);
 
     let proof = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_proof(&quest_id, &submitter, &proof);
@@ -681,6 +722,7 @@ fn test_edge_case_min_reward_amount() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -702,6 +744,7 @@ fn test_edge_case_deadline_just_after_now() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -723,6 +766,7 @@ fn test_edge_case_deadline_exactly_at_now_rejected() {
     let quest_id = symbol_short!("QUEST1");
     let creator = Address::generate(&env);
     let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
     let verifier = Address::generate(&env);
 
     env.ledger().with_mut(|li| {
@@ -733,3 +777,165 @@ fn test_edge_case_deadline_exactly_at_now_rejected() {
     let result = client.try_register_quest(&quest_id, &creator, &token, &100, &verifier, &1000);
     assert!(result.is_err());
 }
+
+//================================================================================
+// Asset Registry Tests
+//================================================================================
+
+#[test]
+fn test_register_quest_unregistered_asset_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    let result = client.try_register_quest(
+        &symbol_short!("QUEST1"),
+        &creator,
+        &token,
+        &100,
+        &verifier,
+        &5000,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+}
+
+#[test]
+fn test_register_quest_respects_asset_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.set_asset_config(
+        &admin,
+        &token,
+        &AssetConfig {
+            allowed: true,
+            min_reward: 10,
+            max_reward: 1000,
+            decimals: 7,
+        },
+    );
+
+    let result = client.try_register_quest(
+        &symbol_short!("LOW"),
+        &creator,
+        &token,
+        &9,
+        &verifier,
+        &5000,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidRewardAmount)));
+
+    let result = client.try_register_quest(
+        &symbol_short!("HIGH"),
+        &creator,
+        &token,
+        &1001,
+        &verifier,
+        &5000,
+    );
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
+
+    client.register_quest(
+        &symbol_short!("OK"),
+        &creator,
+        &token,
+        &1000,
+        &verifier,
+        &5000,
+    );
+    assert_eq!(client.get_asset_config(&token).unwrap().decimals, 7);
+}
+
+#[test]
+fn test_disallowed_asset_blocks_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
+    client.initialize(&admin);
+
+    client.register_quest(
+        &symbol_short!("BEFORE"),
+        &creator,
+        &token,
+        &100,
+        &verifier,
+        &5000,
+    );
+
+    client.set_asset_config(
+        &admin,
+        &token,
+        &AssetConfig {
+            allowed: false,
+            min_reward: 1,
+            max_reward: MAX_REWARD_AMOUNT,
+            decimals: 7,
+        },
+    );
+
+    let result = client.try_register_quest(
+        &symbol_short!("AFTER"),
+        &creator,
+        &token,
+        &100,
+        &verifier,
+        &5000,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+}
+
+#[test]
+fn test_set_asset_config_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize(&admin);
+
+    let config = AssetConfig {
+        allowed: true,
+        min_reward: 1,
+        max_reward: 100,
+        decimals: 7,
+    };
+    let result = client.try_set_asset_config(&stranger, &token, &config);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let inverted = AssetConfig {
+        min_reward: 200,
+        ..config.clone()
+    };
+    let result = client.try_set_asset_config(&admin, &token, &inverted);
+    assert_eq!(result, Err(Ok(Error::InvalidRewardAmount)));
+
+    let zero_min = AssetConfig {
+        min_reward: 0,
+        ..config
+    };
+    let result = client.try_set_asset_config(&admin, &token, &zero_min);
+    assert_eq!(result, Err(Ok(Error::InvalidRewardAmount)));
+}